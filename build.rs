@@ -1,12 +1,23 @@
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+fn main(){
+    link();
+    write_rustc_version_hash();
+}
+
 #[cfg(any(target_os="linux",
           target_os="android"))]
-fn main(){
+fn link(){
     println!("cargo:rustc-link-lib=dl");
 }
 
 #[cfg(any(target_os="freebsd",
           target_os="dragonfly"))]
-fn main(){
+fn link(){
     println!("cargo:rustc-link-lib=c");
 }
 
@@ -15,12 +26,38 @@ fn main(){
           target_os="netbsd",
           target_os="macos",
           target_os="ios"))]
-fn main(){
+fn link(){
     // netbsd claims dl* will be available to any dynamically linked binary, but I haven’t found
     // any libraries that have to be linked to on other platforms.
 }
 
 #[cfg(windows)]
-fn main(){
+fn link(){
     // dependencies come with winapi.
 }
+
+// Rust types have no stable layout across compilers, so plugins and hosts compare a hash of the
+// compiler's version and target before they exchange any. The hash is FNV-1a, which is enough to
+// tell toolchains apart and needs no dependencies.
+fn write_rustc_version_hash(){
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let mut version =
+        Command::new(rustc)
+            .arg("-vV")
+            .output()
+            .map(|output| output.stdout)
+            .unwrap_or_default();
+    version.extend_from_slice(env::var("TARGET").unwrap_or_default().as_bytes());
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in &version {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let mut file = File::create(Path::new(&out_dir).join("rustc_version_hash.rs")).unwrap();
+    writeln!(file, "0x{:016x}", hash).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=RUSTC");
+}
@@ -0,0 +1,118 @@
+//! Derive macros for [sharedlib](https://crates.io/crates/sharedlib). The macros are re-exported by sharedlib, which documents them.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Field;
+use syn::Fields;
+use syn::GenericArgument;
+use syn::LitStr;
+use syn::PathArguments;
+use syn::Type;
+
+/// Implements `sharedlib::SymbolTable` for a struct whose named fields are symbols.
+#[proc_macro_derive(SymbolTable, attributes(symbol))]
+pub fn derive_symbol_table(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match symbol_table(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn symbol_table(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields =
+        match input.data {
+            Data::Struct(ref data) => {
+                match data.fields {
+                    Fields::Named(ref fields) => &fields.named,
+                    _ => return Err(syn::Error::new_spanned(input, "SymbolTable can only be derived for structs with named fields")),
+                }
+            },
+            _ => return Err(syn::Error::new_spanned(input, "SymbolTable can only be derived for structs")),
+        };
+
+    let mut finds = Vec::new();
+    let mut inits = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let symbol = LitStr::new(&format!("{}\0", symbol_name(field)?), ident.span());
+        match option_inner(&field.ty) {
+            Some(inner) => {
+                finds.push(quote! { let #ident = loader.optional::<#inner>(#symbol); });
+                inits.push(quote! { #ident: #ident });
+            },
+            None => {
+                let ty = &field.ty;
+                finds.push(quote! { let #ident = loader.required::<#ty>(#symbol); });
+                inits.push(quote! { #ident: #ident.unwrap() });
+            },
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let result =
+        quote! {
+            unsafe impl #impl_generics ::sharedlib::SymbolTable for #name #ty_generics #where_clause {
+                unsafe fn load(lib: &::sharedlib::LibUnsafe) -> ::sharedlib::SharedlibResult<Self> {
+                    let mut loader = ::sharedlib::SymbolTableLoader::new(lib);
+                    #(#finds)*
+                    loader.finish()?;
+                    ::std::result::Result::Ok(#name { #(#inits),* })
+                }
+            }
+        };
+    Ok(result)
+}
+
+// The name given by `#[symbol(name = "...")]`, or else the name of the field.
+fn symbol_name(field: &Field) -> syn::Result<String> {
+    let mut result = field.ident.as_ref().unwrap().to_string();
+    for attr in &field.attrs {
+        if !attr.path().is_ident("symbol") {
+            continue;
+        }
+        attr.parse_nested_meta(
+            |meta| {
+                if meta.path.is_ident("name") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    result = name.value();
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `name = \"...\"`"))
+                }
+            }
+        )?;
+    }
+    Ok(result)
+}
+
+// The `T` of a field of type `Option<T>`, which marks an optional symbol.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path =
+        match *ty {
+            Type::Path(ref ty) if ty.qself.is_none() => &ty.path,
+            _ => return None,
+        };
+    let segment = path.segments.last().unwrap();
+    if segment.ident != "Option" {
+        return None;
+    }
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref arguments) if arguments.args.len() == 1 => {
+            match arguments.args[0] {
+                GenericArgument::Type(ref inner) => Some(inner),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
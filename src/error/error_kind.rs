@@ -0,0 +1,46 @@
+use error::OsError;
+use SharedlibError;
+use std::error::Error;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+
+/// The kind of an error, classified from the message of `dlerror` on unix and the code of `GetLastError` on windows.
+/// Loaders word their messages differently across platforms and versions, so an error which cannot be classified is `Other`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorKind {
+    /// The shared library does not exist, or could not be found on the search path.
+    NotFound,
+    /// The shared library could not be read or mapped.
+    PermissionDenied,
+    /// The shared library was built for another architecture or word size.
+    WrongArchitecture,
+    /// The file is not a shared library.
+    NotASharedObject,
+    /// A shared library which the shared library depends on could not be loaded.
+    MissingDependency,
+    /// The requested symbol is not exported by the shared library.
+    SymbolNotFound,
+    /// The shared library refers to a symbol which no loaded shared library defines.
+    UndefinedSymbol,
+    /// Any other error.
+    Other,
+}
+
+impl ErrorKind {
+    // Classifies the cause of a wrapping error.
+    pub(crate) fn of(err: &(Error + 'static)) -> ErrorKind {
+        if let Some(err) = err.downcast_ref::<SharedlibError>() {
+            err.kind()
+        } else if let Some(err) = err.downcast_ref::<OsError>() {
+            err.kind()
+        } else if let Some(err) = err.downcast_ref::<IoError>() {
+            match err.kind() {
+                IoErrorKind::NotFound => ErrorKind::NotFound,
+                IoErrorKind::PermissionDenied => ErrorKind::PermissionDenied,
+                _ => ErrorKind::Other,
+            }
+        } else {
+            ErrorKind::Other
+        }
+    }
+}
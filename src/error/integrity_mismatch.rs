@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::path::PathBuf;
+use util;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when the contents of a shared library do not match the digest it was expected to have.
+#[derive(Clone, Debug)]
+pub struct IntegrityMismatch {
+    actual: Vec<u8>,
+    expected: Vec<u8>,
+    path_to_lib: PathBuf,
+}
+
+impl IntegrityMismatch {
+    /// Creates a new [IntegrityMismatch](struct.IntegrityMismatch.html).
+    pub fn new(expected: Vec<u8>, actual: Vec<u8>, path_to_lib: PathBuf) -> Self {
+        IntegrityMismatch {
+            actual: actual,
+            expected: expected,
+            path_to_lib: path_to_lib,
+        }
+    }
+
+    /// Returns the digest the shared library was expected to have.
+    pub fn expected(&self) -> &[u8] {
+        &self.expected
+    }
+
+    /// Returns the digest the shared library actually had.
+    pub fn actual(&self) -> &[u8] {
+        &self.actual
+    }
+
+    /// Returns the path of the shared library which failed verification.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+}
+
+impl Display for IntegrityMismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{}{}",
+            message(MessageId::IntegrityMismatchDisplay1),
+            self.path_to_lib.to_string_lossy(),
+            message(MessageId::IntegrityMismatchDisplay2),
+            util::to_hex(&self.expected),
+            message(MessageId::IntegrityMismatchDisplay3),
+            util::to_hex(&self.actual),
+        )
+    }
+}
+
+impl Error for IntegrityMismatch {
+    fn description(&self) -> &str {
+        message(MessageId::IntegrityMismatchDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when a file cannot be parsed as an ELF object.
+#[derive(Clone, Debug)]
+pub struct InvalidElf {
+    reason: MessageId,
+}
+
+impl InvalidElf {
+    /// Creates a new [InvalidElf](struct.InvalidElf.html). The reason is looked up in the installed [MessageCatalog](../trait.MessageCatalog.html) when the error is displayed.
+    pub fn new(reason: MessageId) -> Self {
+        InvalidElf {
+            reason: reason,
+        }
+    }
+
+    /// Returns the message which explains why the file is not a valid ELF object.
+    pub fn reason(&self) -> MessageId {
+        self.reason
+    }
+}
+
+impl Display for InvalidElf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            message(MessageId::InvalidElfDisplay1),
+            message(self.reason),
+        )
+    }
+}
+
+impl Error for InvalidElf {
+    fn description(&self) -> &str {
+        message(MessageId::InvalidElfDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
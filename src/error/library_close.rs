@@ -1,22 +1,30 @@
+use error::ErrorKind;
 use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
-use string::error::library_close as string;
+use std::sync::Arc;
+use string::message;
+use string::MessageId;
 
 /// An error which occurs when a shared library fails to close.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct LibraryClose {
-    cause: Box<Error>,
+    cause: Arc<Error + Send + Sync>,
 }
 
 impl LibraryClose {
     /// Creates a new [LibraryClose](struct.LibraryClose.html).
-    pub fn new(cause: Box<Error>) -> Self {
+    pub fn new(cause: Box<Error + Send + Sync>) -> Self {
         LibraryClose {
-            cause: cause,
+            cause: Arc::from(cause),
         }
     }
+
+    /// Returns the kind of the error which caused the shared library to fail to close.
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::of(self.cause.as_ref())
+    }
 }
 
 impl Display for LibraryClose {
@@ -24,7 +32,7 @@ impl Display for LibraryClose {
         write!(
             f,
             "{}{}",
-            string::display_1(),
+            message(MessageId::LibraryCloseDisplay1),
             self.cause,
         )
     }
@@ -32,10 +40,14 @@ impl Display for LibraryClose {
 
 impl Error for LibraryClose {
     fn description(&self) -> &str {
-        string::description()
+        message(MessageId::LibraryCloseDescription)
     }
 
     fn cause(&self) -> Option<&Error> {
         Some(self.cause.as_ref())
     }
+
+    fn source(&self) -> Option<&(Error + 'static)> {
+        Some(self.cause.as_ref())
+    }
 }
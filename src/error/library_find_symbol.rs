@@ -1,45 +1,95 @@
+use error::ErrorKind;
 use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
-use string::error::library_find_symbol as string;
+use std::sync::Arc;
+use string::message;
+use string::MessageId;
 
 /// An error which occurs when a symbol cannot be found in a shared library.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct LibraryFindSymbol {
-    cause: Box<Error>,
+    cause: Arc<Error + Send + Sync>,
+    suggestions: Vec<String>,
     symbol: String,
 }
 
 impl LibraryFindSymbol {
     /// Creates a new [LibraryFindSymbol](struct.LibraryFindSymbol.html).
-    pub fn new(cause: Box<Error>, symbol: String) -> Self {
+    pub fn new(cause: Box<Error + Send + Sync>, symbol: String) -> Self {
         LibraryFindSymbol {
-            cause: cause,
+            cause: Arc::from(cause),
+            suggestions: Vec::new(),
             symbol: symbol,
         }
     }
+
+    /// Returns the error with the names of exported symbols which the missing symbol may have been meant to be, closest first.
+    /// This should only be called within the library.
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Returns the name of the symbol which could not be found.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the names of exported symbols which the missing symbol may have been meant to be, closest first.
+    /// This is empty unless suggestions were asked for through [OpenOptions::suggest_symbols](../struct.OpenOptions.html#method.suggest_symbols), and the shared library's dynamic symbol table could be read.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    /// Returns the kind of the error which caused the symbol to be missing.
+    /// This is `SymbolNotFound` unless the operating system reported something more specific.
+    pub fn kind(&self) -> ErrorKind {
+        match ErrorKind::of(self.cause.as_ref()) {
+            ErrorKind::Other => ErrorKind::SymbolNotFound,
+            kind => kind,
+        }
+    }
 }
 
 impl Display for LibraryFindSymbol {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}{}{}{}",
-            string::display_1(),
-            self.symbol,
-            string::display_2(),
-            self.cause,
-        )
+        try!(
+            write!(
+                f,
+                "{}{}{}{}",
+                message(MessageId::LibraryFindSymbolDisplay1),
+                self.symbol,
+                message(MessageId::LibraryFindSymbolDisplay2),
+                self.cause,
+            )
+        );
+        if !self.suggestions.is_empty() {
+            try!(
+                write!(
+                    f,
+                    "{}{}{}",
+                    message(MessageId::LibraryFindSymbolSuggestions1),
+                    self.suggestions.join(message(MessageId::LibraryFindSymbolSuggestionsSeparator)),
+                    message(MessageId::LibraryFindSymbolSuggestions2),
+                )
+            );
+        }
+        Ok(())
     }
 }
 
 impl Error for LibraryFindSymbol {
     fn description(&self) -> &str {
-        string::description()
+        message(MessageId::LibraryFindSymbolDescription)
     }
 
     fn cause(&self) -> Option<&Error> {
         Some(self.cause.as_ref())
     }
+
+    fn source(&self) -> Option<&(Error + 'static)> {
+        Some(self.cause.as_ref())
+    }
 }
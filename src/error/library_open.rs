@@ -1,25 +1,39 @@
+use error::ErrorKind;
 use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::path::Path;
 use std::path::PathBuf;
-use string::error::library_open as string;
+use std::sync::Arc;
+use string::message;
+use string::MessageId;
 
 /// An error which occurs when a shared library fails to open.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct LibraryOpen {
-    cause: Box<Error>,
+    cause: Arc<Error + Send + Sync>,
     path_to_lib: PathBuf,
 }
 
 impl LibraryOpen {
     /// Creates a new [LibraryOpen](struct.LibraryOpen.html).
-    pub fn new(cause: Box<Error>, path_to_lib: PathBuf) -> Self {
+    pub fn new(cause: Box<Error + Send + Sync>, path_to_lib: PathBuf) -> Self {
         LibraryOpen {
-            cause: cause,
+            cause: Arc::from(cause),
             path_to_lib: path_to_lib,
         }
     }
+
+    /// Returns the path of the shared library which failed to open.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+
+    /// Returns the kind of the error which caused the shared library to fail to open.
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::of(self.cause.as_ref())
+    }
 }
 
 impl Display for LibraryOpen {
@@ -27,9 +41,9 @@ impl Display for LibraryOpen {
         write!(
             f,
             "{}{}{}{}",
-            string::display_1(),
+            message(MessageId::LibraryOpenDisplay1),
             self.path_to_lib.to_string_lossy(),
-            string::display_2(),
+            message(MessageId::LibraryOpenDisplay2),
             self.cause,
         )
     }
@@ -37,10 +51,14 @@ impl Display for LibraryOpen {
 
 impl Error for LibraryOpen {
     fn description(&self) -> &str {
-        string::description()
+        message(MessageId::LibraryOpenDescription)
     }
 
     fn cause(&self) -> Option<&Error> {
         Some(self.cause.as_ref())
     }
+
+    fn source(&self) -> Option<&(Error + 'static)> {
+        Some(self.cause.as_ref())
+    }
 }
@@ -0,0 +1,43 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when a shared library is used after it was unloaded through [LibTracked::unload_when_idle](../struct.LibTracked.html#method.unload_when_idle), or while it is being unloaded.
+#[derive(Clone, Debug)]
+pub struct LibraryUnloaded;
+
+impl LibraryUnloaded {
+    /// Creates a new [LibraryUnloaded](struct.LibraryUnloaded.html).
+    pub fn new() -> Self {
+        LibraryUnloaded
+    }
+}
+
+impl Default for LibraryUnloaded {
+    fn default() -> Self {
+        LibraryUnloaded::new()
+    }
+}
+
+impl Display for LibraryUnloaded {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            message(MessageId::LibraryUnloadedDisplay1),
+        )
+    }
+}
+
+impl Error for LibraryUnloaded {
+    fn description(&self) -> &str {
+        message(MessageId::LibraryUnloadedDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
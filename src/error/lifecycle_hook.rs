@@ -0,0 +1,80 @@
+use error::LifecycleHookKind;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::os::raw::c_int;
+use std::path::Path;
+use std::path::PathBuf;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when a lifecycle hook of a shared library returns a non-zero code.
+/// A library whose load hook fails is closed again without running its unload hook. A library whose unload hook fails is closed anyway.
+#[derive(Clone, Debug)]
+pub struct LifecycleHook {
+    kind: LifecycleHookKind,
+    symbol: String,
+    code: c_int,
+    path_to_lib: PathBuf,
+}
+
+impl LifecycleHook {
+    /// Creates a new [LifecycleHook](struct.LifecycleHook.html).
+    pub fn new(kind: LifecycleHookKind, symbol: String, code: c_int, path_to_lib: PathBuf) -> Self {
+        LifecycleHook {
+            kind: kind,
+            symbol: symbol,
+            code: code,
+            path_to_lib: path_to_lib,
+        }
+    }
+
+    /// Returns which hook failed.
+    pub fn kind(&self) -> LifecycleHookKind {
+        self.kind
+    }
+
+    /// Returns the symbol of the hook.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the code the hook returned.
+    pub fn code(&self) -> c_int {
+        self.code
+    }
+
+    /// Returns the path of the shared library.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+}
+
+impl Display for LifecycleHook {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{}{}{}{}{}",
+            message(MessageId::LifecycleHookDisplay1),
+            self.kind,
+            message(MessageId::LifecycleHookDisplay2),
+            self.symbol,
+            message(MessageId::LifecycleHookDisplay3),
+            self.path_to_lib.to_string_lossy(),
+            message(MessageId::LifecycleHookDisplay4),
+            self.code,
+            message(MessageId::LifecycleHookDisplay5),
+        )
+    }
+}
+
+impl Error for LifecycleHook {
+    fn description(&self) -> &str {
+        message(MessageId::LifecycleHookDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
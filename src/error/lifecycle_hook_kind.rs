@@ -0,0 +1,23 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use string::message;
+use string::MessageId;
+
+/// The lifecycle hook which failed with a [LifecycleHook](struct.LifecycleHook.html) error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LifecycleHookKind {
+    /// The hook passed to [OpenOptions::on_load](../struct.OpenOptions.html#method.on_load), which runs right after the library is opened.
+    Load,
+    /// The hook passed to [OpenOptions::on_unload](../struct.OpenOptions.html#method.on_unload), which runs right before the library is closed.
+    Unload,
+}
+
+impl Display for LifecycleHookKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            LifecycleHookKind::Load => write!(f, "{}", message(MessageId::LifecycleHookLoad)),
+            LifecycleHookKind::Unload => write!(f, "{}", message(MessageId::LifecycleHookUnload)),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use error::LibraryFindSymbol;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when a shared library does not contain every requested symbol, for instance every required symbol of a [SymbolTable](../trait.SymbolTable.html). It names all of the missing symbols, not just the first, together with the cause of each.
+#[derive(Clone, Debug)]
+pub struct MissingSymbols {
+    errors: Vec<LibraryFindSymbol>,
+}
+
+impl MissingSymbols {
+    /// Creates a new [MissingSymbols](struct.MissingSymbols.html).
+    pub fn new(errors: Vec<LibraryFindSymbol>) -> Self {
+        MissingSymbols {
+            errors: errors,
+        }
+    }
+
+    /// Returns the error of each missing symbol, in the order the symbols were requested.
+    pub fn errors(&self) -> &[LibraryFindSymbol] {
+        &self.errors
+    }
+
+    /// Returns the names of the missing symbols, in the order they were requested.
+    pub fn symbols(&self) -> Vec<&str> {
+        self.errors.iter().map(LibraryFindSymbol::symbol).collect()
+    }
+}
+
+impl Display for MissingSymbols {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        try!(
+            write!(
+                f,
+                "{}{}{}",
+                message(MessageId::MissingSymbolsDisplay1),
+                self.symbols().join(message(MessageId::MissingSymbolsSeparator)),
+                message(MessageId::MissingSymbolsDisplay2),
+            )
+        );
+        for err in &self.errors {
+            try!(write!(f, "\n{}", err));
+        }
+        Ok(())
+    }
+}
+
+impl Error for MissingSymbols {
+    fn description(&self) -> &str {
+        message(MessageId::MissingSymbolsDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
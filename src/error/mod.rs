@@ -1,5 +1,16 @@
 //! Defines errors which may be returned by [sharedlib](index.html).
 
+#[macro_use]
+mod define_error;
+
+mod error_kind;
+
+mod integrity_mismatch;
+
+mod invalid_elf;
+
+mod missing_symbols;
+
 mod os_error;
 
 mod os_error_failure;
@@ -10,10 +21,46 @@ mod library_find_symbol;
 
 mod library_open;
 
+mod library_unloaded;
+
+mod lifecycle_hook;
+
+mod lifecycle_hook_kind;
+
+mod plugin_dependency;
+
+mod plugin_dependency_kind;
+
+mod plugin_init;
+
+mod plugin_init_kind;
+
+mod plugin_mismatch;
+
+mod plugin_mismatch_kind;
+
+mod policy_violation;
+
+mod policy_violation_kind;
+
 mod sharedlib_error;
 
 mod sharedlib_result;
 
+mod state_handoff;
+
+mod state_handoff_kind;
+
+mod unload_timeout;
+
+pub use error::error_kind::ErrorKind;
+
+pub use error::integrity_mismatch::IntegrityMismatch;
+
+pub use error::invalid_elf::InvalidElf;
+
+pub use error::missing_symbols::MissingSymbols;
+
 pub use error::os_error::OsError;
 
 pub use error::os_error_failure::OsErrorFailure;
@@ -24,6 +71,34 @@ pub use error::library_find_symbol::LibraryFindSymbol;
 
 pub use error::library_open::LibraryOpen;
 
+pub use error::library_unloaded::LibraryUnloaded;
+
+pub use error::lifecycle_hook::LifecycleHook;
+
+pub use error::lifecycle_hook_kind::LifecycleHookKind;
+
+pub use error::plugin_dependency::PluginDependency;
+
+pub use error::plugin_dependency_kind::PluginDependencyKind;
+
+pub use error::plugin_init::PluginInit;
+
+pub use error::plugin_init_kind::PluginInitKind;
+
+pub use error::plugin_mismatch::PluginMismatch;
+
+pub use error::plugin_mismatch_kind::PluginMismatchKind;
+
+pub use error::policy_violation::PolicyViolation;
+
+pub use error::policy_violation_kind::PolicyViolationKind;
+
 pub use error::sharedlib_error::SharedlibError;
 
 pub use error::sharedlib_result::SharedlibResult;
+
+pub use error::state_handoff::StateHandoff;
+
+pub use error::state_handoff_kind::StateHandoffKind;
+
+pub use error::unload_timeout::UnloadTimeout;
@@ -1,24 +1,43 @@
+use error::ErrorKind;
 use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
-use string::error::os_error as string;
+use string::message;
+use string::MessageId;
 
 /// An error which occurs when an operating system function fails.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct OsError {
     cause: String,
     function_called: String,
+    kind: ErrorKind,
 }
 
 impl OsError {
     /// Creates a new [OsError](struct.OsError.html).
-    pub fn new(cause: String, function_called: String) -> Self {
+    pub fn new(kind: ErrorKind, cause: String, function_called: String) -> Self {
         OsError {
             cause: cause,
             function_called: function_called,
+            kind: kind,
         }
     }
+
+    /// Returns the kind of the error, as classified from the message reported by the operating system.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the message reported by the operating system, exactly as it was reported.
+    pub fn raw_message(&self) -> &str {
+        &self.cause
+    }
+
+    /// Returns the name of the operating system function which failed.
+    pub fn function_called(&self) -> &str {
+        &self.function_called
+    }
 }
 
 impl Display for OsError {
@@ -26,9 +45,9 @@ impl Display for OsError {
         write!(
             f,
             "{}{}{}{}",
-            string::display_1(),
+            message(MessageId::OsErrorDisplay1),
             self.function_called,
-            string::display_2(),
+            message(MessageId::OsErrorDisplay2),
             self.cause,
         )
     }
@@ -36,7 +55,7 @@ impl Display for OsError {
 
 impl Error for OsError {
     fn description(&self) -> &str {
-        string::description()
+        message(MessageId::OsErrorDescription)
     }
 
     fn cause(&self) -> Option<&Error> {
@@ -2,10 +2,11 @@ use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
-use string::error::os_error_failure as string;
+use string::message;
+use string::MessageId;
 
 /// An error which occurs when an operating system function fails and no information is available.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct OsErrorFailure {
     function_called: String,
 }
@@ -17,6 +18,11 @@ impl OsErrorFailure {
             function_called: function_called,
         }
     }
+
+    /// Returns the name of the operating system function which failed.
+    pub fn function_called(&self) -> &str {
+        &self.function_called
+    }
 }
 
 impl Display for OsErrorFailure {
@@ -24,16 +30,16 @@ impl Display for OsErrorFailure {
         write!(
             f,
             "{}{}{}",
-            string::display_1(),
+            message(MessageId::OsErrorFailureDisplay1),
             self.function_called,
-            string::display_2(),
+            message(MessageId::OsErrorFailureDisplay2),
         )
     }
 }
 
 impl Error for OsErrorFailure {
     fn description(&self) -> &str {
-        string::description()
+        message(MessageId::OsErrorFailureDescription)
     }
 
     fn cause(&self) -> Option<&Error> {
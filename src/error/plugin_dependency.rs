@@ -0,0 +1,68 @@
+use error::PluginDependencyKind;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::path::PathBuf;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when the dependencies of a plugin cannot be resolved.
+#[derive(Clone, Debug)]
+pub struct PluginDependency {
+    kind: PluginDependencyKind,
+    name: String,
+    path_to_lib: PathBuf,
+}
+
+impl PluginDependency {
+    /// Creates a new [PluginDependency](struct.PluginDependency.html).
+    pub fn new(kind: PluginDependencyKind, name: String, path_to_lib: PathBuf) -> Self {
+        PluginDependency {
+            kind: kind,
+            name: name,
+            path_to_lib: path_to_lib,
+        }
+    }
+
+    /// Returns the problem with the dependencies.
+    pub fn kind(&self) -> &PluginDependencyKind {
+        &self.kind
+    }
+
+    /// Returns the name of the plugin whose dependencies could not be resolved.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the path of the plugin.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+}
+
+impl Display for PluginDependency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{}{}",
+            message(MessageId::PluginDependencyDisplay1),
+            self.name,
+            message(MessageId::PluginDependencyDisplay2),
+            self.path_to_lib.to_string_lossy(),
+            message(MessageId::PluginDependencyDisplay3),
+            self.kind,
+        )
+    }
+}
+
+impl Error for PluginDependency {
+    fn description(&self) -> &str {
+        message(MessageId::PluginDependencyDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use string::message;
+use string::MessageId;
+
+/// The problem with a plugin's dependencies which caused a [PluginDependency](struct.PluginDependency.html) error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PluginDependencyKind {
+    /// The plugin declares a version requirement which cannot be parsed.
+    InvalidRequirement {
+        /// The name of the dependency.
+        name: String,
+        /// The requirement as the plugin declares it.
+        requirement: String,
+    },
+    /// No plugin of the dependency's name is loaded.
+    Missing {
+        /// The name of the dependency.
+        name: String,
+        /// The version requirement of the dependency.
+        requirement: String,
+    },
+    /// A plugin of the dependency's name is loaded, but its version does not meet the requirement.
+    Unsatisfied {
+        /// The name of the dependency.
+        name: String,
+        /// The version requirement of the dependency.
+        requirement: String,
+        /// The version of the loaded plugin.
+        version: String,
+    },
+    /// The plugin depends on itself through the contained names, which start and end with the plugin.
+    Cycle(Vec<String>),
+    /// The plugin cannot be reloaded, because its library is still referenced and would be initialized again instead of being replaced. The contained names are the registered plugins which depend on it; the other references are clones of the plugin and symbols found in it.
+    InUse(Vec<String>),
+}
+
+impl Display for PluginDependencyKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            PluginDependencyKind::InvalidRequirement { ref name, ref requirement } => {
+                write!(
+                    f,
+                    "{}{}{}{}{}",
+                    message(MessageId::PluginDependencyInvalidRequirement1),
+                    requirement,
+                    message(MessageId::PluginDependencyInvalidRequirement2),
+                    name,
+                    message(MessageId::PluginDependencyInvalidRequirement3),
+                )
+            },
+            PluginDependencyKind::Missing { ref name, ref requirement } => {
+                write!(
+                    f,
+                    "{}{}{}{}{}",
+                    message(MessageId::PluginDependencyMissing1),
+                    name,
+                    message(MessageId::PluginDependencyMissing2),
+                    requirement,
+                    message(MessageId::PluginDependencyMissing3),
+                )
+            },
+            PluginDependencyKind::Unsatisfied { ref name, ref requirement, ref version } => {
+                write!(
+                    f,
+                    "{}{}{}{}{}{}{}",
+                    message(MessageId::PluginDependencyUnsatisfied1),
+                    name,
+                    message(MessageId::PluginDependencyUnsatisfied2),
+                    version,
+                    message(MessageId::PluginDependencyUnsatisfied3),
+                    requirement,
+                    message(MessageId::PluginDependencyUnsatisfied4),
+                )
+            },
+            PluginDependencyKind::Cycle(ref names) => write!(f, "{}{}{}", message(MessageId::PluginDependencyCycle1), names.join(" -> "), message(MessageId::PluginDependencyCycle2)),
+            PluginDependencyKind::InUse(ref names) if names.is_empty() => write!(f, "{}{}", message(MessageId::PluginDependencyInUse1), message(MessageId::PluginDependencyInUse3)),
+            PluginDependencyKind::InUse(ref names) => write!(f, "{}{}{}{}", message(MessageId::PluginDependencyInUse1), message(MessageId::PluginDependencyInUse2), names.join(", "), message(MessageId::PluginDependencyInUse3)),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use error::PluginInitKind;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::path::PathBuf;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when the init entry point of a plugin fails. The plugin is unloaded again.
+#[derive(Clone, Debug)]
+pub struct PluginInit {
+    kind: PluginInitKind,
+    path_to_lib: PathBuf,
+}
+
+impl PluginInit {
+    /// Creates a new [PluginInit](struct.PluginInit.html).
+    pub fn new(kind: PluginInitKind, path_to_lib: PathBuf) -> Self {
+        PluginInit {
+            kind: kind,
+            path_to_lib: path_to_lib,
+        }
+    }
+
+    /// Returns why the init entry point failed.
+    pub fn kind(&self) -> PluginInitKind {
+        self.kind
+    }
+
+    /// Returns the path of the plugin.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+}
+
+impl Display for PluginInit {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            message(MessageId::PluginInitDisplay1),
+            self.path_to_lib.to_string_lossy(),
+            message(MessageId::PluginInitDisplay2),
+            self.kind,
+        )
+    }
+}
+
+impl Error for PluginInit {
+    fn description(&self) -> &str {
+        message(MessageId::PluginInitDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
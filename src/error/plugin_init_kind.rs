@@ -0,0 +1,24 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::os::raw::c_int;
+use string::message;
+use string::MessageId;
+
+/// Why `sharedlib_plugin_init` failed with a [PluginInit](struct.PluginInit.html) error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PluginInitKind {
+    /// The plugin needs a newer [HostApi](../plugin/struct.HostApi.html) than the host provides. The contained value is the host's version.
+    HostUnsupported(u32),
+    /// The plugin returned the contained code, which is neither zero nor [HOST_API_UNSUPPORTED](../plugin/constant.HOST_API_UNSUPPORTED.html).
+    Failed(c_int),
+}
+
+impl Display for PluginInitKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            PluginInitKind::HostUnsupported(version) => write!(f, "{}{}{}", message(MessageId::PluginInitHostUnsupported1), version, message(MessageId::PluginInitHostUnsupported2)),
+            PluginInitKind::Failed(code) => write!(f, "{}{}{}", message(MessageId::PluginInitFailed1), code, message(MessageId::PluginInitFailed2)),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use error::PluginMismatchKind;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::path::PathBuf;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when a shared library is not a plugin the host can use. The library is unloaded without any of its other symbols being touched.
+#[derive(Clone, Debug)]
+pub struct PluginMismatch {
+    kind: PluginMismatchKind,
+    path_to_lib: PathBuf,
+}
+
+impl PluginMismatch {
+    /// Creates a new [PluginMismatch](struct.PluginMismatch.html).
+    pub fn new(kind: PluginMismatchKind, path_to_lib: PathBuf) -> Self {
+        PluginMismatch {
+            kind: kind,
+            path_to_lib: path_to_lib,
+        }
+    }
+
+    /// Returns the check which failed.
+    pub fn kind(&self) -> &PluginMismatchKind {
+        &self.kind
+    }
+
+    /// Returns the path of the shared library.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+}
+
+impl Display for PluginMismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            message(MessageId::PluginMismatchDisplay1),
+            self.path_to_lib.to_string_lossy(),
+            message(MessageId::PluginMismatchDisplay2),
+            self.kind,
+        )
+    }
+}
+
+impl Error for PluginMismatch {
+    fn description(&self) -> &str {
+        message(MessageId::PluginMismatchDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
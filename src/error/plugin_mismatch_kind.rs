@@ -0,0 +1,119 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use string::message;
+use string::MessageId;
+
+/// The check of a plugin descriptor which failed with a [PluginMismatch](struct.PluginMismatch.html) error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PluginMismatchKind {
+    /// The library does not export a plugin descriptor.
+    MissingDescriptor,
+    /// The descriptor does not start with the plugin magic number. The contained value was found instead.
+    BadMagic(u64),
+    /// The descriptor uses a different version of the plugin ABI.
+    AbiVersion {
+        /// The ABI version of the host.
+        expected: u32,
+        /// The ABI version of the plugin.
+        actual: u32,
+    },
+    /// The descriptor is smaller than a descriptor of its ABI version.
+    DescriptorSize {
+        /// The size the host expects.
+        expected: u32,
+        /// The size the plugin reports.
+        actual: u32,
+    },
+    /// The plugin was built by a different compiler, or for a different target, than the host.
+    RustcVersion {
+        /// The hash of the host's compiler version.
+        expected: u64,
+        /// The hash of the plugin's compiler version.
+        actual: u64,
+    },
+    /// The plugin implements a different API, or a different version of it, than the host asked for.
+    Api {
+        /// The name of the API the host asked for.
+        expected_name: String,
+        /// The version of the API the host asked for.
+        expected_version: u32,
+        /// The name of the API the plugin implements.
+        actual_name: String,
+        /// The version of the API the plugin implements.
+        actual_version: u32,
+    },
+    /// A reloaded plugin declares a different name than the plugin it replaces.
+    Name {
+        /// The name of the plugin which was reloaded.
+        expected: String,
+        /// The name the new build declares.
+        actual: String,
+    },
+}
+
+impl Display for PluginMismatchKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            PluginMismatchKind::MissingDescriptor => write!(f, "{}", message(MessageId::PluginMismatchMissingDescriptor)),
+            PluginMismatchKind::BadMagic(magic) => write!(f, "{}{:#018x}{}", message(MessageId::PluginMismatchBadMagic1), magic, message(MessageId::PluginMismatchBadMagic2)),
+            PluginMismatchKind::AbiVersion { expected, actual } => {
+                write!(
+                    f,
+                    "{}{}{}{}{}",
+                    message(MessageId::PluginMismatchAbiVersion1),
+                    actual,
+                    message(MessageId::PluginMismatchAbiVersion2),
+                    expected,
+                    message(MessageId::PluginMismatchAbiVersion3),
+                )
+            },
+            PluginMismatchKind::DescriptorSize { expected, actual } => {
+                write!(
+                    f,
+                    "{}{}{}{}{}",
+                    message(MessageId::PluginMismatchDescriptorSize1),
+                    actual,
+                    message(MessageId::PluginMismatchDescriptorSize2),
+                    expected,
+                    message(MessageId::PluginMismatchDescriptorSize3),
+                )
+            },
+            PluginMismatchKind::RustcVersion { expected, actual } => {
+                write!(
+                    f,
+                    "{}{:#018x}{}{:#018x}{}",
+                    message(MessageId::PluginMismatchRustcVersion1),
+                    actual,
+                    message(MessageId::PluginMismatchRustcVersion2),
+                    expected,
+                    message(MessageId::PluginMismatchRustcVersion3),
+                )
+            },
+            PluginMismatchKind::Api { ref expected_name, expected_version, ref actual_name, actual_version } => {
+                write!(
+                    f,
+                    "{}{} {}{}{} {}{}",
+                    message(MessageId::PluginMismatchApi1),
+                    actual_name,
+                    actual_version,
+                    message(MessageId::PluginMismatchApi2),
+                    expected_name,
+                    expected_version,
+                    message(MessageId::PluginMismatchApi3),
+                )
+            },
+            PluginMismatchKind::Name { ref expected, ref actual } => {
+                write!(
+                    f,
+                    "{}{}{}{}{}",
+                    message(MessageId::PluginMismatchName1),
+                    actual,
+                    message(MessageId::PluginMismatchName2),
+                    expected,
+                    message(MessageId::PluginMismatchName3),
+                )
+            },
+        }
+    }
+}
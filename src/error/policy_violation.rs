@@ -0,0 +1,59 @@
+use error::PolicyViolationKind;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::path::PathBuf;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when a shared library, or one of its parent directories, violates a [LoadPolicy](../struct.LoadPolicy.html).
+#[derive(Clone, Debug)]
+pub struct PolicyViolation {
+    kind: PolicyViolationKind,
+    path: PathBuf,
+}
+
+impl PolicyViolation {
+    /// Creates a new [PolicyViolation](struct.PolicyViolation.html).
+    pub fn new(kind: PolicyViolationKind, path: PathBuf) -> Self {
+        PolicyViolation {
+            kind: kind,
+            path: path,
+        }
+    }
+
+    /// Returns the rule which was violated.
+    pub fn kind(&self) -> PolicyViolationKind {
+        self.kind
+    }
+
+    /// Returns the offending path component. This is either the shared library itself or one of its parent directories.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Display for PolicyViolation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            message(MessageId::PolicyViolationDisplay1),
+            self.path.to_string_lossy(),
+            message(MessageId::PolicyViolationDisplay2),
+            self.kind,
+        )
+    }
+}
+
+impl Error for PolicyViolation {
+    fn description(&self) -> &str {
+        message(MessageId::PolicyViolationDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
@@ -0,0 +1,56 @@
+use inspect::HardeningLevel;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use string::message;
+use string::MessageId;
+
+/// The rule of a [LoadPolicy](../struct.LoadPolicy.html) which a [PolicyViolation](struct.PolicyViolation.html) broke.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PolicyViolationKind {
+    /// The library was less hardened than the policy requires.
+    InsufficientHardening {
+        /// The hardening level of the library.
+        actual: HardeningLevel,
+        /// The hardening level the policy requires.
+        required: HardeningLevel,
+    },
+    /// The path was relative but the policy requires absolute paths.
+    RelativePath,
+    /// The path was a symbolic link but the policy refuses symbolic links.
+    Symlink,
+    /// The path was owned by the contained user id, which is neither root nor the current user.
+    UntrustedOwner(u32),
+    /// The path was writable by its group or by other users.
+    WritableByOthers,
+}
+
+impl Display for PolicyViolationKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            PolicyViolationKind::InsufficientHardening { actual, required } => {
+                write!(
+                    f,
+                    "{}{}{}{}{}",
+                    message(MessageId::PolicyViolationInsufficientHardening1),
+                    actual,
+                    message(MessageId::PolicyViolationInsufficientHardening2),
+                    required,
+                    message(MessageId::PolicyViolationInsufficientHardening3),
+                )
+            },
+            PolicyViolationKind::RelativePath => write!(f, "{}", message(MessageId::PolicyViolationRelativePath)),
+            PolicyViolationKind::Symlink => write!(f, "{}", message(MessageId::PolicyViolationSymlink)),
+            PolicyViolationKind::UntrustedOwner(uid) => {
+                write!(
+                    f,
+                    "{}{}{}",
+                    message(MessageId::PolicyViolationUntrustedOwner1),
+                    uid,
+                    message(MessageId::PolicyViolationUntrustedOwner2),
+                )
+            },
+            PolicyViolationKind::WritableByOthers => write!(f, "{}", message(MessageId::PolicyViolationWritableByOthers)),
+        }
+    }
+}
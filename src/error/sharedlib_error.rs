@@ -5,18 +5,55 @@ use std::fmt::Formatter;
 use std::fmt::Result;
 
 define_error!(
-    #[derive(Debug)]
-    #[doc="An error returned when a function in [sharedlib](index.html) fails. Other errors are wrapped in this enum before being returned."]
+    #[derive(Clone, Debug)]
+    #[doc="An error returned when a function in [sharedlib](index.html) fails. Other errors are wrapped in this enum before being returned. Every error is `Send`, `Sync` and `Clone`, and keeps its cause, so it can be moved between threads or shared through an `Arc`."]
     pub error SharedlibError {
+        #[doc="Wraps an `IntegrityMismatch` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror IntegrityMismatch,
+        #[doc="Wraps an `InvalidElf` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror InvalidElf,
         #[doc="Wraps a `LibraryClose` error in a [SharedlibError](enum.SharedlibError.html)."]
         suberror LibraryClose,
         #[doc="Wraps a `LibraryFindSymbol` error in a [SharedlibError](enum.SharedlibError.html)."]
         suberror LibraryFindSymbol,
         #[doc="Wraps a `LibraryOpen` error in a [SharedlibError](enum.SharedlibError.html)."]
         suberror LibraryOpen,
+        #[doc="Wraps a `LibraryUnloaded` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror LibraryUnloaded,
+        #[doc="Wraps a `LifecycleHook` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror LifecycleHook,
+        #[doc="Wraps a `MissingSymbols` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror MissingSymbols,
         #[doc="Wraps a `OsError` error in a [SharedlibError](enum.SharedlibError.html)."]
         suberror OsError,
         #[doc="Wraps a `OsErrorFailure` error in a [SharedlibError](enum.SharedlibError.html)."]
-        suberror OsErrorFailure
+        suberror OsErrorFailure,
+        #[doc="Wraps a `PluginDependency` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror PluginDependency,
+        #[doc="Wraps a `PluginInit` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror PluginInit,
+        #[doc="Wraps a `PluginMismatch` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror PluginMismatch,
+        #[doc="Wraps a `PolicyViolation` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror PolicyViolation,
+        #[doc="Wraps a `StateHandoff` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror StateHandoff,
+        #[doc="Wraps an `UnloadTimeout` error in a [SharedlibError](enum.SharedlibError.html)."]
+        suberror UnloadTimeout
     }
 );
+
+impl SharedlibError {
+    /// Returns the kind of the error, as classified from the message reported by the operating system.
+    /// Errors which are not reported by the operating system are `Other`, except for `MissingSymbols`, which is `SymbolNotFound`.
+    pub fn kind(&self) -> ErrorKind {
+        match *self {
+            SharedlibError::LibraryClose(ref err) => err.kind(),
+            SharedlibError::LibraryFindSymbol(ref err) => err.kind(),
+            SharedlibError::LibraryOpen(ref err) => err.kind(),
+            SharedlibError::MissingSymbols(_) => ErrorKind::SymbolNotFound,
+            SharedlibError::OsError(ref err) => err.kind(),
+            _ => ErrorKind::Other,
+        }
+    }
+}
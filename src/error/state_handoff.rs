@@ -0,0 +1,59 @@
+use error::StateHandoffKind;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::path::PathBuf;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when the state of a [HotLib](../struct.HotLib.html) cannot be handed from the old generation to the new one. The reload is rolled back, so the old generation stays current.
+#[derive(Clone, Debug)]
+pub struct StateHandoff {
+    kind: StateHandoffKind,
+    path_to_lib: PathBuf,
+}
+
+impl StateHandoff {
+    /// Creates a new [StateHandoff](struct.StateHandoff.html).
+    pub fn new(kind: StateHandoffKind, path_to_lib: PathBuf) -> Self {
+        StateHandoff {
+            kind: kind,
+            path_to_lib: path_to_lib,
+        }
+    }
+
+    /// Returns the step which failed.
+    pub fn kind(&self) -> StateHandoffKind {
+        self.kind
+    }
+
+    /// Returns the path of the library which was being reloaded.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+}
+
+impl Display for StateHandoff {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            message(MessageId::StateHandoffDisplay1),
+            self.path_to_lib.to_string_lossy(),
+            message(MessageId::StateHandoffDisplay2),
+            self.kind,
+        )
+    }
+}
+
+impl Error for StateHandoff {
+    fn description(&self) -> &str {
+        message(MessageId::StateHandoffDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::os::raw::c_int;
+use string::message;
+use string::MessageId;
+
+/// The step of a state handoff which failed with a [StateHandoff](struct.StateHandoff.html) error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StateHandoffKind {
+    /// `sharedlib_save_state` of the old generation returned the contained non-zero code.
+    SaveFailed(c_int),
+    /// `sharedlib_restore_state` of the new generation does not understand the contained state version.
+    VersionMismatch(u32),
+    /// `sharedlib_restore_state` of the new generation returned the contained code, which is neither zero nor [STATE_VERSION_MISMATCH](../constant.STATE_VERSION_MISMATCH.html).
+    RestoreFailed(c_int),
+}
+
+impl Display for StateHandoffKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            StateHandoffKind::SaveFailed(code) => write!(f, "{}{}{}", message(MessageId::StateHandoffSaveFailed1), code, message(MessageId::StateHandoffSaveFailed2)),
+            StateHandoffKind::VersionMismatch(version) => write!(f, "{}{}{}", message(MessageId::StateHandoffVersionMismatch1), version, message(MessageId::StateHandoffVersionMismatch2)),
+            StateHandoffKind::RestoreFailed(code) => write!(f, "{}{}{}", message(MessageId::StateHandoffRestoreFailed1), code, message(MessageId::StateHandoffRestoreFailed2)),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::time::Duration;
+use string::message;
+use string::MessageId;
+
+/// An error which occurs when calls into a shared library are still running after the timeout given to [LibTracked::unload_when_idle](../struct.LibTracked.html#method.unload_when_idle). The library is left loaded.
+#[derive(Clone, Debug)]
+pub struct UnloadTimeout {
+    timeout: Duration,
+    in_flight: usize,
+}
+
+impl UnloadTimeout {
+    /// Creates a new [UnloadTimeout](struct.UnloadTimeout.html).
+    pub fn new(timeout: Duration, in_flight: usize) -> Self {
+        UnloadTimeout {
+            timeout: timeout,
+            in_flight: in_flight,
+        }
+    }
+
+    /// Returns how long the unload waited for calls to return.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the number of calls which were still running when the timeout elapsed.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight
+    }
+}
+
+impl Display for UnloadTimeout {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{:?}{}",
+            message(MessageId::UnloadTimeoutDisplay1),
+            self.in_flight,
+            message(MessageId::UnloadTimeoutDisplay2),
+            self.timeout,
+            message(MessageId::UnloadTimeoutDisplay3),
+        )
+    }
+}
+
+impl Error for UnloadTimeout {
+    fn description(&self) -> &str {
+        message(MessageId::UnloadTimeoutDescription)
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        None
+    }
+}
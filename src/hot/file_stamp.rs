@@ -0,0 +1,51 @@
+use std::fs;
+use std::io;
+#[cfg(unix)]
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+use std::time::SystemTime;
+
+// The parts of a file's metadata which change when a build replaces it. A build which writes a new
+// file and renames it over the old one keeps the length and may keep the modification time within
+// the filesystem's resolution, but always changes the inode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+    device: u64,
+    inode: u64,
+}
+
+impl FileStamp {
+    pub fn of(path: &Path) -> io::Result<FileStamp> {
+        let metadata = try!(fs::metadata(path));
+        let result =
+            FileStamp {
+                modified: metadata.modified().ok(),
+                len: metadata.len(),
+                device: FileStamp::device(&metadata),
+                inode: FileStamp::inode(&metadata),
+            };
+        Ok(result)
+    }
+
+    #[cfg(unix)]
+    fn device(metadata: &fs::Metadata) -> u64 {
+        metadata.dev()
+    }
+
+    #[cfg(not(unix))]
+    fn device(_metadata: &fs::Metadata) -> u64 {
+        0
+    }
+
+    #[cfg(unix)]
+    fn inode(metadata: &fs::Metadata) -> u64 {
+        metadata.ino()
+    }
+
+    #[cfg(not(unix))]
+    fn inode(_metadata: &fs::Metadata) -> u64 {
+        0
+    }
+}
@@ -0,0 +1,76 @@
+use FuncTracked;
+use hot::HotSlot;
+use LibUnsafe;
+use SharedlibResult as R;
+use std::sync::Arc;
+
+/// A function in a [HotLib](struct.HotLib.html) which follows the library across reloads.
+/// A [FuncTracked](struct.FuncTracked.html) always points into the generation it was found in. A [HotFunc](struct.HotFunc.html) is resolved by name through a slot which the [HotLib](struct.HotLib.html) swaps whenever it loads a new generation, so calls made through it always reach the newest code.
+///
+/// A call takes the function out of the slot together with a ref-count to its generation. A reload therefore never waits for calls to return, and a call which is in progress during a reload finishes in the old generation, which is released once no calls into it remain.
+///
+/// # Examples
+/// ``` no_run
+/// # use sharedlib::HotFunc;
+/// # use sharedlib::HotLib;
+/// # use sharedlib::LibUnsafe;
+/// # use sharedlib::SharedlibResult as R;
+/// # use std::sync::Arc;
+/// # fn test() -> R<()> {
+/// let lib: HotLib<Arc<LibUnsafe>> = try!(unsafe { HotLib::new("libexample.so") });
+/// let update: HotFunc<extern "C" fn(u32) -> u32, _> = try!(unsafe { lib.find_hot_func("update") });
+/// let result = try!(unsafe { update.call(|update| update(1)) });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HotFunc<T, TLib> {
+    slot: Arc<HotSlot<T, TLib>>,
+}
+
+impl <T, TLib> HotFunc<T, TLib>
+    where T: Copy,
+          TLib: Clone {
+    pub(crate) fn new(slot: Arc<HotSlot<T, TLib>>) -> Self {
+        HotFunc {
+            slot: slot,
+        }
+    }
+
+    /// Returns the name of the function.
+    pub fn name(&self) -> &str {
+        self.slot.name()
+    }
+
+    /// Returns the generation which calls made now reach.
+    pub fn generation(&self) -> u64 {
+        self.slot.current().0
+    }
+
+    /// Returns the function of the current generation. The returned [FuncTracked](struct.FuncTracked.html) keeps pointing into that generation after later reloads.
+    pub fn current(&self) -> FuncTracked<T, TLib> {
+        self.slot.current().1
+    }
+
+    /// Calls `call` with the function of the current generation, which stays loaded until `call` returns.
+    /// The call is counted as running inside that generation, as with [FuncTracked::call](struct.FuncTracked.html#method.call).
+    ///
+    /// # Errors
+    /// A `LibraryUnloaded` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant, without calling `call`, if the current generation is being unloaded or has been unloaded.
+    ///
+    /// # Safety
+    /// This function is not type safe so there is no guarntee that `T` is really the type of the symbol in every generation. Using a symbol as a `T` when the symbol is not really of type `T` causes undefined behavior. The function passed to `call` must not be kept after `call` returns.
+    pub unsafe fn call<TResult, TCall>(&self, call: TCall) -> R<TResult>
+        where TLib: AsRef<LibUnsafe>,
+              TCall: FnOnce(T) -> TResult {
+        self.current().call(call)
+    }
+}
+
+impl <T, TLib> Clone for HotFunc<T, TLib> {
+    fn clone(&self) -> Self {
+        HotFunc {
+            slot: self.slot.clone(),
+        }
+    }
+}
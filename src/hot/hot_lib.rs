@@ -0,0 +1,321 @@
+use DataTracked;
+use error::LibraryOpen;
+use FuncTracked;
+use hot::FileStamp;
+use hot::hand_off;
+use hot::HotFunc;
+use hot::HotSlot;
+use hot::Rebind;
+use hot::RESTORE_STATE_SYMBOL;
+use hot::RestoreState;
+use hot::SAVE_STATE_SYMBOL;
+use hot::SaveState;
+use LibTracked;
+use LibUnsafe;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+static SHADOW_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// A [LibTracked](struct.LibTracked.html) which follows a shared library file as it is rebuilt, so that a running program can pick up new builds of a plugin.
+/// The file is watched by polling its modification time, length, and inode. No background thread is started; a reload only happens when [reload_if_changed](#method.reload_if_changed) or [reload](#method.reload) is called.
+///
+/// Each build is copied to a unique shadow path before it is loaded. Loaders identify libraries by path, and on some platforms by inode, so loading the original path again would return the already loaded build. Shadow copies are placed next to the library, unless another directory is given, and are removed as soon as they are loaded on unix. On windows, a shadow copy cannot be removed while it is loaded, so removal is retried on every reload and when the [HotLib](struct.HotLib.html) is dropped. Copies which are still loaded at that point, including the one of the current generation, are left behind.
+///
+/// A library can keep its in-memory state across reloads by exporting two functions, `sharedlib_save_state` of type [SaveState](type.SaveState.html) and `sharedlib_restore_state` of type [RestoreState](type.RestoreState.html). When the current generation exports `sharedlib_save_state` and the new one exports `sharedlib_restore_state`, a reload passes the state, a versioned byte buffer, from the former to the latter. If either function fails, or the new generation does not support the version of the state, the reload is rolled back and the current generation stays current. If either function is missing, no state is handed over. No calls should be made into the library while it is reloaded, since the state could change after it was saved.
+///
+/// Every load is a new generation. Symbols found through a [HotLib](struct.HotLib.html) hold a ref-count to the generation they were found in, so an old generation stays loaded until its last [DataTracked](struct.DataTracked.html) or [FuncTracked](struct.FuncTracked.html) is dropped, even after newer generations have been loaded.
+///
+/// # Examples
+/// ``` no_run
+/// # use sharedlib::FuncArc;
+/// # use sharedlib::HotLib;
+/// # use sharedlib::LibUnsafe;
+/// # use sharedlib::SharedlibResult as R;
+/// # use sharedlib::Symbol;
+/// # use std::sync::Arc;
+/// # fn test() -> R<()> {
+/// let mut lib: HotLib<Arc<LibUnsafe>> = try!(unsafe { HotLib::new("libexample.so") });
+/// loop {
+///     if try!(unsafe { lib.reload_if_changed() }) {
+///         println!("loaded generation {}", lib.generation());
+///     }
+///     let update: FuncArc<extern "C" fn()> = try!(unsafe { lib.find_func("update") });
+///     unsafe { update.get()() };
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HotLib<TLib> {
+    path_to_lib: PathBuf,
+    shadow_dir: PathBuf,
+    stamp: FileStamp,
+    generation: u64,
+    lib: LibTracked<TLib>,
+    slots: Mutex<Vec<Weak<Rebind<TLib>>>>,
+    #[cfg(windows)]
+    stale_shadows: Vec<PathBuf>,
+}
+
+impl <TLib> HotLib<TLib>
+    where TLib: AsRef<LibUnsafe> + Clone + From<LibUnsafe> {
+    /// Loads the current build of the shared library at the specified path as generation 0. Shadow copies are placed in the directory of the library.
+    /// The path must name the library file itself; platform specific search paths are not used to find it.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem copying or opening the shared library.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to new is unsafe.
+    pub unsafe fn new<TPath>(path_to_lib: TPath) -> R<Self>
+        where TPath: AsRef<Path> {
+        let path_to_lib = path_to_lib.as_ref();
+        let shadow_dir =
+            match path_to_lib.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+                _ => PathBuf::from("."),
+            };
+        HotLib::new_with_shadow_dir(path_to_lib, shadow_dir)
+    }
+
+    /// Loads the current build of the shared library at the specified path as generation 0, placing shadow copies in `shadow_dir`.
+    /// See [new](#method.new) for more information.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem copying or opening the shared library.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to new is unsafe.
+    pub unsafe fn new_with_shadow_dir<TPath, TDir>(path_to_lib: TPath, shadow_dir: TDir) -> R<Self>
+        where TPath: AsRef<Path>,
+              TDir: AsRef<Path> {
+        let path_to_lib = path_to_lib.as_ref().to_path_buf();
+        let shadow_dir = shadow_dir.as_ref().to_path_buf();
+        let stamp = try!(FileStamp::of(&path_to_lib).map_err(|err| HotLib::<TLib>::open_error(err, &path_to_lib)));
+        let shadow = try!(HotLib::<TLib>::load_shadow(&path_to_lib, &shadow_dir));
+        let result =
+            HotLib {
+                path_to_lib: path_to_lib,
+                shadow_dir: shadow_dir,
+                stamp: stamp,
+                generation: 0,
+                lib: shadow.0,
+                slots: Mutex::new(Vec::new()),
+                #[cfg(windows)]
+                stale_shadows: vec![shadow.1],
+            };
+        Ok(result)
+    }
+
+    /// Returns the path of the watched library file.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+
+    /// Returns the generation of the currently loaded build. The first build is generation 0 and every reload adds one.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the currently loaded generation.
+    pub fn lib(&self) -> &LibTracked<TLib> {
+        &self.lib
+    }
+
+    /// Returns `true` if the library file has changed since the current generation was loaded.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the library file cannot be inspected. For instance, this may happen while a build has removed the file and not yet written the new one.
+    pub fn has_changed(&self) -> R<bool> {
+        let stamp = try!(FileStamp::of(&self.path_to_lib).map_err(|err| HotLib::<TLib>::open_error(err, &self.path_to_lib)));
+        Ok(stamp != self.stamp)
+    }
+
+    /// Loads a new generation if the library file has changed since the current generation was loaded. Returns `true` if a new generation was loaded.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem copying or opening the new build. The current generation stays loaded, and the new build is tried again on the next call.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to reload_if_changed is unsafe.
+    pub unsafe fn reload_if_changed(&mut self) -> R<bool> {
+        if try!(self.has_changed()) {
+            try!(self.reload());
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Loads the library file as a new generation, whether or not it has changed.
+    /// Symbols found in older generations keep them loaded until the symbols are dropped. Every live [HotFunc](struct.HotFunc.html) is resolved in the new generation before any of them is switched over, so either all of them reach the new generation or the reload fails.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem copying or opening the new build. A `LibraryFindSymbol` error will be returned if the new build lacks the function of a live [HotFunc](struct.HotFunc.html). A `StateHandoff` error will be returned if the state cannot be handed to the new build. In each case the current generation stays current.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to reload is unsafe.
+    pub unsafe fn reload(&mut self) -> R<()> {
+        // The stamp is taken before copying so that a build which lands during the copy is
+        // noticed by the next poll.
+        let stamp = try!(FileStamp::of(&self.path_to_lib).map_err(|err| HotLib::<TLib>::open_error(err, &self.path_to_lib)));
+        let shadow = try!(HotLib::<TLib>::load_shadow(&self.path_to_lib, &self.shadow_dir));
+        #[cfg(windows)]
+        self.stale_shadows.push(shadow.1);
+        let result = self.switch_to(shadow.0, stamp);
+        #[cfg(windows)]
+        self.remove_stale_shadows();
+        result
+    }
+
+    // Makes `lib` the current generation, or drops it if any step fails. Slots are only committed
+    // once every fallible step has succeeded, which is what rolls a failed reload back.
+    unsafe fn switch_to(&mut self, lib: LibTracked<TLib>, stamp: FileStamp) -> R<()> {
+        let live_slots: Vec<Arc<Rebind<TLib>>> = {
+            let mut slots = self.slots.lock().unwrap();
+            slots.retain(|slot| slot.upgrade().is_some());
+            slots.iter().filter_map(|slot| slot.upgrade()).collect()
+        };
+        let prepared =
+            live_slots
+                .iter()
+                .map(|slot| slot.prepare(&lib))
+                .collect::<R<Vec<()>>>()
+                .and_then(|_| self.hand_off_state(&lib));
+        if let Err(err) = prepared {
+            for slot in &live_slots {
+                slot.abort();
+            }
+            return Err(err);
+        }
+        self.stamp = stamp;
+        self.generation += 1;
+        for slot in &live_slots {
+            slot.commit(self.generation);
+        }
+        self.lib = lib;
+        Ok(())
+    }
+
+    // Passes the state of the current generation to `lib` if both take part in the handoff.
+    unsafe fn hand_off_state(&self, lib: &LibTracked<TLib>) -> R<()> {
+        let save = self.lib.as_ref().find_func::<SaveState, _>(SAVE_STATE_SYMBOL);
+        let restore = lib.as_ref().find_func::<RestoreState, _>(RESTORE_STATE_SYMBOL);
+        match (save, restore) {
+            (Ok(save), Ok(restore)) => hand_off(save, restore, &self.path_to_lib),
+            _ => Ok(()),
+        }
+    }
+
+    /// Finds and returns a data symbol within the current generation.
+    /// See [LibTracked::find_data](struct.LibTracked.html#method.find_data) for more information.
+    ///
+    /// # Errors
+    /// A `LibraryFindSymbol` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem finding the symbol. For instance, this may happen if the shared library does not contain the requested symbol.
+    ///
+    /// # Safety
+    /// This function is not type safe so there is no guarntee that `T` is really the type of the symbol. Using a symbol as a `T` when the symbol is not really of type `T` causes undefined behavior.
+    pub unsafe fn find_data<T, TStr>(&self, symbol: TStr) -> R<DataTracked<T, TLib>>
+        where TStr: AsRef<str> {
+        self.lib.find_data(symbol)
+    }
+
+    /// Finds and returns a function symbol within the current generation.
+    /// See [LibTracked::find_func](struct.LibTracked.html#method.find_func) for more information.
+    ///
+    /// # Errors
+    /// A `LibraryFindSymbol` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem finding the symbol. For instance, this may happen if the shared library does not contain the requested symbol.
+    ///
+    /// # Safety
+    /// This function is not type safe so there is no guarntee that `T` is really the type of the symbol. Using a symbol as a `T` when the symbol is not really of type `T` causes undefined behavior.
+    pub unsafe fn find_func<T, TStr>(&self, symbol: TStr) -> R<FuncTracked<T, TLib>>
+        where T: Copy,
+              TStr: AsRef<str> {
+        self.lib.find_func(symbol)
+    }
+
+    /// Finds a function within the current generation and returns a [HotFunc](struct.HotFunc.html) which follows it into every later generation.
+    ///
+    /// # Errors
+    /// A `LibraryFindSymbol` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem finding the symbol. For instance, this may happen if the shared library does not contain the requested symbol.
+    ///
+    /// # Safety
+    /// This function is not type safe so there is no guarntee that `T` is really the type of the symbol. Using a symbol as a `T` when the symbol is not really of type `T` causes undefined behavior.
+    pub unsafe fn find_hot_func<T, TStr>(&self, symbol: TStr) -> R<HotFunc<T, TLib>>
+        where T: Copy + 'static,
+              TLib: 'static,
+              TStr: AsRef<str> {
+        let func = try!(self.lib.find_func::<T, _>(symbol.as_ref()));
+        let slot = Arc::new(HotSlot::new(symbol.as_ref().to_string(), self.generation, func));
+        let mut slots = self.slots.lock().unwrap();
+        slots.retain(|slot| slot.upgrade().is_some());
+        let rebind: Arc<Rebind<TLib>> = slot.clone();
+        slots.push(Arc::downgrade(&rebind));
+        Ok(HotFunc::new(slot))
+    }
+
+    // Copies the library to a fresh shadow path and loads the copy.
+    unsafe fn load_shadow(path_to_lib: &Path, shadow_dir: &Path) -> R<(LibTracked<TLib>, PathBuf)> {
+        let shadow = HotLib::<TLib>::shadow_path(path_to_lib, shadow_dir);
+        try!(fs::copy(path_to_lib, &shadow).map_err(|err| HotLib::<TLib>::open_error(err, path_to_lib)));
+        let lib = LibUnsafe::new(&shadow);
+        // The loader keeps its own mapping of the copy, so it is not needed on disk any more.
+        #[cfg(unix)]
+        let _ = fs::remove_file(&shadow);
+        #[cfg(windows)]
+        {
+            if lib.is_err() {
+                let _ = fs::remove_file(&shadow);
+            }
+        }
+        let lib = try!(lib);
+        Ok((LibTracked::from(lib), shadow))
+    }
+
+    // Returns `<stem>.hot-<pid>-<count>.<extension>` inside `shadow_dir`. The extension is kept
+    // because some loaders add a default one to paths which have none.
+    fn shadow_path(path_to_lib: &Path, shadow_dir: &Path) -> PathBuf {
+        let count = SHADOW_COUNT.fetch_add(1, Ordering::SeqCst);
+        let mut file_name = OsString::new();
+        if let Some(stem) = path_to_lib.file_stem() {
+            file_name.push(stem);
+        }
+        file_name.push(format!(".hot-{}-{}", process::id(), count));
+        if let Some(extension) = path_to_lib.extension() {
+            file_name.push(".");
+            file_name.push(extension);
+        }
+        shadow_dir.join(file_name)
+    }
+
+    #[cfg(windows)]
+    fn remove_stale_shadows(&mut self) {
+        self.stale_shadows.retain(|shadow| fs::remove_file(shadow).is_err());
+    }
+
+    fn open_error(err: io::Error, path_to_lib: &Path) -> E {
+        let err = LibraryOpen::new(Box::new(err), path_to_lib.to_path_buf());
+        E::from(err)
+    }
+}
+
+#[cfg(windows)]
+impl <TLib> Drop for HotLib<TLib> {
+    fn drop(&mut self) {
+        // Copies which are still loaded through a symbol cannot be removed yet and are left behind.
+        for shadow in &self.stale_shadows {
+            let _ = fs::remove_file(shadow);
+        }
+    }
+}
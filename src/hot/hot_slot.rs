@@ -0,0 +1,65 @@
+use FuncTracked;
+use hot::Rebind;
+use LibTracked;
+use LibUnsafe;
+use SharedlibResult as R;
+use std::mem;
+use std::sync::Mutex;
+use std::sync::RwLock;
+
+// The indirection slot behind a [HotFunc](struct.HotFunc.html). Readers take a clone of the
+// current function, which holds a ref-count to its generation, so a swap never waits for calls to
+// finish and an old generation is released when the last call into it returns.
+#[derive(Debug)]
+pub struct HotSlot<T, TLib> {
+    name: String,
+    current: RwLock<(u64, FuncTracked<T, TLib>)>,
+    pending: Mutex<Option<FuncTracked<T, TLib>>>,
+}
+
+impl <T, TLib> HotSlot<T, TLib>
+    where T: Copy,
+          TLib: Clone {
+    pub fn new(name: String, generation: u64, func: FuncTracked<T, TLib>) -> Self {
+        HotSlot {
+            name: name,
+            current: RwLock::new((generation, func)),
+            pending: Mutex::new(None),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn current(&self) -> (u64, FuncTracked<T, TLib>) {
+        let current = self.current.read().unwrap();
+        (current.0, current.1.clone())
+    }
+}
+
+impl <T, TLib> Rebind<TLib> for HotSlot<T, TLib>
+    where T: Copy,
+          TLib: AsRef<LibUnsafe> + Clone + From<LibUnsafe> {
+    unsafe fn prepare(&self, lib: &LibTracked<TLib>) -> R<()> {
+        let func = try!(lib.find_func::<T, _>(&self.name));
+        *self.pending.lock().unwrap() = Some(func);
+        Ok(())
+    }
+
+    fn commit(&self, generation: u64) {
+        if let Some(func) = self.pending.lock().unwrap().take() {
+            // The old function is dropped after the lock is released, in case it holds the last
+            // ref-count to its generation and unloading it takes a while.
+            let old = {
+                let mut current = self.current.write().unwrap();
+                mem::replace(&mut *current, (generation, func))
+            };
+            drop(old);
+        }
+    }
+
+    fn abort(&self) {
+        self.pending.lock().unwrap().take();
+    }
+}
@@ -0,0 +1,35 @@
+pub mod file_stamp;
+
+pub mod hot_func;
+
+pub mod hot_lib;
+
+pub mod hot_slot;
+
+pub mod rebind;
+
+pub mod state_handoff;
+
+pub use hot::file_stamp::FileStamp;
+
+pub use hot::hot_func::HotFunc;
+
+pub use hot::hot_lib::HotLib;
+
+pub use hot::hot_slot::HotSlot;
+
+pub use hot::rebind::Rebind;
+
+pub use hot::state_handoff::hand_off;
+
+pub use hot::state_handoff::RESTORE_STATE_SYMBOL;
+
+pub use hot::state_handoff::RestoreState;
+
+pub use hot::state_handoff::SAVE_STATE_SYMBOL;
+
+pub use hot::state_handoff::SaveState;
+
+pub use hot::state_handoff::STATE_VERSION_MISMATCH;
+
+pub use hot::state_handoff::StateWriter;
@@ -0,0 +1,16 @@
+use LibTracked;
+use SharedlibResult as R;
+
+// Something which has resolved a symbol in one generation of a [HotLib](struct.HotLib.html) and
+// has to resolve it again when a new generation is loaded. Rebinding happens in two phases so
+// that a reload either moves every slot to the new generation or none of them.
+pub trait Rebind<TLib> {
+    // Resolves the symbol in `lib` and holds on to it without publishing it.
+    unsafe fn prepare(&self, lib: &LibTracked<TLib>) -> R<()>;
+
+    // Publishes the symbol resolved by `prepare`.
+    fn commit(&self, generation: u64);
+
+    // Drops the symbol resolved by `prepare`.
+    fn abort(&self);
+}
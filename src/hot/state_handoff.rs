@@ -0,0 +1,56 @@
+use error::StateHandoff;
+use error::StateHandoffKind;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::os::raw::c_int;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::slice;
+
+/// The name of the function which the old generation of a [HotLib](struct.HotLib.html) exports to save its state. Its type is [SaveState](type.SaveState.html).
+pub const SAVE_STATE_SYMBOL: &str = "sharedlib_save_state\0";
+
+/// The name of the function which the new generation of a [HotLib](struct.HotLib.html) exports to restore the state. Its type is [RestoreState](type.RestoreState.html).
+pub const RESTORE_STATE_SYMBOL: &str = "sharedlib_restore_state\0";
+
+/// The code which `sharedlib_restore_state` returns when it does not support the version of the state it was given.
+pub const STATE_VERSION_MISMATCH: c_int = 1;
+
+/// The function which `sharedlib_save_state` calls to append bytes to the saved state. It may be called any number of times, and only during the call to `sharedlib_save_state`.
+pub type StateWriter = unsafe extern "C" fn(context: *mut c_void, data: *const u8, len: usize);
+
+/// The type of `sharedlib_save_state`. It stores the version of its state format in `version`, passes the state to `write` together with `context`, and returns zero on success. It must leave its own state intact, since the old generation stays current if the handoff fails.
+pub type SaveState = unsafe extern "C" fn(version: *mut u32, write: StateWriter, context: *mut c_void) -> c_int;
+
+/// The type of `sharedlib_restore_state`. It receives the version and the bytes written by `sharedlib_save_state`, and returns zero on success, [STATE_VERSION_MISMATCH](constant.STATE_VERSION_MISMATCH.html) if it does not support the version, or any other code if it fails. The bytes are only valid during the call.
+pub type RestoreState = unsafe extern "C" fn(version: u32, data: *const u8, len: usize) -> c_int;
+
+unsafe extern "C" fn append(context: *mut c_void, data: *const u8, len: usize) {
+    if len != 0 {
+        let buffer = &mut *(context as *mut Vec<u8>);
+        buffer.extend_from_slice(slice::from_raw_parts(data, len));
+    }
+}
+
+// Saves the state with `save` and restores it with `restore`.
+pub unsafe fn hand_off(save: SaveState, restore: RestoreState, path_to_lib: &Path) -> R<()> {
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut version = 0;
+    let context = &mut buffer as *mut Vec<u8> as *mut c_void;
+    let code = save(&mut version, append, context);
+    if code != 0 {
+        return Err(handoff_error(StateHandoffKind::SaveFailed(code), path_to_lib));
+    }
+
+    let code = restore(version, buffer.as_ptr(), buffer.len());
+    match code {
+        0 => Ok(()),
+        STATE_VERSION_MISMATCH => Err(handoff_error(StateHandoffKind::VersionMismatch(version), path_to_lib)),
+        code => Err(handoff_error(StateHandoffKind::RestoreFailed(code), path_to_lib)),
+    }
+}
+
+fn handoff_error(kind: StateHandoffKind, path_to_lib: &Path) -> E {
+    let err = StateHandoff::new(kind, path_to_lib.to_path_buf());
+    E::from(err)
+}
@@ -0,0 +1,34 @@
+/// An entry of the dynamic symbol table of an [ElfFile](struct.ElfFile.html).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DynamicSymbol {
+    binding: u8,
+    defined: bool,
+    name: String,
+}
+
+impl DynamicSymbol {
+    /// Creates a new [DynamicSymbol](struct.DynamicSymbol.html).
+    /// This should only be called within the library.
+    pub fn new(name: String, binding: u8, defined: bool) -> Self {
+        DynamicSymbol {
+            binding: binding,
+            defined: defined,
+            name: name,
+        }
+    }
+
+    /// Returns the name of the symbol.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the raw `STB_*` binding of the symbol, for instance `1` for `STB_GLOBAL`.
+    pub fn binding(&self) -> u8 {
+        self.binding
+    }
+
+    /// Returns true if the symbol is defined by the file, and false if the file imports it from another object.
+    pub fn is_defined(&self) -> bool {
+        self.defined
+    }
+}
@@ -0,0 +1,367 @@
+use error::InvalidElf;
+use error::LibraryOpen;
+use inspect::DynamicSymbol;
+use inspect::Hardening;
+use inspect::Relro;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::cmp;
+use std::fs::File;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+use string::MessageId;
+use util;
+
+const PT_DYNAMIC: u32 = 2;
+const PT_GNU_STACK: u32 = 0x6474e551;
+const PT_GNU_RELRO: u32 = 0x6474e552;
+const PF_X: u32 = 1;
+
+const SHT_DYNSYM: u32 = 11;
+
+const DT_NULL: u64 = 0;
+const DT_TEXTREL: u64 = 22;
+const DT_BIND_NOW: u64 = 24;
+const DT_FLAGS: u64 = 30;
+const DT_FLAGS_1: u64 = 0x6ffffffb;
+const DF_TEXTREL: u64 = 0x4;
+const DF_BIND_NOW: u64 = 0x8;
+const DF_1_NOW: u64 = 0x1;
+
+/// An ELF shared object which has been read from disk but not loaded.
+#[derive(Clone, Debug)]
+pub struct ElfFile {
+    dynamic: Vec<(u64, u64)>,
+    dynamic_symbols: Vec<DynamicSymbol>,
+    gnu_relro: bool,
+    gnu_stack_flags: Option<u32>,
+}
+
+impl ElfFile {
+    /// Reads and parses the ELF object at the specified path.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](../enum.SharedlibError.html) variant if the file cannot be read. An `InvalidElf` error will be returned if the file is not a valid ELF object.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::inspect::ElfFile;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// let elf = try!(ElfFile::open("/usr/lib/libexample.so"));
+    /// println!("{:?}", elf.hardening());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open<TPath>(path: TPath) -> R<ElfFile>
+        where TPath: AsRef<Path> {
+        let path = path.as_ref();
+        let mut file =
+            try!(
+                File::open(path)
+                    .map_err(
+                        |err| {
+                            let err = LibraryOpen::new(Box::new(err), path.to_path_buf());
+                            E::from(err)
+                        }
+                    )
+            );
+        ElfFile::read(&mut file, path)
+    }
+
+    /// Reads and parses the ELF object in an open file, starting from the beginning of the file.
+    /// `path` is only used for error reporting. Passing `&mut &File` reads through a shared reference to a file.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](../enum.SharedlibError.html) variant if the file cannot be read. An `InvalidElf` error will be returned if the file is not a valid ELF object.
+    pub fn read<TFile>(file: &mut TFile, path: &Path) -> R<ElfFile>
+        where TFile: Read + Seek {
+        let mut bytes = Vec::new();
+        try!(
+            file.seek(SeekFrom::Start(0))
+                .and_then(|_| file.read_to_end(&mut bytes))
+                .map_err(
+                    |err| {
+                        let err = LibraryOpen::new(Box::new(err), path.to_path_buf());
+                        E::from(err)
+                    }
+                )
+        );
+        ElfFile::parse(&bytes)
+    }
+
+    /// Parses an ELF object from its bytes.
+    ///
+    /// # Errors
+    /// An `InvalidElf` error will be returned as a [SharedlibError](../enum.SharedlibError.html) variant if the bytes are not a valid ELF object.
+    pub fn parse(bytes: &[u8]) -> R<ElfFile> {
+        let reader = try!(Reader::new(bytes));
+
+        let phoff = try!(reader.word_at(0, if reader.is_64 { 0x20 } else { 0x1c }));
+        let shoff = try!(reader.word_at(0, if reader.is_64 { 0x28 } else { 0x20 }));
+        let phentsize = try!(reader.u16_at(0, if reader.is_64 { 0x36 } else { 0x2a })) as u64;
+        let phnum = try!(reader.u16_at(0, if reader.is_64 { 0x38 } else { 0x2c })) as u64;
+        let shentsize = try!(reader.u16_at(0, if reader.is_64 { 0x3a } else { 0x2e })) as u64;
+        let shnum = try!(reader.u16_at(0, if reader.is_64 { 0x3c } else { 0x30 })) as u64;
+
+        let mut dynamic = Vec::new();
+        let mut gnu_relro = false;
+        let mut gnu_stack_flags = None;
+        for index in 0..phnum {
+            let header = try!(reader.offset(phoff, index * phentsize));
+            let p_type = try!(reader.u32_at(header, 0));
+            let p_flags = try!(reader.u32_at(header, if reader.is_64 { 0x04 } else { 0x18 }));
+            match p_type {
+                PT_DYNAMIC => {
+                    let p_offset = try!(reader.word_at(header, if reader.is_64 { 0x08 } else { 0x04 }));
+                    let p_filesz = try!(reader.word_at(header, if reader.is_64 { 0x20 } else { 0x10 }));
+                    let entry_size = reader.word_size() * 2;
+                    for entry in 0..(p_filesz / entry_size) {
+                        let entry = try!(reader.offset(p_offset, entry * entry_size));
+                        let tag = try!(reader.word_at(entry, 0));
+                        if tag == DT_NULL {
+                            break;
+                        }
+                        let value = try!(reader.word_at(entry, reader.word_size()));
+                        dynamic.push((tag, value));
+                    }
+                },
+                PT_GNU_STACK => gnu_stack_flags = Some(p_flags),
+                PT_GNU_RELRO => gnu_relro = true,
+                _ => (),
+            }
+        }
+
+        let mut dynamic_symbols = Vec::new();
+        for index in 0..shnum {
+            let header = try!(reader.offset(shoff, index * shentsize));
+            let sh_type = try!(reader.u32_at(header, 0x04));
+            if sh_type != SHT_DYNSYM {
+                continue;
+            }
+            let sh_offset = try!(reader.word_at(header, if reader.is_64 { 0x18 } else { 0x10 }));
+            let sh_size = try!(reader.word_at(header, if reader.is_64 { 0x20 } else { 0x14 }));
+            let sh_link = try!(reader.u32_at(header, if reader.is_64 { 0x28 } else { 0x18 })) as u64;
+            let sh_entsize = try!(reader.word_at(header, if reader.is_64 { 0x38 } else { 0x24 }));
+            if sh_entsize == 0 {
+                continue;
+            }
+
+            let strtab_header = try!(reader.offset(shoff, sh_link * shentsize));
+            let strtab_offset = try!(reader.word_at(strtab_header, if reader.is_64 { 0x18 } else { 0x10 }));
+
+            // The first entry of a symbol table is always the undefined symbol.
+            for symbol in 1..(sh_size / sh_entsize) {
+                let symbol = try!(reader.offset(sh_offset, symbol * sh_entsize));
+                let st_name = try!(reader.u32_at(symbol, 0)) as u64;
+                let st_info = try!(reader.u8_at(symbol, if reader.is_64 { 0x04 } else { 0x0c }));
+                let st_shndx = try!(reader.u16_at(symbol, if reader.is_64 { 0x06 } else { 0x0e }));
+                let name = try!(reader.str_at(strtab_offset, st_name));
+                let symbol = DynamicSymbol::new(name, st_info >> 4, st_shndx != 0);
+                dynamic_symbols.push(symbol);
+            }
+        }
+
+        let result =
+            ElfFile {
+                dynamic: dynamic,
+                dynamic_symbols: dynamic_symbols,
+                gnu_relro: gnu_relro,
+                gnu_stack_flags: gnu_stack_flags,
+            };
+        Ok(result)
+    }
+
+    /// Returns the entries of the dynamic symbol table, both the symbols the object defines and the ones it imports.
+    pub fn dynamic_symbols(&self) -> &[DynamicSymbol] {
+        &self.dynamic_symbols
+    }
+
+    /// Returns the names of the defined symbols which most resemble `symbol`, closest first, for suggesting what a missing symbol was meant to be.
+    /// A symbol whose demangled name is `symbol`, or whose name is what `symbol` demangles to, ranks first. This catches a missing `extern "C"` on either side. Other symbols rank by their edit distance to `symbol`, and only close ones are returned. At most three names are returned.
+    pub fn similar_symbols(&self, symbol: &str) -> Vec<String> {
+        let symbol = symbol.trim_end_matches('\0');
+        let symbol_demangled = util::demangle(symbol);
+        // Allows one edit for every three characters, so that short names do not match everything.
+        let max_distance = cmp::max(1, symbol.chars().count() / 3);
+        let mut ranked: Vec<(usize, &str)> =
+            self.dynamic_symbols
+                .iter()
+                .filter(|candidate| candidate.is_defined() && !candidate.name().is_empty() && candidate.name() != symbol)
+                .filter_map(
+                    |candidate| {
+                        let name = candidate.name();
+                        let demangled = util::demangle(name);
+                        let base = demangled.as_ref().map(|demangled| demangled.rsplit("::").next().unwrap_or(demangled));
+                        if demangled.as_deref() == Some(symbol) ||
+                            base == Some(symbol) ||
+                            symbol_demangled.as_deref() == Some(name) {
+                            return Some((0, name));
+                        }
+                        let distance =
+                            match base {
+                                Some(base) => cmp::min(util::edit_distance(symbol, name), util::edit_distance(symbol, base)),
+                                None => util::edit_distance(symbol, name),
+                            };
+                        if distance <= max_distance {
+                            Some((distance, name))
+                        } else {
+                            None
+                        }
+                    }
+                )
+                .collect();
+        ranked.sort();
+        ranked.dedup();
+        ranked.into_iter().take(3).map(|(_, name)| name.to_string()).collect()
+    }
+
+    /// Reports the hardening features of the object, much like `checksec` does.
+    pub fn hardening(&self) -> Hardening {
+        let flags = self.dynamic_value(DT_FLAGS).unwrap_or(0);
+        let flags_1 = self.dynamic_value(DT_FLAGS_1).unwrap_or(0);
+
+        let bind_now =
+            self.dynamic_value(DT_BIND_NOW).is_some() ||
+            flags & DF_BIND_NOW != 0 ||
+            flags_1 & DF_1_NOW != 0;
+        let text_relocations =
+            self.dynamic_value(DT_TEXTREL).is_some() ||
+            flags & DF_TEXTREL != 0;
+        let relro =
+            match (self.gnu_relro, bind_now) {
+                (false, _) => Relro::None,
+                (true, false) => Relro::Partial,
+                (true, true) => Relro::Full,
+            };
+        // Without a `PT_GNU_STACK` header, loaders fall back to an executable stack.
+        let nx_stack =
+            match self.gnu_stack_flags {
+                Some(flags) => flags & PF_X == 0,
+                None => false,
+            };
+
+        let imports = self.dynamic_symbols.iter().filter(|symbol| !symbol.is_defined());
+        let mut stack_protector = false;
+        let mut fortify = false;
+        for symbol in imports {
+            let name = symbol.name();
+            if name == "__stack_chk_fail" || name == "__stack_chk_guard" {
+                stack_protector = true;
+            } else if name.starts_with("__") && name.ends_with("_chk") {
+                fortify = true;
+            }
+        }
+
+        Hardening::new(relro, nx_stack, bind_now, text_relocations, stack_protector, fortify)
+    }
+
+    fn dynamic_value(&self, tag: u64) -> Option<u64> {
+        self.dynamic
+            .iter()
+            .find(|&&(entry_tag, _)| entry_tag == tag)
+            .map(|&(_, value)| value)
+    }
+}
+
+struct Reader<'a> {
+    big_endian: bool,
+    bytes: &'a [u8],
+    is_64: bool,
+}
+
+impl <'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> R<Reader<'a>> {
+        if bytes.len() < 0x34 {
+            return Reader::invalid(MessageId::InvalidElfTruncated);
+        }
+        if &bytes[0..4] != b"\x7fELF" {
+            return Reader::invalid(MessageId::InvalidElfBadMagic);
+        }
+        let is_64 =
+            match bytes[4] {
+                1 => false,
+                2 => true,
+                _ => return Reader::invalid(MessageId::InvalidElfUnsupportedClass),
+            };
+        let big_endian =
+            match bytes[5] {
+                1 => false,
+                2 => true,
+                _ => return Reader::invalid(MessageId::InvalidElfUnsupportedEncoding),
+            };
+        let result =
+            Reader {
+                big_endian: big_endian,
+                bytes: bytes,
+                is_64: is_64,
+            };
+        Ok(result)
+    }
+
+    fn invalid<T>(reason: MessageId) -> R<T> {
+        let err = InvalidElf::new(reason);
+        Err(E::from(err))
+    }
+
+    fn word_size(&self) -> u64 {
+        if self.is_64 { 8 } else { 4 }
+    }
+
+    fn offset(&self, base: u64, offset: u64) -> R<u64> {
+        match base.checked_add(offset) {
+            Some(result) => Ok(result),
+            None => Reader::invalid(MessageId::InvalidElfTruncated),
+        }
+    }
+
+    fn slice(&self, offset: u64, len: u64) -> R<&'a [u8]> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.bytes.len() as u64 => Ok(&self.bytes[offset as usize..end as usize]),
+            _ => Reader::invalid(MessageId::InvalidElfTruncated),
+        }
+    }
+
+    fn uint_at(&self, base: u64, offset: u64, len: u64) -> R<u64> {
+        let offset = try!(self.offset(base, offset));
+        let bytes = try!(self.slice(offset, len));
+        let mut result = 0;
+        if self.big_endian {
+            for byte in bytes {
+                result = result << 8 | *byte as u64;
+            }
+        } else {
+            for byte in bytes.iter().rev() {
+                result = result << 8 | *byte as u64;
+            }
+        }
+        Ok(result)
+    }
+
+    fn u8_at(&self, base: u64, offset: u64) -> R<u8> {
+        self.uint_at(base, offset, 1).map(|value| value as u8)
+    }
+
+    fn u16_at(&self, base: u64, offset: u64) -> R<u16> {
+        self.uint_at(base, offset, 2).map(|value| value as u16)
+    }
+
+    fn u32_at(&self, base: u64, offset: u64) -> R<u32> {
+        self.uint_at(base, offset, 4).map(|value| value as u32)
+    }
+
+    fn word_at(&self, base: u64, offset: u64) -> R<u64> {
+        self.uint_at(base, offset, self.word_size())
+    }
+
+    fn str_at(&self, base: u64, offset: u64) -> R<String> {
+        let offset = try!(self.offset(base, offset));
+        let rest = try!(self.slice(offset, 0).map(|_| &self.bytes[offset as usize..]));
+        match rest.iter().position(|byte| *byte == 0) {
+            Some(len) => Ok(String::from_utf8_lossy(&rest[..len]).into_owned()),
+            None => Reader::invalid(MessageId::InvalidElfTruncated),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use inspect::HardeningLevel;
+use inspect::Relro;
+
+/// The hardening features of a shared library, as reported by [ElfFile::hardening](struct.ElfFile.html#method.hardening).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Hardening {
+    bind_now: bool,
+    fortify: bool,
+    nx_stack: bool,
+    relro: Relro,
+    stack_protector: bool,
+    text_relocations: bool,
+}
+
+impl Hardening {
+    pub(crate) fn new(relro: Relro, nx_stack: bool, bind_now: bool, text_relocations: bool, stack_protector: bool, fortify: bool) -> Self {
+        Hardening {
+            bind_now: bind_now,
+            fortify: fortify,
+            nx_stack: nx_stack,
+            relro: relro,
+            stack_protector: stack_protector,
+            text_relocations: text_relocations,
+        }
+    }
+
+    /// Returns how much of the library is made read-only after relocation.
+    pub fn relro(&self) -> Relro {
+        self.relro
+    }
+
+    /// Returns true if the library asks for a non-executable stack.
+    pub fn nx_stack(&self) -> bool {
+        self.nx_stack
+    }
+
+    /// Returns true if the library asks for all of its symbols to be bound at load time.
+    pub fn bind_now(&self) -> bool {
+        self.bind_now
+    }
+
+    /// Returns true if the library needs its code to be written to while it is relocated.
+    pub fn text_relocations(&self) -> bool {
+        self.text_relocations
+    }
+
+    /// Returns true if the library references the stack protector's failure handler.
+    pub fn stack_protector(&self) -> bool {
+        self.stack_protector
+    }
+
+    /// Returns true if the library references any of the checked `__*_chk` functions emitted by `_FORTIFY_SOURCE`.
+    pub fn fortify(&self) -> bool {
+        self.fortify
+    }
+
+    /// Summarizes the features which are decided by how the library was linked.
+    /// The stack protector and `_FORTIFY_SOURCE` only show up in libraries which contain code that benefits from them, so they do not affect the level.
+    pub fn level(&self) -> HardeningLevel {
+        if !self.nx_stack || self.text_relocations || self.relro == Relro::None {
+            HardeningLevel::None
+        } else if self.relro == Relro::Partial {
+            HardeningLevel::Partial
+        } else {
+            HardeningLevel::Full
+        }
+    }
+}
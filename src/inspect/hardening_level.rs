@@ -0,0 +1,24 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// A summary of the hardening of a shared library, ordered from weakest to strongest.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum HardeningLevel {
+    /// The library has an executable stack, has text relocations, or has no RELRO.
+    None,
+    /// The library has a non-executable stack, no text relocations, and at least partial RELRO.
+    Partial,
+    /// The library meets the [Partial](#variant.Partial) level and also has full RELRO.
+    Full,
+}
+
+impl Display for HardeningLevel {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            HardeningLevel::None => write!(f, "none"),
+            HardeningLevel::Partial => write!(f, "partial"),
+            HardeningLevel::Full => write!(f, "full"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Defines tools which inspect shared libraries without loading them.
+
+mod dynamic_symbol;
+
+mod elf_file;
+
+mod hardening;
+
+mod hardening_level;
+
+mod relro;
+
+pub use inspect::dynamic_symbol::DynamicSymbol;
+
+pub use inspect::elf_file::ElfFile;
+
+pub use inspect::hardening::Hardening;
+
+pub use inspect::hardening_level::HardeningLevel;
+
+pub use inspect::relro::Relro;
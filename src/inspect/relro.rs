@@ -0,0 +1,10 @@
+/// How much of a shared library is made read-only after relocation.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Relro {
+    /// Nothing is made read-only after relocation.
+    None,
+    /// Some relocated data is made read-only but the procedure linkage table stays writable because symbols are bound lazily.
+    Partial,
+    /// All relocated data, including the procedure linkage table, is made read-only because every symbol is bound at load time.
+    Full,
+}
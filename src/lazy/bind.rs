@@ -0,0 +1,86 @@
+/// Binds functions from a shared library which is opened the first time one of them is called, without linking against the library.
+/// This is a replacement for weak linking: the program starts whether or not the library exists, and asks whether it does at runtime. The macro should be invoked inside a module of its own, which then contains:
+///
+/// * A function for each declared function, which takes the same arguments and returns a [SharedlibResult](type.SharedlibResult.html) of the declared return type. The library is opened through a [LazyLib](struct.LazyLib.html) on the first call, and each symbol is found through a [LazySymbol](struct.LazySymbol.html) on its first call.
+/// * `is_available() -> bool`, which returns whether the library could be opened.
+/// * `load_error() -> Option<SharedlibError>`, which returns the error which occurred when the library was opened.
+///
+/// Functions declared as `fn` are safe to call, so the signature given to the macro is trusted to be both correct and safe for every argument. Functions which take pointers, or which are unsafe to call for any other reason, should be declared as `unsafe fn`. A function whose symbol is missing returns a `LibraryFindSymbol` error instead of being called.
+///
+/// # Examples
+/// ``` no_run
+/// # #[macro_use] extern crate sharedlib;
+/// mod zlib {
+///     use std::os::raw::c_char;
+///     use std::os::raw::c_int;
+///     use std::os::raw::c_ulong;
+///
+///     bind! {
+///         library "libz.so.1";
+///         unsafe fn compress(dest: *mut u8, dest_len: *mut c_ulong, source: *const u8, source_len: c_ulong) -> c_int;
+///         fn compressBound(source_len: c_ulong) -> c_ulong;
+///         fn zlibVersion() -> *const c_char;
+///     }
+/// }
+///
+/// # fn main() {
+/// if zlib::is_available() {
+///     let bound = zlib::compressBound(1024).unwrap();
+///     println!("compressing 1024 bytes needs at most {} bytes", bound);
+/// } else {
+///     println!("zlib is not available: {}", zlib::load_error().unwrap());
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bind {
+    (library $path:expr; $($rest:tt)*) => {
+        static SHAREDLIB_BIND_LIB: $crate::LazyLib = $crate::LazyLib::new($path);
+
+        /// Returns whether the bound library could be opened. The library is opened if this is the first use.
+        #[allow(dead_code)]
+        pub fn is_available() -> bool {
+            unsafe { SHAREDLIB_BIND_LIB.is_available() }
+        }
+
+        /// Returns the error which occurred when the bound library was opened, or `None` if it opened. The library is opened if this is the first use.
+        #[allow(dead_code)]
+        pub fn load_error() -> ::std::option::Option<$crate::SharedlibError> {
+            unsafe { SHAREDLIB_BIND_LIB.load_error() }
+        }
+
+        $crate::bind!(@fns $($rest)*);
+    };
+    (@fns) => {};
+    (@fns $(#[$attr:meta])* fn $name:ident($($arg:ident: $arg_ty:ty),* $(,)*) $(-> $ret:ty)*; $($rest:tt)*) => {
+        $(#[$attr])*
+        #[allow(dead_code, non_snake_case, unused_parens)]
+        pub fn $name($($arg: $arg_ty),*) -> $crate::SharedlibResult<($($ret)*)> {
+            unsafe { $crate::bind!(@call $name($($arg: $arg_ty),*) -> ($($ret)*)) }
+        }
+
+        $crate::bind!(@fns $($rest)*);
+    };
+    (@fns $(#[$attr:meta])* unsafe fn $name:ident($($arg:ident: $arg_ty:ty),* $(,)*) $(-> $ret:ty)*; $($rest:tt)*) => {
+        $(#[$attr])*
+        #[allow(dead_code, non_snake_case, unused_parens)]
+        pub unsafe fn $name($($arg: $arg_ty),*) -> $crate::SharedlibResult<($($ret)*)> {
+            $crate::bind!(@call $name($($arg: $arg_ty),*) -> ($($ret)*))
+        }
+
+        $crate::bind!(@fns $($rest)*);
+    };
+    (@call $name:ident($($arg:ident: $arg_ty:ty),*) -> $ret:ty) => {
+        {
+            static SHAREDLIB_BIND_SYMBOL: $crate::LazySymbol = $crate::LazySymbol::new();
+            let symbol = SHAREDLIB_BIND_SYMBOL.get::<unsafe extern "C" fn($($arg_ty),*) -> $ret>(
+                &SHAREDLIB_BIND_LIB,
+                concat!(stringify!($name), "\0"),
+            );
+            match symbol {
+                ::std::result::Result::Ok(func) => ::std::result::Result::Ok(func($($arg),*)),
+                ::std::result::Result::Err(err) => ::std::result::Result::Err(err),
+            }
+        }
+    };
+}
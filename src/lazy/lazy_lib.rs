@@ -0,0 +1,68 @@
+use LibUnsafe;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::cell::UnsafeCell;
+use std::sync::Once;
+
+/// A shared library which is opened the first time it is used and then stays open for the rest of the program. It is used by [bind!](macro.bind.html).
+/// If the library fails to open, the failure is remembered and reported again on every use without trying to open the library again.
+pub struct LazyLib {
+    path_to_lib: &'static str,
+    once: Once,
+    state: UnsafeCell<Option<R<LibUnsafe>>>,
+}
+
+// The state is only written inside `once`, and only read after `once` has completed.
+unsafe impl Sync for LazyLib { }
+
+impl LazyLib {
+    /// Creates a new [LazyLib](struct.LazyLib.html) for the shared library at the specified path. The library is not opened yet.
+    pub const fn new(path_to_lib: &'static str) -> Self {
+        LazyLib {
+            path_to_lib: path_to_lib,
+            once: Once::new(),
+            state: UnsafeCell::new(None),
+        }
+    }
+
+    /// Returns the shared library, opening it if this is the first use.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the shared library could not be opened, whether on this use or on the first one.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library.
+    pub unsafe fn get(&self) -> R<&LibUnsafe> {
+        match *self.state() {
+            Ok(ref lib) => Ok(lib),
+            Err(ref err) => Err(err.clone()),
+        }
+    }
+
+    /// Returns whether the shared library could be opened, opening it if this is the first use.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library.
+    pub unsafe fn is_available(&self) -> bool {
+        self.state().is_ok()
+    }
+
+    /// Returns the error which occurred when the shared library was opened, or `None` if it opened. The library is opened if this is the first use.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library.
+    pub unsafe fn load_error(&self) -> Option<E> {
+        self.get().err()
+    }
+
+    unsafe fn state(&self) -> &R<LibUnsafe> {
+        self.once.call_once(|| {
+            let state = LibUnsafe::new(self.path_to_lib);
+            *self.state.get() = Some(state);
+        });
+        match *self.state.get() {
+            Some(ref state) => state,
+            None => unreachable!(),
+        }
+    }
+}
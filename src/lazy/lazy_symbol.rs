@@ -0,0 +1,46 @@
+use LazyLib;
+use SharedlibResult as R;
+use std::mem;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+/// A function symbol which is found the first time it is used and then cached. It is used by [bind!](macro.bind.html).
+/// A symbol which is missing is looked up again on every use.
+pub struct LazySymbol {
+    address: AtomicUsize,
+}
+
+impl LazySymbol {
+    /// Creates a new [LazySymbol](struct.LazySymbol.html) which has not been found yet.
+    pub const fn new() -> Self {
+        LazySymbol {
+            address: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the function symbol, finding it in `lib` if this is the first use. `lib` is opened if it has not been yet.
+    /// By passing in a null terminated string, an extra allocation may be avoided.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if `lib` could not be opened. A `LibraryFindSymbol` error will be returned if `lib` does not contain the symbol.
+    ///
+    /// # Safety
+    /// `T` must be the type of the symbol, and it must be a function pointer. The same [LazySymbol](struct.LazySymbol.html) must always be used with the same `lib` and `symbol`.
+    pub unsafe fn get<T>(&self, lib: &LazyLib, symbol: &str) -> R<T>
+        where T: Copy {
+        let mut address = self.address.load(Ordering::Acquire);
+        if address == 0 {
+            let lib = try!(lib.get());
+            address = try!(lib.find_func::<usize, _>(symbol));
+            self.address.store(address, Ordering::Release);
+        }
+        let result: T = mem::transmute_copy(&address);
+        Ok(result)
+    }
+}
+
+impl Default for LazySymbol {
+    fn default() -> Self {
+        LazySymbol::new()
+    }
+}
@@ -0,0 +1,10 @@
+#[macro_use]
+mod bind;
+
+mod lazy_lib;
+
+mod lazy_symbol;
+
+pub use lazy::lazy_lib::LazyLib;
+
+pub use lazy::lazy_symbol::LazySymbol;
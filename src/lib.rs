@@ -142,12 +142,11 @@
 //! ### Doesn't rust already provide linking against shared libraries?
 //! While rust provides linking against shared libraries, it does not provide the ability to load them at runtime. If you only want to use shared libraries that you know about before runtime, you may find not find this crate very useful. On the other hand, if you wish to load something at runtime, like a plugin, you are in the right place.
 
-#[macro_use]
-extern crate define_error;
-
 #[macro_use]
 extern crate lazy_static;
 
+extern crate sharedlib_derive;
+
 #[cfg(windows)]
 extern crate kernel32;
 
@@ -156,10 +155,20 @@ extern crate winapi;
 
 pub mod error;
 
+mod hot;
+
+pub mod inspect;
+
+#[macro_use]
+mod lazy;
+
 mod os;
 
 mod lib_impl;
 
+#[macro_use]
+pub mod plugin;
+
 mod string;
 
 mod symbol;
@@ -169,10 +178,29 @@ mod test;
 
 mod util;
 
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod verify;
+
 pub use error::SharedlibError;
 
 pub use error::SharedlibResult;
 
+pub use hot::HotFunc;
+
+pub use hot::HotLib;
+
+pub use hot::RestoreState;
+
+pub use hot::SaveState;
+
+pub use hot::STATE_VERSION_MISMATCH;
+
+pub use hot::StateWriter;
+
+pub use lazy::LazyLib;
+
+pub use lazy::LazySymbol;
+
 pub use lib_impl::Lib;
 
 pub use lib_impl::LibArc;
@@ -183,6 +211,28 @@ pub use lib_impl::LibTracked;
 
 pub use lib_impl::LibUnsafe;
 
+pub use lib_impl::LifecycleFn;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub use lib_impl::Namespace;
+
+pub use lib_impl::OpenOptions;
+
+pub use lib_impl::ResidentReason;
+
+pub use lib_impl::UnloadOutcome;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub use verify::LoadPolicy;
+
+pub use string::EnglishCatalog;
+
+pub use string::MessageCatalog;
+
+pub use string::MessageId;
+
+pub use string::set_message_catalog;
+
 pub use symbol::Data;
 
 pub use symbol::DataArc;
@@ -203,4 +253,22 @@ pub use symbol::FuncTracked;
 
 pub use symbol::FuncUnsafe;
 
+pub use symbol::OwnedSymbols;
+
 pub use symbol::Symbol;
+
+pub use symbol::SymbolBatch;
+
+pub use symbol::SymbolTable;
+
+pub use sharedlib_derive::SymbolTable;
+
+pub use symbol::SymbolTableLoader;
+
+pub use symbol::Table;
+
+pub use symbol::TableArc;
+
+pub use symbol::TableRc;
+
+pub use symbol::TableTracked;
@@ -0,0 +1,39 @@
+use error::LifecycleHook;
+use error::LifecycleHookKind;
+use LibUnsafe;
+use LifecycleFn;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::path::Path;
+use std::path::PathBuf;
+
+// A lifecycle hook which was found in the library it belongs to.
+#[derive(Debug)]
+pub struct BoundHook {
+    kind: LifecycleHookKind,
+    func: LifecycleFn,
+    symbol: String,
+    path_to_lib: PathBuf,
+}
+
+impl BoundHook {
+    pub unsafe fn find(lib: &LibUnsafe, kind: LifecycleHookKind, symbol: &str, path_to_lib: &Path) -> R<BoundHook> {
+        let func = try!(lib.find_func::<LifecycleFn, _>(symbol));
+        let result =
+            BoundHook {
+                kind: kind,
+                func: func,
+                symbol: symbol.trim_end_matches('\0').to_string(),
+                path_to_lib: path_to_lib.to_path_buf(),
+            };
+        Ok(result)
+    }
+
+    // Must only be called while the library is loaded.
+    pub unsafe fn run(&self) -> R<()> {
+        match (self.func)() {
+            0 => Ok(()),
+            code => Err(E::from(LifecycleHook::new(self.kind, self.symbol.clone(), code, self.path_to_lib.clone()))),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use error::LibraryUnloaded;
+use error::UnloadTimeout;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+#[derive(Debug)]
+struct CallGateState {
+    in_flight: usize,
+    closed: bool,
+}
+
+// Counts the calls which are running inside a library, so that it is only unloaded once none
+// remain. A closed gate refuses new calls.
+#[derive(Debug)]
+pub struct CallGate {
+    state: Mutex<CallGateState>,
+    idle: Condvar,
+}
+
+impl CallGate {
+    pub fn new() -> Self {
+        let state =
+            CallGateState {
+                in_flight: 0,
+                closed: false,
+            };
+        CallGate {
+            state: Mutex::new(state),
+            idle: Condvar::new(),
+        }
+    }
+
+    pub fn enter<'a>(&'a self) -> R<CallGuard<'a>> {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            return Err(E::from(LibraryUnloaded::new()));
+        }
+        state.in_flight += 1;
+        let result =
+            CallGuard {
+                gate: self,
+            };
+        Ok(result)
+    }
+
+    // Closes the gate and waits for the calls which are already running to return. If they do
+    // not return within `timeout`, the gate is opened again.
+    pub fn close_when_idle(&self, timeout: Duration) -> R<()> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            return Err(E::from(LibraryUnloaded::new()));
+        }
+        state.closed = true;
+        while state.in_flight != 0 {
+            let now = Instant::now();
+            if now >= deadline {
+                state.closed = false;
+                return Err(E::from(UnloadTimeout::new(timeout, state.in_flight)));
+            }
+            state = self.idle.wait_timeout(state, deadline - now).unwrap().0;
+        }
+        Ok(())
+    }
+
+    fn exit(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.in_flight -= 1;
+        if state.in_flight == 0 {
+            self.idle.notify_all();
+        }
+    }
+}
+
+impl Default for CallGate {
+    fn default() -> Self {
+        CallGate::new()
+    }
+}
+
+// Marks a call as running until it is dropped.
+#[derive(Debug)]
+pub struct CallGuard<'a> {
+    gate: &'a CallGate,
+}
+
+impl <'a> Drop for CallGuard<'a> {
+    fn drop(&mut self) {
+        self.gate.exit();
+    }
+}
@@ -2,6 +2,10 @@ use Data;
 use Func;
 use LibUnsafe;
 use SharedlibResult as R;
+use SymbolTable;
+use Table;
+#[cfg(any(windows, target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+use UnloadOutcome;
 use std::mem;
 use std::path::Path;
 
@@ -41,6 +45,32 @@ impl Lib {
         Ok(result)
     }
 
+    /// Returns another handle to the same loaded shared library.
+    /// See [LibUnsafe::try_clone](struct.LibUnsafe.html#method.try_clone) for more information.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the operating system refuses to open another handle, which can only happen on windows.
+    #[cfg(any(windows, target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+    pub fn try_clone(&self) -> R<Self> {
+        let inner = try!(self.inner.try_clone());
+        let result =
+            Lib {
+                inner: inner,
+            };
+        Ok(result)
+    }
+
+    /// Closes the shared library and then asks the operating system whether it is still loaded.
+    /// Since symbols borrow the library, none of them can outlive this call.
+    /// See [LibUnsafe::close_and_verify](struct.LibUnsafe.html#method.close_and_verify) for more information.
+    ///
+    /// # Errors
+    /// A `LibraryClose` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the operating system refuses to close the library.
+    #[cfg(any(windows, target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+    pub fn close_and_verify(self) -> R<UnloadOutcome> {
+        self.inner.close_and_verify()
+    }
+
     /// Finds and returns a data symbol within the shared library.
     /// By passing in a null terminated string, an extra allocation may be avoided.
     ///
@@ -130,4 +160,53 @@ impl Lib {
         let result = Func::new(func);
         Ok(result)
     }
+
+    /// Finds every symbol of a [SymbolTable](trait.SymbolTable.html) at once.
+    /// The returned [Table](struct.Table.html) borrows the library, so it cannot outlive it.
+    ///
+    /// # Errors
+    /// A `MissingSymbols` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant, which names every required symbol the shared library does not contain.
+    ///
+    /// # Safety
+    /// The fields of `T` are not checked against the types of the symbols. Using a symbol as a type it does not have causes undefined behavior.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::Lib;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # use sharedlib::SymbolTable;
+    /// #[derive(SymbolTable)]
+    /// struct Example {
+    ///     some_func: extern "C" fn(),
+    ///     #[symbol(name = "some_other_func")]
+    ///     other_func: Option<extern "C" fn()>,
+    /// }
+    ///
+    /// # fn test() -> R<()> {
+    /// # let lib = try!(unsafe { Lib::new("examplelib.dll") });
+    /// let example = try!(unsafe { lib.load_table::<Example>() });
+    /// (example.some_func)();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn load_table<'a, T>(&'a self) -> R<Table<'a, T>>
+        where T: SymbolTable {
+        let table = try!(self.inner.load_table::<T>());
+        let result = Table::new(table);
+        Ok(result)
+    }
+}
+
+impl From<LibUnsafe> for Lib {
+    fn from(inner: LibUnsafe) -> Self {
+        Lib {
+            inner: inner,
+        }
+    }
+}
+
+impl AsRef<LibUnsafe> for Lib {
+    fn as_ref(&self) -> &LibUnsafe {
+        &self.inner
+    }
 }
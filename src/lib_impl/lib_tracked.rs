@@ -2,10 +2,14 @@ use DataTracked;
 use FuncTracked;
 use LibUnsafe;
 use SharedlibResult as R;
+use SymbolTable;
+use TableTracked;
 use std::path::Path;
+use std::time::Duration;
 
 /// A shared library which which allows a user-provided ref-counting implementation to track its [Symbols](trait.Symbol.html).
-/// The inner library will not be droped until all of teh ref-counts are dropped.
+/// The inner library will not be dropped until all of the ref-counts are dropped, unless it is closed early by [unload_when_idle](#method.unload_when_idle). That function overrides this guarantee; see its Safety section for how symbols must be used once the library is closed.
+/// A hook passed to [OpenOptions::on_unload](struct.OpenOptions.html#method.on_unload) runs when the last ref-count is dropped, right before the library is closed.
 #[derive(Clone, Debug)]
 pub struct LibTracked<TLib> {
     inner: TLib,
@@ -151,4 +155,82 @@ impl <TLib> LibTracked<TLib>
         let result = FuncTracked::new(func, self.inner.clone());
         Ok(result)
     }
+
+    /// Finds every symbol of a [SymbolTable](trait.SymbolTable.html) at once.
+    /// The returned [TableTracked](struct.TableTracked.html) holds a single ref-count to the library for the whole table.
+    ///
+    /// # Errors
+    /// A `MissingSymbols` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant, which names every required symbol the shared library does not contain.
+    ///
+    /// # Safety
+    /// The fields of `T` are not checked against the types of the symbols. Using a symbol as a type it does not have causes undefined behavior.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::LibArc;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # use sharedlib::SymbolTable;
+    /// #[derive(SymbolTable)]
+    /// struct Example {
+    ///     some_func: extern "C" fn(),
+    /// }
+    ///
+    /// # fn test() -> R<()> {
+    /// # let lib = try!(unsafe { LibArc::new("examplelib.dll") });
+    /// let example = try!(unsafe { lib.load_table::<Example>() });
+    /// (example.some_func)();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn load_table<T>(&self) -> R<TableTracked<T, TLib>>
+        where T: SymbolTable {
+        let table = try!(self.inner.as_ref().load_table::<T>());
+        let result = TableTracked::new(table, self.inner.clone());
+        Ok(result)
+    }
+
+    /// Blocks new calls through [FuncTracked::call](struct.FuncTracked.html#method.call), waits for the running ones to return, and then closes the shared library, even if ref-counts to it remain.
+    /// See [LibUnsafe::unload_when_idle](struct.LibUnsafe.html#method.unload_when_idle) for more information.
+    ///
+    /// # Errors
+    /// An `UnloadTimeout` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if calls are still running after `timeout`. The library is then left loaded. A `LibraryUnloaded` error will be returned if the library is already being unloaded. A `LibraryClose` error will be returned if the operating system refuses to close the library.
+    ///
+    /// # Safety
+    /// Only calls made through [FuncTracked::call](struct.FuncTracked.html#method.call) are waited for. Symbols which are used any other way, for instance through [Symbol::get](trait.Symbol.html#tymethod.get), must not be used once the library is closed.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::LibArc;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # use std::time::Duration;
+    /// # fn test() -> R<()> {
+    /// let lib = try!(unsafe { LibArc::new("examplelib.dll") });
+    /// try!(unsafe { lib.unload_when_idle(Duration::from_secs(5)) });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn unload_when_idle(&self, timeout: Duration) -> R<()> {
+        self.inner.as_ref().unload_when_idle(timeout)
+    }
+
+    // Returns the ref-counted library, so that the crate can inspect its ref-count.
+    pub(crate) fn inner(&self) -> &TLib {
+        &self.inner
+    }
+}
+
+impl <TLib> From<LibUnsafe> for LibTracked<TLib>
+    where TLib: From<LibUnsafe> {
+    fn from(lib_unsafe: LibUnsafe) -> Self {
+        LibTracked {
+            inner: TLib::from(lib_unsafe),
+        }
+    }
+}
+
+impl <TLib> AsRef<LibUnsafe> for LibTracked<TLib>
+    where TLib: AsRef<LibUnsafe> {
+    fn as_ref(&self) -> &LibUnsafe {
+        self.inner.as_ref()
+    }
 }
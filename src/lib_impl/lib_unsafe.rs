@@ -1,16 +1,41 @@
 use DataUnsafe;
+use error::LibraryFindSymbol;
+use error::LibraryUnloaded;
 use FuncUnsafe;
+use inspect::ElfFile;
+use lib_impl::BoundHook;
+use lib_impl::CallGate;
+use lib_impl::CallGuard;
+use lib_impl::SymbolCache;
 use os::uses::Lib as InnerLib;
+use SharedlibError as E;
 use SharedlibResult as R;
+use SymbolBatch;
+use SymbolTable;
+#[cfg(any(windows, target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+use UnloadOutcome;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::fs::File;
 use std::mem;
+#[cfg(all(target_os="linux", target_env="gnu"))]
+use std::os::raw::c_long;
 use std::path::Path;
+#[cfg(target_os="linux")]
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
 use util;
 
 /// A shared library which does not track its [Symbols](trait.Symbol.html).
 /// The inner library may be dropped at any time, even if it has loose symbols.
 #[derive(Debug)]
 pub struct LibUnsafe {
-    inner: InnerLib,
+    inner: RwLock<Option<InnerLib>>,
+    gate: CallGate,
+    unload_hook: Option<BoundHook>,
+    cache: Option<SymbolCache>,
+    #[cfg(target_os="linux")]
+    suggest_symbols: bool,
 }
 
 impl LibUnsafe {
@@ -35,10 +60,102 @@ impl LibUnsafe {
     pub unsafe fn new<TPath>(path_to_lib: TPath) -> R<Self>
         where TPath: AsRef<Path> {
         let inner = try!(InnerLib::new(path_to_lib));
+        let result = LibUnsafe::from_inner(inner);
+        Ok(result)
+    }
+
+    /// Returns a handle to the shared library at the specified path only if it is already loaded, for instance because a dependency pulled it in. The library is never loaded by this function.
+    /// The returned handle holds its own reference to the library, so the library stays loaded until the handle is dropped, even if whoever loaded it originally unloads it. This can also be used to check that a library really was unloaded.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem looking up the shared library. A library which is not loaded is not an error.
+    ///
+    /// # Safety
+    /// The library may be in the middle of being loaded or unloaded by another thread, so it is up to the caller to make sure that using the returned handle is sound.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::LibUnsafe;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// match try!(unsafe { LibUnsafe::get_loaded("examplelib.dll") }) {
+    ///     Some(lib) => println!("already loaded: {:?}", lib),
+    ///     None => println!("not loaded"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(windows, target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+    pub unsafe fn get_loaded<TPath>(path_to_lib: TPath) -> R<Option<Self>>
+        where TPath: AsRef<Path> {
+        let inner = try!(InnerLib::get_loaded(path_to_lib));
         let result =
-            LibUnsafe {
-                inner: inner,
-            };
+            inner.map(LibUnsafe::from_inner);
+        Ok(result)
+    }
+
+    /// Returns another handle to the same loaded shared library.
+    /// On unix, the handle the operating system returned is shared rather than opened again, since the path the library was opened with may name another file by now; it is closed once every clone is dropped. On windows, the reference count the operating system keeps for the library is incremented.
+    /// Unlike the ref-counting done by a [LibTracked](struct.LibTracked.html), the library is only unloaded once every handle has been dropped, whichever way it was obtained.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the operating system refuses to open another handle, which can only happen on windows.
+    #[cfg(any(windows, target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+    pub fn try_clone(&self) -> R<Self> {
+        let inner = try!(self.with_inner(|inner| inner.try_clone()));
+        let result = LibUnsafe::from_inner(inner);
+        Ok(result)
+    }
+
+    /// Closes the shared library and then asks the operating system whether it is still loaded.
+    /// Dropping a handle only releases a reference, so a library may stay resident without any sign of it. This function reports why if it does.
+    ///
+    /// The library is looked up again through `RTLD_NOLOAD` and the list of loaded objects on unix, and by its former base address on windows.
+    /// On linux, the reasons reported for a resident library are found by reading the library's dynamic section before it is closed. Anything which cannot be attributed to `DF_1_NODELETE`, `STB_GNU_UNIQUE` symbols or the platform is reported as `OtherReferences`.
+    ///
+    /// # Errors
+    /// A `LibraryClose` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the operating system refuses to close the library. A `LifecycleHook` error will be returned if the hook passed to [OpenOptions::on_unload](struct.OpenOptions.html#method.on_unload) fails, after the library was closed.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::LibUnsafe;
+    /// # use sharedlib::UnloadOutcome;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// let lib = try!(unsafe { LibUnsafe::new("examplelib.dll") });
+    /// match try!(lib.close_and_verify()) {
+    ///     UnloadOutcome::Unloaded => println!("unloaded"),
+    ///     UnloadOutcome::StillResident(reasons) => println!("still resident: {:?}", reasons),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(windows, target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+    pub fn close_and_verify(self) -> R<UnloadOutcome> {
+        let inner = self.inner.write().unwrap().take();
+        self.clear_symbol_cache();
+        match inner {
+            Some(inner) => {
+                let hooked = self.run_unload_hook();
+                let outcome = try!(inner.close_and_verify());
+                try!(hooked);
+                Ok(outcome)
+            },
+            None => Err(E::from(LibraryUnloaded::new())),
+        }
+    }
+
+    #[cfg(any(target_os="linux", target_os="android"))]
+    pub(crate) unsafe fn new_from_file(file: &File, path_to_lib: &Path) -> R<Self> {
+        let inner = try!(InnerLib::new_from_file(file, path_to_lib));
+        let result = LibUnsafe::from_inner(inner);
+        Ok(result)
+    }
+
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub(crate) unsafe fn new_in_namespace(path_to_lib: &Path, namespace: c_long) -> R<Self> {
+        let inner = try!(InnerLib::new_in_namespace(path_to_lib, namespace));
+        let result = LibUnsafe::from_inner(inner);
         Ok(result)
     }
 
@@ -81,10 +198,9 @@ impl LibUnsafe {
     /// ```
     pub unsafe fn find_data<T, TStr>(&self, symbol: TStr) -> R<DataUnsafe<T>>
         where TStr: AsRef<str> {
-        match util::null_terminate(&symbol) {
-            Some(symbol) => self.inner.find(symbol),
-            None => self.inner.find(symbol),
-        }
+        let data = try!(self.find_address(symbol));
+        let result = data as DataUnsafe<T>;
+        Ok(result)
     }
 
     /// Finds and returns a function symbol within the shared library.
@@ -127,13 +243,320 @@ impl LibUnsafe {
     pub unsafe fn find_func<T, TStr>(&self, symbol: TStr) -> R<FuncUnsafe<T>>
         where T: Copy,
               TStr: AsRef<str> {
-        let func =
-            match util::null_terminate(&symbol) {
-                Some(symbol) => try!(self.inner.find::<u8, _>(symbol)),
-                None => try!(self.inner.find::<u8, _>(symbol)),
-            };
+        let func = try!(self.find_address(symbol));
         let func_ref = &func;
         let result: T = mem::transmute_copy(func_ref);
         Ok(result)
     }
+
+    /// Finds and returns the definition of a data symbol with the specified version within the shared library, like `name@VERSION` in a version script.
+    /// By passing in null terminated strings, extra allocations may be avoided.
+    ///
+    /// # Errors
+    /// A `LibraryFindSymbol` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem finding the symbol. For instance, this may happen if the shared library does not define the symbol with the requested version.
+    ///
+    /// # Safety
+    /// This function is not type safe so there is no guarntee that `T` is really the type of the symbol. Using a symbol as a `T` when the symbol is not really of type `T` causes undefined behavior.
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub unsafe fn find_data_versioned<T, TStr, TVersion>(&self, symbol: TStr, version: TVersion) -> R<DataUnsafe<T>>
+        where TStr: AsRef<str>,
+              TVersion: AsRef<str> {
+        let data = try!(self.find_versioned_address(symbol, version));
+        let result = data as DataUnsafe<T>;
+        Ok(result)
+    }
+
+    /// Finds and returns the definition of a function symbol with the specified version within the shared library, like `name@VERSION` in a version script.
+    /// By passing in null terminated strings, extra allocations may be avoided.
+    ///
+    /// # Errors
+    /// A `LibraryFindSymbol` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem finding the symbol. For instance, this may happen if the shared library does not define the symbol with the requested version.
+    ///
+    /// # Safety
+    /// This function is not type safe so there is no guarntee that `T` is really the type of the symbol. Using a symbol as a `T` when the symbol is not really of type `T` causes undefined behavior.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::FuncUnsafe;
+    /// # use sharedlib::LibUnsafe;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// # let lib = try!(unsafe { LibUnsafe::new("libc.so.6") });
+    /// let memcpy: FuncUnsafe<fn()> = try!(unsafe { lib.find_func_versioned("memcpy\0", "GLIBC_2.14\0") });
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub unsafe fn find_func_versioned<T, TStr, TVersion>(&self, symbol: TStr, version: TVersion) -> R<FuncUnsafe<T>>
+        where T: Copy,
+              TStr: AsRef<str>,
+              TVersion: AsRef<str> {
+        let func = try!(self.find_versioned_address(symbol, version));
+        let func_ref = &func;
+        let result: T = mem::transmute_copy(func_ref);
+        Ok(result)
+    }
+
+    /// Finds every symbol in `symbols` at once.
+    /// Unlike calling [find_func](#method.find_func) or [find_data](#method.find_data) for each symbol, the library is checked for being unloaded only once, and names which are not null terminated are copied into a single buffer. As for any other lookup, the lock which guards the operating system's error state is only held while an error is read, never while the operating system looks up a symbol. A missing symbol does not stop the search; the returned [SymbolBatch](struct.SymbolBatch.html) holds every symbol which was found together with one `MissingSymbols` error which names each missing symbol and its cause.
+    ///
+    /// # Errors
+    /// A `LibraryUnloaded` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the library was unloaded. Missing symbols are reported by the [SymbolBatch](struct.SymbolBatch.html) instead.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::LibUnsafe;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// # let lib = try!(unsafe { LibUnsafe::new("examplelib.dll") });
+    /// let batch = try!(lib.find_many(&["some_func", "some_other_func"]));
+    /// if let Some(missing) = batch.missing() {
+    ///     println!("{}", missing);
+    /// }
+    /// let some_func: Option<fn()> = unsafe { batch.func(0) };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_many<TStr>(&self, symbols: &[TStr]) -> R<SymbolBatch>
+        where TStr: AsRef<str> {
+        let results =
+            try!(
+                self.with_inner(
+                    |inner| {
+                        let results = unsafe { inner.find_many(symbols) };
+                        // The symbol table is only read if something is missing.
+                        let symbol_table =
+                            if results.iter().any(Result::is_err) {
+                                self.suggestion_source(inner)
+                            } else {
+                                None
+                            };
+                        let results =
+                            match symbol_table {
+                                Some(symbol_table) => {
+                                    results
+                                        .into_iter()
+                                        .map(|result| result.map_err(|err| LibUnsafe::suggest(&symbol_table, err)))
+                                        .collect()
+                                },
+                                None => results,
+                            };
+                        Ok(results)
+                    }
+                )
+            );
+        let result = SymbolBatch::new(results);
+        Ok(result)
+    }
+
+    /// Finds every symbol of a [SymbolTable](trait.SymbolTable.html) at once.
+    ///
+    /// # Errors
+    /// A `MissingSymbols` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant, which names every required symbol the shared library does not contain.
+    ///
+    /// # Safety
+    /// The fields of `T` are not checked against the types of the symbols. The table must not be used after the shared library is unloaded.
+    pub unsafe fn load_table<T>(&self) -> R<T>
+        where T: SymbolTable {
+        T::load(self)
+    }
+
+    /// Returns the id of the linker namespace which the shared library was loaded into.
+    /// Libraries opened through [LibUnsafe::new](#method.new) live in the base namespace, whose id is `0`. Libraries opened through a [Namespace](struct.Namespace.html) live in that namespace.
+    ///
+    /// # Errors
+    /// An `OsError` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the loader cannot report the namespace.
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub fn namespace_id(&self) -> R<c_long> {
+        self.with_inner(|inner| inner.namespace())
+    }
+
+    /// Returns the paths of the objects which are loaded into the same linker namespace as the shared library, in load order.
+    /// Objects without a name, like the main program, are skipped.
+    ///
+    /// # Errors
+    /// An `OsError` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the loader cannot report its list of objects.
+    #[cfg(target_os="linux")]
+    pub fn loaded_objects(&self) -> R<Vec<PathBuf>> {
+        self.with_inner(|inner| inner.loaded_objects())
+    }
+
+    /// Waits for the calls made through [FuncTracked::call](struct.FuncTracked.html#method.call) to return and then closes the shared library.
+    /// New calls are refused with a `LibraryUnloaded` error as soon as this function starts waiting. If calls are still running after `timeout`, the library is left loaded, calls are accepted again, and an `UnloadTimeout` error is returned.
+    /// Once the library is closed, every function of this [LibUnsafe](struct.LibUnsafe.html) which needs the library returns a `LibraryUnloaded` error.
+    ///
+    /// # Errors
+    /// An `UnloadTimeout` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if calls are still running after `timeout`. A `LibraryUnloaded` error will be returned if the library is already being unloaded. A `LibraryClose` error will be returned if the operating system refuses to close the library. A `LifecycleHook` error will be returned if the hook passed to [OpenOptions::on_unload](struct.OpenOptions.html#method.on_unload) fails, after the library was closed.
+    ///
+    /// # Safety
+    /// Only calls made through [FuncTracked::call](struct.FuncTracked.html#method.call) are waited for. Symbols which are used any other way, for instance through [Symbol::get](trait.Symbol.html#tymethod.get), must not be used once the library is closed.
+    pub unsafe fn unload_when_idle(&self, timeout: Duration) -> R<()> {
+        try!(self.gate.close_when_idle(timeout));
+        let inner = self.inner.write().unwrap().take();
+        self.clear_symbol_cache();
+        match inner {
+            Some(mut inner) => {
+                let hooked = self.run_unload_hook();
+                try!(inner.close());
+                hooked
+            },
+            None => Ok(()),
+        }
+    }
+
+    // Marks a call into the library as running until the returned guard is dropped.
+    pub(crate) fn enter_call<'a>(&'a self) -> R<CallGuard<'a>> {
+        self.gate.enter()
+    }
+
+    // Runs `hook` right before the library is closed, however that happens.
+    pub(crate) fn set_unload_hook(&mut self, hook: BoundHook) {
+        self.unload_hook = Some(hook);
+    }
+
+    // Remembers every symbol which is found from now on, until the library is closed.
+    pub(crate) fn enable_symbol_cache(&mut self) {
+        if self.cache.is_none() {
+            self.cache = Some(SymbolCache::new());
+        }
+    }
+
+    // Suggests similar exported names in every error about a missing symbol from now on.
+    #[cfg(target_os="linux")]
+    pub(crate) fn enable_symbol_suggestions(&mut self) {
+        self.suggest_symbols = true;
+    }
+
+    fn clear_symbol_cache(&self) {
+        if let Some(ref cache) = self.cache {
+            cache.clear();
+        }
+    }
+
+    // Looks in the cache before asking the operating system. The cache is read while the library
+    // is known to be open, so an address is never returned for a closed library.
+    unsafe fn find_address<TStr>(&self, symbol: TStr) -> R<*const u8>
+        where TStr: AsRef<str> {
+        self.with_inner(
+            |inner| {
+                let name = symbol.as_ref().trim_end_matches('\0');
+                if let Some(address) = self.cache.as_ref().and_then(|cache| cache.get(name)) {
+                    return Ok(address as *const u8);
+                }
+                let address =
+                    match util::null_terminate(&symbol) {
+                        Some(symbol) => inner.find::<u8, _>(symbol),
+                        None => inner.find::<u8, _>(&symbol),
+                    };
+                let address = try!(address.map_err(|err| self.with_suggestions(inner, err)));
+                if let Some(ref cache) = self.cache {
+                    cache.insert(name, address as usize);
+                }
+                Ok(address)
+            }
+        )
+    }
+
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    unsafe fn find_versioned_address<TStr, TVersion>(&self, symbol: TStr, version: TVersion) -> R<*const u8>
+        where TStr: AsRef<str>,
+              TVersion: AsRef<str> {
+        self.with_inner(
+            |inner| {
+                let name = symbol.as_ref().trim_end_matches('\0');
+                let version_name = version.as_ref().trim_end_matches('\0');
+                if let Some(address) = self.cache.as_ref().and_then(|cache| cache.get_versioned(name, version_name)) {
+                    return Ok(address as *const u8);
+                }
+                let symbol_terminated = util::null_terminate(&symbol);
+                let version_terminated = util::null_terminate(&version);
+                let address =
+                    try!(
+                        inner.find_versioned::<u8, _, _>(
+                            symbol_terminated.as_ref().map_or(symbol.as_ref(), String::as_str),
+                            version_terminated.as_ref().map_or(version.as_ref(), String::as_str),
+                        ).map_err(|err| self.with_suggestions(inner, err))
+                    );
+                if let Some(ref cache) = self.cache {
+                    cache.insert_versioned(name, version_name, address as usize);
+                }
+                Ok(address)
+            }
+        )
+    }
+
+    // Adds the closest exported names to an error about a missing symbol, if they were asked for.
+    fn with_suggestions(&self, inner: &InnerLib, err: E) -> E {
+        match err {
+            E::LibraryFindSymbol(err) => {
+                match self.suggestion_source(inner) {
+                    Some(symbol_table) => E::from(LibUnsafe::suggest(&symbol_table, err)),
+                    None => E::from(err),
+                }
+            },
+            err => err,
+        }
+    }
+
+    // Reads the dynamic symbol table of the file the loader mapped, if suggestions were asked for
+    // and the file can still be read.
+    #[cfg(target_os="linux")]
+    fn suggestion_source(&self, inner: &InnerLib) -> Option<ElfFile> {
+        if self.suggest_symbols {
+            inner.file_path().ok().and_then(|path| ElfFile::open(path).ok())
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(target_os="linux"))]
+    fn suggestion_source(&self, _inner: &InnerLib) -> Option<ElfFile> {
+        None
+    }
+
+    fn suggest(symbol_table: &ElfFile, err: LibraryFindSymbol) -> LibraryFindSymbol {
+        let suggestions = symbol_table.similar_symbols(err.symbol());
+        err.with_suggestions(suggestions)
+    }
+
+    // Must only be called right before the library is closed.
+    fn run_unload_hook(&self) -> R<()> {
+        match self.unload_hook {
+            Some(ref hook) => unsafe { hook.run() },
+            None => Ok(()),
+        }
+    }
+
+    fn from_inner(inner: InnerLib) -> Self {
+        LibUnsafe {
+            inner: RwLock::new(Some(inner)),
+            gate: CallGate::new(),
+            unload_hook: None,
+            cache: None,
+            #[cfg(target_os="linux")]
+            suggest_symbols: false,
+        }
+    }
+
+    fn with_inner<T, TFn>(&self, func: TFn) -> R<T>
+        where TFn: FnOnce(&InnerLib) -> R<T> {
+        match *self.inner.read().unwrap() {
+            Some(ref inner) => func(inner),
+            None => Err(E::from(LibraryUnloaded::new())),
+        }
+    }
+}
+
+impl Drop for LibUnsafe {
+    fn drop(&mut self) {
+        let inner =
+            match self.inner.get_mut() {
+                Ok(inner) => inner.take(),
+                Err(err) => err.into_inner().take(),
+            };
+        // There is no one to report a failed hook to, so the library is closed regardless.
+        if inner.is_some() {
+            let _ = self.run_unload_hook();
+        }
+    }
 }
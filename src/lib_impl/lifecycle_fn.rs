@@ -0,0 +1,4 @@
+use std::os::raw::c_int;
+
+/// The type of the functions which [OpenOptions::on_load](struct.OpenOptions.html#method.on_load) and [OpenOptions::on_unload](struct.OpenOptions.html#method.on_unload) name. They return zero on success and any other code on failure.
+pub type LifecycleFn = unsafe extern "C" fn() -> c_int;
@@ -1,3 +1,7 @@
+pub mod bound_hook;
+
+pub mod call_gate;
+
 pub mod lib;
 
 pub mod lib_arc;
@@ -8,6 +12,25 @@ pub mod lib_tracked;
 
 pub mod lib_unsafe;
 
+pub mod lifecycle_fn;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub mod namespace;
+
+pub mod open_options;
+
+pub mod resident_reason;
+
+pub mod symbol_cache;
+
+pub mod unload_outcome;
+
+pub use lib_impl::bound_hook::BoundHook;
+
+pub use lib_impl::call_gate::CallGate;
+
+pub use lib_impl::call_gate::CallGuard;
+
 pub use lib_impl::lib::Lib;
 
 pub use lib_impl::lib_arc::LibArc;
@@ -17,3 +40,16 @@ pub use lib_impl::lib_rc::LibRc;
 pub use lib_impl::lib_tracked::LibTracked;
 
 pub use lib_impl::lib_unsafe::LibUnsafe;
+
+pub use lib_impl::lifecycle_fn::LifecycleFn;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub use lib_impl::namespace::Namespace;
+
+pub use lib_impl::open_options::OpenOptions;
+
+pub use lib_impl::resident_reason::ResidentReason;
+
+pub use lib_impl::symbol_cache::SymbolCache;
+
+pub use lib_impl::unload_outcome::UnloadOutcome;
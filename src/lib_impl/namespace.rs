@@ -0,0 +1,72 @@
+use LibUnsafe;
+use os::unix::LM_ID_NEWLM;
+use SharedlibResult as R;
+use std::os::raw::c_long;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// A linker namespace which isolates the shared libraries opened in it, and their dependencies, from the rest of the program.
+/// Every [Namespace](struct.Namespace.html) is backed by `dlmopen`. Opening a library in a new namespace loads fresh copies of all of its dependencies, so two versions of the same library can be loaded side by side, and a library's dependencies never resolve to the program's copies. Handles opened in a namespace are ordinary [LibUnsafe](struct.LibUnsafe.html) or [LibTracked](struct.LibTracked.html) handles, so every symbol type works with them unchanged.
+///
+/// The loader creates the namespace when the first library is opened in it, and glibc supports only a small number of namespaces per process.
+///
+/// # Examples
+/// ``` no_run
+/// # use sharedlib::LibArc;
+/// # use sharedlib::Namespace;
+/// # use sharedlib::SharedlibResult as R;
+/// # fn test() -> R<()> {
+/// let namespace = Namespace::new();
+/// let lib: LibArc = try!(unsafe { namespace.open("libexample.so.2") });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Namespace {
+    id: Mutex<Option<c_long>>,
+}
+
+impl Namespace {
+    /// Creates a new [Namespace](struct.Namespace.html). The loader does not create the namespace until the first library is opened in it.
+    pub fn new() -> Self {
+        Namespace {
+            id: Mutex::new(None),
+        }
+    }
+
+    /// Returns the id of the namespace, or `None` if no library has been opened in it yet.
+    pub fn id(&self) -> Option<c_long> {
+        *self.id.lock().unwrap()
+    }
+
+    /// Opens a shared library at the specified path inside this namespace.
+    /// The path is used in conjunction with platform specific shared library search paths to determine which shared library will be opened. The result can be any of the library types, [LibUnsafe](struct.LibUnsafe.html), [Lib](struct.Lib.html), or a [LibTracked](struct.LibTracked.html) like [LibArc](type.LibArc.html), or [LibRc](type.LibRc.html).
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem opening the shared library. For instance, this may happen if the shared library is not at the path specified, if the loader has run out of namespaces, or if every library of the namespace has been closed and the loader has released it.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to open is unsafe.
+    pub unsafe fn open<TLib, TPath>(&self, path_to_lib: TPath) -> R<TLib>
+        where TLib: From<LibUnsafe>,
+              TPath: AsRef<Path> {
+        let mut id = self.id.lock().unwrap();
+        let lib_unsafe =
+            match *id {
+                Some(id) => try!(LibUnsafe::new_in_namespace(path_to_lib.as_ref(), id)),
+                None => {
+                    let lib_unsafe = try!(LibUnsafe::new_in_namespace(path_to_lib.as_ref(), LM_ID_NEWLM));
+                    *id = Some(try!(lib_unsafe.namespace_id()));
+                    lib_unsafe
+                },
+            };
+        let result = TLib::from(lib_unsafe);
+        Ok(result)
+    }
+}
+
+impl Default for Namespace {
+    fn default() -> Self {
+        Namespace::new()
+    }
+}
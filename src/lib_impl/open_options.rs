@@ -0,0 +1,259 @@
+#[cfg(any(target_os="linux", target_os="android"))]
+use error::LibraryOpen;
+use error::LifecycleHookKind;
+use lib_impl::BoundHook;
+use LibUnsafe;
+#[cfg(any(target_os="linux", target_os="android"))]
+use SharedlibError as E;
+use SharedlibResult as R;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::fs::File;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::io::Seek;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::io::SeekFrom;
+use std::path::Path;
+#[cfg(any(target_os="linux", target_os="android"))]
+use verify::LoadPolicy;
+#[cfg(any(target_os="linux", target_os="android"))]
+use verify::Sha256Verifier;
+#[cfg(any(target_os="linux", target_os="android"))]
+use verify::Verifier;
+
+/// Options which configure how a shared library is opened.
+/// An [OpenOptions](struct.OpenOptions.html) can open any of the library types, [LibUnsafe](struct.LibUnsafe.html), [Lib](struct.Lib.html), or a [LibTracked](struct.LibTracked.html) like [LibArc](type.LibArc.html), or [LibRc](type.LibRc.html).
+///
+/// # Examples
+/// ``` no_run
+/// # use sharedlib::Lib;
+/// # use sharedlib::OpenOptions;
+/// # use sharedlib::SharedlibResult as R;
+/// # fn test() -> R<()> {
+/// let lib: Lib = try!(unsafe { OpenOptions::new().open("examplelib.dll") });
+/// # Ok(())
+/// # }
+/// ```
+pub struct OpenOptions {
+    #[cfg(any(target_os="linux", target_os="android"))]
+    policy: Option<LoadPolicy>,
+    #[cfg(any(target_os="linux", target_os="android"))]
+    verifiers: Vec<Box<Verifier>>,
+    load_hook: Option<String>,
+    unload_hook: Option<String>,
+    cache_symbols: bool,
+    #[cfg(target_os="linux")]
+    suggest_symbols: bool,
+}
+
+impl OpenOptions {
+    /// Creates a new [OpenOptions](struct.OpenOptions.html) which opens shared libraries the same way [LibUnsafe::new](struct.LibUnsafe.html#method.new) does.
+    pub fn new() -> Self {
+        OpenOptions {
+            #[cfg(any(target_os="linux", target_os="android"))]
+            policy: None,
+            #[cfg(any(target_os="linux", target_os="android"))]
+            verifiers: Vec::new(),
+            load_hook: None,
+            unload_hook: None,
+            cache_symbols: false,
+            #[cfg(target_os="linux")]
+            suggest_symbols: false,
+        }
+    }
+
+    /// Requires the shared library, and every directory above it, to satisfy a [LoadPolicy](struct.LoadPolicy.html).
+    /// The policy is checked before any [Verifier](verify/trait.Verifier.html) runs. Like a verifier, a policy causes the path to be opened as a file exactly once, and the library is loaded through that open file, so platform specific search paths are not consulted.
+    #[cfg(any(target_os="linux", target_os="android"))]
+    pub fn policy(&mut self, policy: LoadPolicy) -> &mut Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Requires the shared library to have the specified SHA-256 digest.
+    /// This is shorthand for passing a [Sha256Verifier](verify/struct.Sha256Verifier.html) to [verifier](#method.verifier).
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::Lib;
+    /// # use sharedlib::OpenOptions;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// # let digest = [0; 32];
+    /// let lib: Lib = try!(unsafe { OpenOptions::new().verify_sha256(digest).open("/opt/plugins/libexample.so") });
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(target_os="linux", target_os="android"))]
+    pub fn verify_sha256(&mut self, digest: [u8; 32]) -> &mut Self {
+        self.verifier(Sha256Verifier::new(digest))
+    }
+
+    /// Adds a [Verifier](verify/trait.Verifier.html) which must accept the shared library before it is loaded.
+    /// When at least one verifier is present, the path is opened as a file exactly once. Every verifier reads that open file, and the platform loader is then pointed at the same file descriptor through `/proc/self/fd`, so the file cannot be swapped between the check and the load. Because the path is opened directly, platform specific search paths are not consulted.
+    #[cfg(any(target_os="linux", target_os="android"))]
+    pub fn verifier<TVerifier>(&mut self, verifier: TVerifier) -> &mut Self
+        where TVerifier: Verifier + 'static {
+        self.verifiers.push(Box::new(verifier));
+        self
+    }
+
+    /// Calls the exported [LifecycleFn](type.LifecycleFn.html) named `symbol` right after the shared library is opened.
+    /// If the function returns a non-zero code, the library is closed again, without running the hook passed to [on_unload](#method.on_unload), and a `LifecycleHook` error is returned.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::LibArc;
+    /// # use sharedlib::OpenOptions;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// let lib: LibArc = try!(unsafe { OpenOptions::new().on_load("plugin_init").on_unload("plugin_fini").open("libexample.so") });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_load<TStr>(&mut self, symbol: TStr) -> &mut Self
+        where TStr: Into<String> {
+        self.load_hook = Some(symbol.into());
+        self
+    }
+
+    /// Calls the exported [LifecycleFn](type.LifecycleFn.html) named `symbol` right before the shared library is closed.
+    /// The library is closed when its [LibUnsafe](struct.LibUnsafe.html) is dropped, which for a [LibTracked](struct.LibTracked.html) is when its last reference is dropped, or by [LibUnsafe::unload_when_idle](struct.LibUnsafe.html#method.unload_when_idle) and [LibUnsafe::close_and_verify](struct.LibUnsafe.html#method.close_and_verify). Unlike ELF destructors, the hook runs exactly then, and exactly once. Only the latter two can report a failure of the hook; when the library is dropped, the failure is ignored.
+    pub fn on_unload<TStr>(&mut self, symbol: TStr) -> &mut Self
+        where TStr: Into<String> {
+        self.unload_hook = Some(symbol.into());
+        self
+    }
+
+    /// Sets whether the shared library remembers the address of every symbol it finds.
+    /// With the cache, finding a symbol which was found before through `find_func` or `find_data`, on any of the library types, takes neither the lock which guards the operating system's error state nor an allocation. Versioned symbols are cached by name and version. The cache is shared by every thread which uses the library, for instance through a [LibArc](type.LibArc.html), and it is emptied when the library is closed. Symbols which are missing are not cached.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::FuncArc;
+    /// # use sharedlib::LibArc;
+    /// # use sharedlib::OpenOptions;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// let lib: LibArc = try!(unsafe { OpenOptions::new().cache_symbols(true).open("examplelib.dll") });
+    /// for _ in 0..1000 {
+    ///     let some_func: FuncArc<fn()> = try!(unsafe { lib.find_func("some_func\0") });
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cache_symbols(&mut self, cache_symbols: bool) -> &mut Self {
+        self.cache_symbols = cache_symbols;
+        self
+    }
+
+    /// Sets whether a `LibraryFindSymbol` error names the exported symbols which the missing symbol may have been meant to be.
+    /// The names are taken from the dynamic symbol table of the file the loader mapped, which is read each time a symbol is missing, so this is best left off where missing symbols are expected. A symbol which only differs from the missing one by C++ or Rust mangling ranks first, followed by symbols within a small edit distance. See [LibraryFindSymbol::suggestions](error/struct.LibraryFindSymbol.html#method.suggestions).
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::LibArc;
+    /// # use sharedlib::OpenOptions;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// let lib: LibArc = try!(unsafe { OpenOptions::new().suggest_symbols(true).open("libexample.so") });
+    /// if let Err(err) = unsafe { lib.find_func::<fn(), _>("plugin_int") } {
+    ///     // "... did you mean 'plugin_init'?"
+    ///     println!("{}", err);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(target_os="linux")]
+    pub fn suggest_symbols(&mut self, suggest_symbols: bool) -> &mut Self {
+        self.suggest_symbols = suggest_symbols;
+        self
+    }
+
+    /// Opens a shared library at the specified path using these options.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem opening the shared library. A `PolicyViolation` error will be returned if the shared library does not satisfy the [LoadPolicy](struct.LoadPolicy.html) passed to [policy](#method.policy). An `IntegrityMismatch` error will be returned if the shared library does not have the digest passed to [verify_sha256](#method.verify_sha256). Any error returned by a [Verifier](verify/trait.Verifier.html) is returned unchanged. A `LibraryFindSymbol` error will be returned if the library does not export a hook passed to [on_load](#method.on_load) or [on_unload](#method.on_unload), and a `LifecycleHook` error if the load hook fails.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to open is unsafe.
+    pub unsafe fn open<TLib, TPath>(&self, path_to_lib: TPath) -> R<TLib>
+        where TLib: From<LibUnsafe>,
+              TPath: AsRef<Path> {
+        let path_to_lib = path_to_lib.as_ref();
+        let mut lib_unsafe = try!(self.open_unsafe(path_to_lib));
+        if self.cache_symbols {
+            lib_unsafe.enable_symbol_cache();
+        }
+        #[cfg(target_os="linux")]
+        {
+            if self.suggest_symbols {
+                lib_unsafe.enable_symbol_suggestions();
+            }
+        }
+        try!(self.run_hooks(&mut lib_unsafe, path_to_lib));
+        let result = TLib::from(lib_unsafe);
+        Ok(result)
+    }
+
+    // Runs the load hook and arms the unload hook. Both are found first, so that a library whose
+    // load hook ran always gets its unload hook.
+    unsafe fn run_hooks(&self, lib: &mut LibUnsafe, path_to_lib: &Path) -> R<()> {
+        let load_hook =
+            match self.load_hook {
+                Some(ref symbol) => Some(try!(BoundHook::find(lib, LifecycleHookKind::Load, symbol, path_to_lib))),
+                None => None,
+            };
+        let unload_hook =
+            match self.unload_hook {
+                Some(ref symbol) => Some(try!(BoundHook::find(lib, LifecycleHookKind::Unload, symbol, path_to_lib))),
+                None => None,
+            };
+        if let Some(load_hook) = load_hook {
+            try!(load_hook.run());
+        }
+        if let Some(unload_hook) = unload_hook {
+            lib.set_unload_hook(unload_hook);
+        }
+        Ok(())
+    }
+
+    #[cfg(any(target_os="linux", target_os="android"))]
+    unsafe fn open_unsafe(&self, path_to_lib: &Path) -> R<LibUnsafe> {
+        if self.policy.is_none() && self.verifiers.is_empty() {
+            return LibUnsafe::new(path_to_lib);
+        }
+
+        let to_open_error =
+            |err| {
+                let err = LibraryOpen::new(Box::new(err), path_to_lib.to_path_buf());
+                E::from(err)
+            };
+        if let Some(ref policy) = self.policy {
+            try!(policy.check_path(path_to_lib));
+        }
+        let mut file =
+            match self.policy {
+                Some(ref policy) => try!(policy.open(path_to_lib)),
+                None => try!(File::open(path_to_lib).map_err(&to_open_error)),
+            };
+        if let Some(ref policy) = self.policy {
+            try!(policy.check_file(&file, path_to_lib));
+        }
+        for verifier in &self.verifiers {
+            try!(file.seek(SeekFrom::Start(0)).map_err(&to_open_error));
+            try!(verifier.verify(&mut file, path_to_lib));
+        }
+        LibUnsafe::new_from_file(&file, path_to_lib)
+    }
+
+    #[cfg(not(any(target_os="linux", target_os="android")))]
+    unsafe fn open_unsafe(&self, path_to_lib: &Path) -> R<LibUnsafe> {
+        LibUnsafe::new(path_to_lib)
+    }
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        OpenOptions::new()
+    }
+}
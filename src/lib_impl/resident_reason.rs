@@ -0,0 +1,12 @@
+/// A reason why a shared library may still be resident after it was closed, as reported by [LibUnsafe::close_and_verify](struct.LibUnsafe.html#method.close_and_verify).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResidentReason {
+    /// The library is marked `DF_1_NODELETE`, for instance because it was linked with `-z nodelete`, so the loader never unloads it.
+    NoDelete,
+    /// The library defines `STB_GNU_UNIQUE` symbols, which make glibc treat it as if it were marked `DF_1_NODELETE`.
+    UniqueSymbols,
+    /// The platform's loader never unloads libraries. For instance, `dlclose` does nothing on musl.
+    PlatformNeverUnloads,
+    /// None of the other reasons apply, so the library is most likely still referenced by another handle, or by another library which depends on it.
+    OtherReferences,
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+// Remembers the addresses of the symbols which were found in a library, so that finding them
+// again takes neither the error lock nor an allocation. Names are stored without their null
+// terminator. Addresses are stored as integers so that the cache can be shared between threads.
+#[derive(Debug)]
+pub struct SymbolCache {
+    symbols: RwLock<HashMap<String, usize>>,
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    versioned_symbols: RwLock<HashMap<String, HashMap<String, usize>>>,
+}
+
+impl SymbolCache {
+    pub fn new() -> Self {
+        SymbolCache {
+            symbols: RwLock::new(HashMap::new()),
+            #[cfg(all(target_os="linux", target_env="gnu"))]
+            versioned_symbols: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<usize> {
+        self.symbols.read().unwrap().get(symbol).cloned()
+    }
+
+    pub fn insert(&self, symbol: &str, address: usize) {
+        self.symbols.write().unwrap().insert(symbol.to_string(), address);
+    }
+
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub fn get_versioned(&self, symbol: &str, version: &str) -> Option<usize> {
+        let versioned_symbols = self.versioned_symbols.read().unwrap();
+        versioned_symbols.get(symbol).and_then(|versions| versions.get(version).cloned())
+    }
+
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub fn insert_versioned(&self, symbol: &str, version: &str, address: usize) {
+        let mut versioned_symbols = self.versioned_symbols.write().unwrap();
+        versioned_symbols
+            .entry(symbol.to_string())
+            .or_default()
+            .insert(version.to_string(), address);
+    }
+
+    pub fn clear(&self) {
+        self.symbols.write().unwrap().clear();
+        #[cfg(all(target_os="linux", target_env="gnu"))]
+        self.versioned_symbols.write().unwrap().clear();
+    }
+}
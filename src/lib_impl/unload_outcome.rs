@@ -0,0 +1,10 @@
+use ResidentReason;
+
+/// The result of [LibUnsafe::close_and_verify](struct.LibUnsafe.html#method.close_and_verify).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UnloadOutcome {
+    /// The library was closed and is no longer resident.
+    Unloaded,
+    /// The library was closed but is still resident, for the contained reasons.
+    StillResident(Vec<ResidentReason>),
+}
@@ -0,0 +1,8 @@
+use std::os::raw::c_char;
+
+// The leading fields of `struct dl_phdr_info`, which are all that is read here.
+#[repr(C)]
+pub struct DlPhdrInfo {
+    pub dlpi_addr: usize,
+    pub dlpi_name: *const c_char,
+}
@@ -0,0 +1,35 @@
+use error::ErrorKind;
+
+// Classifies the message `dlerror` reported after `function` failed. glibc, musl and the macOS
+// loader each word their messages differently, so every known wording is matched. Earlier checks
+// win, since a message may mention several things, such as the object which needed a missing
+// dependency.
+pub fn dlerror_kind(function: &str, message: &str) -> ErrorKind {
+    let message = message.to_lowercase();
+    let finding_symbol = function == "dlsym" || function == "dlvsym";
+    if contains_any(&message, &["undefined symbol", "symbol not found"]) {
+        if finding_symbol {
+            ErrorKind::SymbolNotFound
+        } else {
+            ErrorKind::UndefinedSymbol
+        }
+    } else if contains_any(&message, &["symbol lookup error", "error relocating"]) {
+        ErrorKind::UndefinedSymbol
+    } else if contains_any(&message, &["(needed by", "library not loaded", "version `"]) {
+        ErrorKind::MissingDependency
+    } else if contains_any(&message, &["wrong elf class", "elf file data encoding", "elf file os abi", "elf file version", "elf file's phentsize", "incompatible architecture", "wrong architecture"]) {
+        ErrorKind::WrongArchitecture
+    } else if contains_any(&message, &["invalid elf header", "file too short", "only et_dyn and et_exec", "not a mach-o file", "unknown file type", "exec format error"]) {
+        ErrorKind::NotASharedObject
+    } else if contains_any(&message, &["permission denied", "operation not permitted"]) {
+        ErrorKind::PermissionDenied
+    } else if contains_any(&message, &["no such file", "image not found"]) {
+        ErrorKind::NotFound
+    } else {
+        ErrorKind::Other
+    }
+}
+
+fn contains_any(message: &str, patterns: &[&str]) -> bool {
+    patterns.iter().any(|pattern| message.contains(pattern))
+}
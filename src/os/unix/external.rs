@@ -1,5 +1,9 @@
+#[cfg(target_os="linux")]
+use os::unix::DlPhdrInfo;
 use std::os::raw::c_char;
 use std::os::raw::c_int;
+#[cfg(all(target_os="linux", target_env="gnu"))]
+use std::os::raw::c_long;
 use std::os::raw::c_void;
 
 extern {
@@ -10,4 +14,21 @@ extern {
     pub fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
 
     pub fn dlerror() -> *mut c_char;
+
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub fn dlvsym(handle: *mut c_void, symbol: *const c_char, version: *const c_char) -> *mut c_void;
+
+    pub fn geteuid() -> u32;
+
+    #[cfg(target_os="linux")]
+    pub fn dl_iterate_phdr(
+        callback: Option<unsafe extern "C" fn(info: *mut DlPhdrInfo, size: usize, data: *mut c_void) -> c_int>,
+        data: *mut c_void,
+    ) -> c_int;
+
+    #[cfg(target_os="linux")]
+    pub fn dlinfo(handle: *mut c_void, request: c_int, info: *mut c_void) -> c_int;
+
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub fn dlmopen(lmid: c_long, filename: *const c_char, flags: c_int) -> *mut c_void;
 }
@@ -1,95 +1,400 @@
+use error::ErrorKind;
 use error::LibraryClose;
 use error::LibraryFindSymbol;
 use error::LibraryOpen;
+use error::OsError;
 use os::unix::external;
+#[cfg(all(target_os="linux", target_env="gnu"))]
+use os::unix::DlPhdrInfo;
+#[cfg(target_os="linux")]
+use os::unix::LinkMap;
 use os::unix::OkOrDlerror;
+#[cfg(all(target_os="linux", target_env="gnu"))]
+use os::unix::RTLD_DI_LMID;
 use os::unix::RTLD_LAZY;
+#[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+use os::unix::RTLD_NOLOAD;
+#[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+use os::unix::UnloadProbe;
+#[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+use ResidentReason;
 use SharedlibError as E;
 use SharedlibResult as R;
+#[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+use UnloadOutcome;
 use util;
+#[cfg(target_os="linux")]
+use std::ffi::CStr;
+use std::ffi::CString;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::fs::File;
 use std::mem;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::os::unix::io::AsRawFd;
+use std::os::unix::ffi::OsStrExt;
 use std::path::Path;
+use std::path::PathBuf;
 use std::os::raw::c_char;
+#[cfg(all(target_os="linux", target_env="gnu"))]
+use std::os::raw::c_int;
+#[cfg(all(target_os="linux", target_env="gnu"))]
+use std::os::raw::c_long;
 use std::os::raw::c_void;
+#[cfg(target_os="linux")]
+use std::ffi::OsStr;
+use std::ptr;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 #[derive(Debug)]
 pub struct Lib {
-    handle: *mut c_void
+    handle: *mut c_void,
+    path_to_lib: PathBuf,
+    // The number of open libraries which share `handle`, which is only closed by the last of them.
+    holders: Arc<AtomicUsize>,
 }
 
 impl Lib {
     pub unsafe fn new<TPath>(path_to_lib: TPath) -> R<Lib>
         where TPath: AsRef<Path> {
-        let path_to_lib_str =
-            path_to_lib
-                .as_ref()
-                .to_string_lossy();
-        let path_to_lib_c_str = path_to_lib_str.as_ptr() as *const c_char;
-
-        util::error_guard(
-            || {
-                let result = external::dlopen(path_to_lib_c_str, RTLD_LAZY);
-
-                if result.is_null() {
-                    None
-                } else {
-                    let lib =
-                        Lib {
-                            handle: result,
-                        };
-                    Some(lib)
+        let path_to_lib = path_to_lib.as_ref();
+        let path_to_lib_c_str = try!(Lib::to_c_string(path_to_lib));
+        Lib::open(
+            "dlopen",
+            path_to_lib,
+            || external::dlopen(path_to_lib_c_str.as_ptr(), RTLD_LAZY),
+        )
+    }
+
+    // Loads the library through its open file descriptor so that the object which is mapped is
+    // exactly the one which was inspected through `file`, regardless of what `path_to_lib` names
+    // by now.
+    #[cfg(any(target_os="linux", target_os="android"))]
+    pub unsafe fn new_from_file(file: &File, path_to_lib: &Path) -> R<Lib> {
+        let fd_path = format!("/proc/self/fd/{}", file.as_raw_fd());
+        let fd_path_c_str = CString::new(fd_path).unwrap();
+        Lib::open(
+            "dlopen",
+            path_to_lib,
+            || external::dlopen(fd_path_c_str.as_ptr(), RTLD_LAZY),
+        )
+    }
+
+    // Returns the library only if the loader already has it resident, without loading it.
+    #[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+    pub unsafe fn get_loaded<TPath>(path_to_lib: TPath) -> R<Option<Lib>>
+        where TPath: AsRef<Path> {
+        let path_to_lib = path_to_lib.as_ref();
+        let path_to_lib_c_str = try!(Lib::to_c_string(path_to_lib));
+        let handle = external::dlopen(path_to_lib_c_str.as_ptr(), RTLD_LAZY | RTLD_NOLOAD);
+        let result =
+            if handle.is_null() {
+                // A library which is not resident is not an error, so discard the message the
+                // loader may have left behind.
+                util::error_guard(|| external::dlerror());
+                None
+            } else {
+                let lib =
+                    Lib {
+                        handle: handle,
+                        path_to_lib: path_to_lib.to_path_buf(),
+                        holders: Arc::new(AtomicUsize::new(1)),
+                    };
+                Some(lib)
+            };
+        Ok(result)
+    }
+
+    // Shares the handle with another library, which keeps the object loaded until both are closed.
+    // The object is not opened again, since the path it was opened with may name another file by
+    // now, or, for a library opened through its file descriptor, nothing at all.
+    #[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+    pub fn try_clone(&self) -> R<Lib> {
+        self.holders.fetch_add(1, Ordering::SeqCst);
+        let result =
+            Lib {
+                handle: self.handle,
+                path_to_lib: self.path_to_lib.clone(),
+                holders: self.holders.clone(),
+            };
+        Ok(result)
+    }
+
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub unsafe fn new_in_namespace<TPath>(path_to_lib: TPath, namespace: c_long) -> R<Lib>
+        where TPath: AsRef<Path> {
+        let path_to_lib = path_to_lib.as_ref();
+        let path_to_lib_c_str = try!(Lib::to_c_string(path_to_lib));
+        Lib::open(
+            "dlmopen",
+            path_to_lib,
+            || external::dlmopen(namespace, path_to_lib_c_str.as_ptr(), RTLD_LAZY),
+        )
+    }
+
+    fn to_c_string(path_to_lib: &Path) -> R<CString> {
+        CString::new(path_to_lib.as_os_str().as_bytes())
+            .map_err(
+                |err| {
+                    let err = LibraryOpen::new(Box::new(err), path_to_lib.to_path_buf());
+                    E::from(err)
                 }
-            }
-        ).ok_or_dlerror("dlopen")
+            )
+    }
+
+    unsafe fn open<TOpen>(function: &str, path_to_lib: &Path, open: TOpen) -> R<Lib>
+        where TOpen: FnOnce() -> *mut c_void {
+        let result = open();
+        if result.is_null() {
+            None
+        } else {
+            let lib =
+                Lib {
+                    handle: result,
+                    path_to_lib: path_to_lib.to_path_buf(),
+                    holders: Arc::new(AtomicUsize::new(1)),
+                };
+            Some(lib)
+        }.ok_or_dlerror(function)
         .map_err(
             |err| {
-                let err = LibraryOpen::new(Box::new(err), path_to_lib.as_ref().to_path_buf());
+                let err = Lib::refine_open_error(err, path_to_lib);
+                let err = LibraryOpen::new(Box::new(err), path_to_lib.to_path_buf());
                 E::from(err)
             }
         )
     }
 
+    // glibc words a missing dependency exactly like a missing library, naming the object which
+    // could not be found before the first ':'. An object other than the one which was opened is a
+    // dependency.
+    fn refine_open_error(err: E, path_to_lib: &Path) -> E {
+        match err {
+            E::OsError(ref os_error)
+                if os_error.kind() == ErrorKind::NotFound
+                    && os_error.raw_message().contains("cannot open shared object file") => {
+                let missing = os_error.raw_message().split(": ").next().unwrap_or("");
+                if Path::new(missing) == path_to_lib {
+                    return err;
+                }
+                let os_error =
+                    OsError::new(
+                        ErrorKind::MissingDependency,
+                        os_error.raw_message().to_string(),
+                        os_error.function_called().to_string(),
+                    );
+                E::from(os_error)
+            },
+            err => err,
+        }
+    }
+
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub fn namespace(&self) -> R<c_long> {
+        let mut namespace: c_long = 0;
+        let info = &mut namespace as *mut c_long as *mut c_void;
+        if unsafe { external::dlinfo(self.handle, RTLD_DI_LMID, info) } == 0 {
+            Some(())
+        } else {
+            None
+        }.ok_or_dlerror("dlinfo")
+        .map(|()| namespace)
+    }
+
+    // Returns the path of the file the loader mapped for this library, which for a library found
+    // through the search path is not the path it was opened with.
+    #[cfg(target_os="linux")]
+    pub fn file_path(&self) -> R<PathBuf> {
+        let link_map = unsafe { LinkMap::of(self.handle) };
+        if link_map.is_null() || unsafe { (*link_map).l_name }.is_null() {
+            None
+        } else {
+            let name = unsafe { CStr::from_ptr((*link_map).l_name) }.to_bytes();
+            Some(PathBuf::from(OsStr::from_bytes(name)))
+        }.ok_or_dlerror("dlinfo")
+    }
+
+    // Walks the list of objects which the loader keeps for the namespace of this library.
+    #[cfg(target_os="linux")]
+    pub fn loaded_objects(&self) -> R<Vec<PathBuf>> {
+        let link_map = unsafe { LinkMap::of(self.handle) };
+        if link_map.is_null() {
+            None
+        } else {
+            Some(unsafe { Lib::with_loader_lock(|| Lib::walk_link_map(link_map)) })
+        }.ok_or_dlerror("dlinfo")
+    }
+
+    #[cfg(target_os="linux")]
+    unsafe fn walk_link_map(mut link_map: *mut LinkMap) -> Vec<PathBuf> {
+        let mut result = Vec::new();
+        while !(*link_map).l_prev.is_null() {
+            link_map = (*link_map).l_prev;
+        }
+        while !link_map.is_null() {
+            let name = (*link_map).l_name;
+            if !name.is_null() {
+                let name = CStr::from_ptr(name).to_bytes();
+                if !name.is_empty() {
+                    result.push(PathBuf::from(OsStr::from_bytes(name)));
+                }
+            }
+            link_map = (*link_map).l_next;
+        }
+        result
+    }
+
+    // Runs `func` while glibc holds the lock it takes to add objects to, and remove them from, its
+    // lists, which `dl_iterate_phdr` holds for as long as its callback runs. `func` must not call
+    // into the loader.
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    unsafe fn with_loader_lock<TFn, T>(func: TFn) -> T
+        where TFn: FnOnce() -> T {
+        unsafe extern "C" fn callback<TFn, T>(_info: *mut DlPhdrInfo, _size: usize, data: *mut c_void) -> c_int
+            where TFn: FnOnce() -> T {
+            let &mut (ref mut func, ref mut result) = &mut *(data as *mut (Option<TFn>, Option<T>));
+            if let Some(func) = func.take() {
+                *result = Some(func());
+            }
+            1
+        }
+
+        let mut data: (Option<TFn>, Option<T>) = (Some(func), None);
+        external::dl_iterate_phdr(Some(callback::<TFn, T>), &mut data as *mut (Option<TFn>, Option<T>) as *mut c_void);
+        match data {
+            (_, Some(result)) => result,
+            (Some(func), None) => func(),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    // musl never unmaps an object once it is loaded, so its lists can be walked at any time.
+    #[cfg(all(target_os="linux", not(target_env="gnu")))]
+    unsafe fn with_loader_lock<TFn, T>(func: TFn) -> T
+        where TFn: FnOnce() -> T {
+        func()
+    }
+
     pub unsafe fn find<T, TStr>(&self, symbol_str: TStr) -> R<*const T>
         where TStr: AsRef<str> {
         let symbol = symbol_str.as_ref();
         let symbol = symbol.as_ptr();
         let symbol = symbol as *const c_char;
 
-        util::error_guard(
-            || {
-                let symbol = external::dlsym(self.handle, symbol);
-                if symbol.is_null() {
-                    None
-                } else {
-                    Some(mem::transmute(symbol))
-                }
+        let symbol = external::dlsym(self.handle, symbol);
+        if symbol.is_null() {
+            None
+        } else {
+            Some(mem::transmute(symbol))
+        }.ok_or_dlerror("dlsym")
+        .map_err(
+            |err| {
+                let err = LibraryFindSymbol::new(Box::new(err), symbol_str.as_ref().trim_end_matches('\0').to_string());
+                E::from(err)
             }
-        ).ok_or_dlerror("dlsym")
+        )
+    }
+
+    // Finds the definition of a symbol with the specified version, like `name@VERSION` in a
+    // version script. Both strings must be null terminated.
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    pub unsafe fn find_versioned<T, TStr, TVersion>(&self, symbol_str: TStr, version_str: TVersion) -> R<*const T>
+        where TStr: AsRef<str>,
+              TVersion: AsRef<str> {
+        let symbol = symbol_str.as_ref().as_ptr() as *const c_char;
+        let version = version_str.as_ref().as_ptr() as *const c_char;
+
+        let symbol = external::dlvsym(self.handle, symbol, version);
+        if symbol.is_null() {
+            None
+        } else {
+            Some(mem::transmute(symbol))
+        }.ok_or_dlerror("dlvsym")
         .map_err(
             |err| {
-                let err = LibraryFindSymbol::new(Box::new(err), symbol_str.as_ref().to_string());
+                let symbol = format!("{}@{}", symbol_str.as_ref().trim_end_matches('\0'), version_str.as_ref().trim_end_matches('\0'));
+                let err = LibraryFindSymbol::new(Box::new(err), symbol);
                 E::from(err)
             }
         )
     }
-}
 
-impl Drop for Lib {
-    fn drop(&mut self) {
-        util::error_guard(
-            || {
-                if unsafe { external::dlclose(self.handle) } == 0 {
-                    Some(())
-                } else {
-                    None
+    // Finds every symbol, reusing one buffer for the null terminated names. Any message left
+    // behind by an earlier call is cleared first, so that it cannot be reported as the cause of a
+    // missing symbol.
+    pub unsafe fn find_many<TStr>(&self, symbols: &[TStr]) -> Vec<Result<*const c_void, LibraryFindSymbol>>
+        where TStr: AsRef<str> {
+        let mut buffer = Vec::new();
+        util::error_guard(|| external::dlerror());
+        symbols
+            .iter()
+            .map(
+                |symbol| {
+                    let symbol = symbol.as_ref().trim_end_matches('\0');
+                    buffer.clear();
+                    buffer.extend_from_slice(symbol.as_bytes());
+                    buffer.push(0);
+                    let address = external::dlsym(self.handle, buffer.as_ptr() as *const c_char);
+                    if address.is_null() {
+                        None
+                    } else {
+                        Some(address as *const c_void)
+                    }.ok_or_dlerror("dlsym")
+                    .map_err(|err| LibraryFindSymbol::new(Box::new(err), symbol.to_string()))
                 }
-            }
-        ).ok_or_dlerror("dlclose")
+            ).collect()
+    }
+
+    // Closes the handle, after which it is null so that dropping the library does nothing.
+    pub fn close(&mut self) -> R<()> {
+        self.release().map(|_| ())
+    }
+
+    // Releases this library's share of the handle, and closes the handle if no other library shares
+    // it. Returns whether the handle was closed.
+    fn release(&mut self) -> R<bool> {
+        let handle = mem::replace(&mut self.handle, ptr::null_mut());
+        if self.holders.fetch_sub(1, Ordering::SeqCst) > 1 {
+            return Ok(false);
+        }
+        if unsafe { external::dlclose(handle) } == 0 {
+            Some(true)
+        } else {
+            None
+        }.ok_or_dlerror("dlclose")
         .map_err(
             |err| {
                 let err = LibraryClose::new(Box::new(err));
                 E::from(err)
             }
-        ).unwrap();
+        )
+    }
+
+    // Closes the library and then asks the loader whether the object is still resident.
+    #[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+    pub fn close_and_verify(mut self) -> R<UnloadOutcome> {
+        let path_to_lib_c_str = try!(Lib::to_c_string(&self.path_to_lib));
+        let probe = unsafe { UnloadProbe::new(self.handle, path_to_lib_c_str) };
+        if !try!(self.release()) {
+            return Ok(UnloadOutcome::StillResident(vec![ResidentReason::OtherReferences]));
+        }
+        Ok(probe.outcome())
+    }
+}
+
+// The loader's functions may be called from any thread, and the error state they leave behind is
+// only read through `OkOrDlerror`, under `util::error_guard`.
+unsafe impl Send for Lib {
+}
+
+unsafe impl Sync for Lib {
+}
+
+impl Drop for Lib {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            self.close().unwrap();
+        }
     }
 }
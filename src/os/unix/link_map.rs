@@ -0,0 +1,28 @@
+use os::unix::external;
+use os::unix::RTLD_DI_LINKMAP;
+use std::os::raw::c_char;
+use std::os::raw::c_void;
+use std::ptr;
+
+// The public prefix of glibc's and musl's `struct link_map`.
+#[repr(C)]
+pub struct LinkMap {
+    pub l_addr: usize,
+    pub l_name: *const c_char,
+    pub l_ld: *mut c_void,
+    pub l_next: *mut LinkMap,
+    pub l_prev: *mut LinkMap,
+}
+
+impl LinkMap {
+    // Returns the entry the loader keeps for `handle`, or null if the loader does not report one.
+    pub unsafe fn of(handle: *mut c_void) -> *mut LinkMap {
+        let mut link_map: *mut LinkMap = ptr::null_mut();
+        let info = &mut link_map as *mut *mut LinkMap as *mut c_void;
+        if external::dlinfo(handle, RTLD_DI_LINKMAP, info) == 0 {
+            link_map
+        } else {
+            ptr::null_mut()
+        }
+    }
+}
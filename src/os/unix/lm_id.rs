@@ -0,0 +1,3 @@
+use std::os::raw::c_long;
+
+pub const LM_ID_NEWLM: c_long = -1;
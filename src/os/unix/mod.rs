@@ -1,13 +1,62 @@
+#[cfg(target_os="linux")]
+pub mod dl_phdr_info;
+
+pub mod dlerror_kind;
+
 pub mod external;
 
 pub mod lib;
 
+#[cfg(target_os="linux")]
+pub mod link_map;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub mod lm_id;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod o_nofollow;
+
 pub mod ok_or_dlerror;
 
+#[cfg(target_os="linux")]
+pub mod rtld_di;
+
 pub mod rtld_lazy;
 
+#[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+pub mod rtld_noload;
+
+#[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+pub mod unload_probe;
+
+#[cfg(target_os="linux")]
+pub use os::unix::dl_phdr_info::DlPhdrInfo;
+
+pub use os::unix::dlerror_kind::dlerror_kind;
+
 pub use os::unix::lib::Lib;
 
+#[cfg(target_os="linux")]
+pub use os::unix::link_map::LinkMap;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub use os::unix::lm_id::LM_ID_NEWLM;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub use os::unix::o_nofollow::O_NOFOLLOW;
+
 pub use os::unix::ok_or_dlerror::OkOrDlerror;
 
+#[cfg(target_os="linux")]
+pub use os::unix::rtld_di::RTLD_DI_LINKMAP;
+
+#[cfg(target_os="linux")]
+pub use os::unix::rtld_di::RTLD_DI_LMID;
+
 pub use os::unix::rtld_lazy::RTLD_LAZY;
+
+#[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+pub use os::unix::rtld_noload::RTLD_NOLOAD;
+
+#[cfg(any(target_os="linux", target_os="android", target_os="macos", target_os="ios", target_os="freebsd", target_os="dragonfly", target_os="netbsd"))]
+pub use os::unix::unload_probe::UnloadProbe;
@@ -0,0 +1,7 @@
+use std::os::raw::c_int;
+
+#[cfg(not(any(target_arch="arm", target_arch="aarch64", target_arch="powerpc", target_arch="powerpc64")))]
+pub const O_NOFOLLOW: c_int = 0o400000;
+
+#[cfg(any(target_arch="arm", target_arch="aarch64", target_arch="powerpc", target_arch="powerpc64"))]
+pub const O_NOFOLLOW: c_int = 0o100000;
@@ -1,9 +1,11 @@
 use error::OsError;
 use error::OsErrorFailure;
+use os::unix::dlerror_kind;
 use os::unix::external;
 use SharedlibError as E;
 use SharedlibResult as R;
 use std::ffi::CStr;
+use util;
 
 pub trait OkOrDlerror<T> {
     fn ok_or_dlerror<TStr>(self, function: TStr) -> R<T>
@@ -16,25 +18,37 @@ impl <T> OkOrDlerror<T> for Option<T> {
         match self {
             Some(some) => Ok(some),
             None => {
-                let error = unsafe { external::dlerror() };
-                if error.is_null() {
-                    let err = OsErrorFailure::new(function.as_ref().to_string());
-                    Err(E::from(err))
-                } else {
-                    let message = unsafe { CStr::from_ptr(error) };
-                    let message_string = message.to_string_lossy().to_string();
-                    let err =
-                        OsError::new(
-                            message_string,
-                            function.as_ref().to_string(),
-                        );
-                    Err(E::from(err))
+                // The message may be overwritten by the next call to `dlerror`, so it is copied
+                // before the lock is released.
+                let message = util::error_guard(dlerror_message);
+                match message {
+                    Some(message) => {
+                        let function = function.as_ref();
+                        let kind = dlerror_kind(function, &message);
+                        let err = OsError::new(kind, message, function.to_string());
+                        Err(E::from(err))
+                    },
+                    None => {
+                        let err = OsErrorFailure::new(function.as_ref().to_string());
+                        Err(E::from(err))
+                    },
                 }
             },
         }
     }
 }
 
+// Takes the message of the last error, which also clears it.
+fn dlerror_message() -> Option<String> {
+    let error = unsafe { external::dlerror() };
+    if error.is_null() {
+        None
+    } else {
+        let message = unsafe { CStr::from_ptr(error) };
+        Some(message.to_string_lossy().to_string())
+    }
+}
+
 // First of all, whole error handling scheme in libdl is done via setting and querying some global
 // state, therefore it is not safe to use libdl in MT-capable environment at all. Only in POSIX
 // 2008+TC1 a thread-local state was allowed, which for our purposes is way too late.
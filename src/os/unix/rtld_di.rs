@@ -0,0 +1,5 @@
+use std::os::raw::c_int;
+
+pub const RTLD_DI_LMID: c_int = 1;
+
+pub const RTLD_DI_LINKMAP: c_int = 2;
@@ -0,0 +1,13 @@
+use std::os::raw::c_int;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub const RTLD_NOLOAD: c_int = 0x4;
+
+#[cfg(any(target_os="macos", target_os="ios"))]
+pub const RTLD_NOLOAD: c_int = 0x10;
+
+#[cfg(any(target_os="freebsd", target_os="dragonfly"))]
+pub const RTLD_NOLOAD: c_int = 0x2000;
+
+#[cfg(target_os="netbsd")]
+pub const RTLD_NOLOAD: c_int = 0x200;
@@ -0,0 +1,186 @@
+#[cfg(target_os="linux")]
+use inspect::ElfFile;
+use os::unix::external;
+#[cfg(target_os="linux")]
+use os::unix::DlPhdrInfo;
+#[cfg(target_os="linux")]
+use os::unix::LinkMap;
+#[cfg(all(target_os="linux", target_env="gnu"))]
+use os::unix::RTLD_DI_LMID;
+use os::unix::RTLD_LAZY;
+use os::unix::RTLD_NOLOAD;
+use ResidentReason;
+use UnloadOutcome;
+use util;
+#[cfg(target_os="linux")]
+use std::ffi::CStr;
+use std::ffi::CString;
+#[cfg(target_os="linux")]
+use std::ffi::OsStr;
+#[cfg(target_os="linux")]
+use std::os::raw::c_int;
+#[cfg(all(target_os="linux", target_env="gnu"))]
+use std::os::raw::c_long;
+use std::os::raw::c_void;
+#[cfg(target_os="linux")]
+use std::os::unix::ffi::OsStrExt;
+#[cfg(target_os="linux")]
+use std::path::PathBuf;
+
+#[cfg(target_os="linux")]
+const DT_NULL: usize = 0;
+
+#[cfg(target_os="linux")]
+const DT_FLAGS_1: usize = 0x6fff_fffb;
+
+#[cfg(target_os="linux")]
+const DF_1_NODELETE: usize = 0x8;
+
+#[cfg(target_os="linux")]
+const STB_GNU_UNIQUE: u8 = 10;
+
+// What is known about a library before it is closed, which is needed both to tell whether it was
+// unloaded and, if it was not, why.
+pub struct UnloadProbe {
+    path_to_lib: CString,
+    #[cfg(target_os="linux")]
+    object: Option<(PathBuf, usize)>,
+    #[cfg(all(target_os="linux", target_env="gnu"))]
+    namespace: c_long,
+    reasons: Vec<ResidentReason>,
+}
+
+impl UnloadProbe {
+    // Must be called while `handle` is still open.
+    pub unsafe fn new(handle: *mut c_void, path_to_lib: CString) -> UnloadProbe {
+        let mut result =
+            UnloadProbe {
+                path_to_lib: path_to_lib,
+                #[cfg(target_os="linux")]
+                object: None,
+                #[cfg(all(target_os="linux", target_env="gnu"))]
+                namespace: 0,
+                reasons: Vec::new(),
+            };
+        result.inspect(handle);
+        result
+    }
+
+    #[cfg(target_os="linux")]
+    unsafe fn inspect(&mut self, handle: *mut c_void) {
+        #[cfg(all(target_os="linux", target_env="gnu"))]
+        {
+            let info = &mut self.namespace as *mut c_long as *mut c_void;
+            if external::dlinfo(handle, RTLD_DI_LMID, info) != 0 {
+                self.namespace = 0;
+            }
+        }
+
+        let link_map = LinkMap::of(handle);
+        if link_map.is_null() {
+            util::error_guard(|| external::dlerror());
+        } else {
+            let name = (*link_map).l_name;
+            if !name.is_null() {
+                let name = PathBuf::from(OsStr::from_bytes(CStr::from_ptr(name).to_bytes()));
+                if ElfFile::open(&name)
+                    .map(|elf| elf.dynamic_symbols().iter().any(|symbol| symbol.is_defined() && symbol.binding() == STB_GNU_UNIQUE))
+                    .unwrap_or(false) {
+                    self.reasons.push(ResidentReason::UniqueSymbols);
+                }
+                self.object = Some((name, (*link_map).l_addr));
+            }
+            if UnloadProbe::is_nodelete((*link_map).l_ld as *const usize) {
+                self.reasons.insert(0, ResidentReason::NoDelete);
+            }
+        }
+
+        if cfg!(target_env="musl") {
+            self.reasons.push(ResidentReason::PlatformNeverUnloads);
+        }
+    }
+
+    #[cfg(not(target_os="linux"))]
+    unsafe fn inspect(&mut self, _handle: *mut c_void) {
+    }
+
+    // Reads `DT_FLAGS_1` from the dynamic section as the loader mapped it.
+    #[cfg(target_os="linux")]
+    unsafe fn is_nodelete(mut dynamic: *const usize) -> bool {
+        if dynamic.is_null() {
+            return false;
+        }
+        while *dynamic != DT_NULL {
+            if *dynamic == DT_FLAGS_1 {
+                return *dynamic.offset(1) & DF_1_NODELETE != 0;
+            }
+            dynamic = dynamic.offset(2);
+        }
+        false
+    }
+
+    // Must be called after the handle was closed.
+    pub fn outcome(self) -> UnloadOutcome {
+        let resident = unsafe { self.is_resident() };
+        if !resident {
+            UnloadOutcome::Unloaded
+        } else if self.reasons.is_empty() {
+            UnloadOutcome::StillResident(vec![ResidentReason::OtherReferences])
+        } else {
+            UnloadOutcome::StillResident(self.reasons)
+        }
+    }
+
+    unsafe fn is_resident(&self) -> bool {
+        let path_to_lib = self.path_to_lib.as_ptr();
+        let flags = RTLD_LAZY | RTLD_NOLOAD;
+        #[cfg(all(target_os="linux", target_env="gnu"))]
+        let handle =
+            if self.namespace != 0 {
+                external::dlmopen(self.namespace, path_to_lib, flags)
+            } else {
+                external::dlopen(path_to_lib, flags)
+            };
+        #[cfg(not(all(target_os="linux", target_env="gnu")))]
+        let handle = external::dlopen(path_to_lib, flags);
+
+        if !handle.is_null() {
+            external::dlclose(handle);
+            return true;
+        }
+        util::error_guard(|| external::dlerror());
+        self.is_listed()
+    }
+
+    // Looks for the object in the list of loaded objects, which also covers objects the loader
+    // no longer finds by the path they were opened with.
+    #[cfg(target_os="linux")]
+    unsafe fn is_listed(&self) -> bool {
+        unsafe extern "C" fn callback(info: *mut DlPhdrInfo, _size: usize, data: *mut c_void) -> c_int {
+            let &(ref name, address) = &*(data as *const (PathBuf, usize));
+            let info_name = (*info).dlpi_name;
+            if (*info).dlpi_addr != address || info_name.is_null() {
+                return 0;
+            }
+            let info_name = OsStr::from_bytes(CStr::from_ptr(info_name).to_bytes());
+            if info_name == name.as_os_str() {
+                1
+            } else {
+                0
+            }
+        }
+
+        match self.object {
+            Some(ref object) => {
+                let data = object as *const (PathBuf, usize) as *mut c_void;
+                external::dl_iterate_phdr(Some(callback), data) != 0
+            },
+            None => false,
+        }
+    }
+
+    #[cfg(not(target_os="linux"))]
+    unsafe fn is_listed(&self) -> bool {
+        false
+    }
+}
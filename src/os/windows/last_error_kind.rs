@@ -0,0 +1,30 @@
+use error::ErrorKind;
+
+// Classifies the code `GetLastError` reported after `function` failed.
+pub fn last_error_kind(function: &str, error_code: u32) -> ErrorKind {
+    match error_code {
+        // ERROR_FILE_NOT_FOUND, ERROR_PATH_NOT_FOUND and ERROR_MOD_NOT_FOUND. The last is also
+        // reported for a missing dependency, which cannot be told apart.
+        2 | 3 | 126 => ErrorKind::NotFound,
+        // ERROR_ACCESS_DENIED.
+        5 => ErrorKind::PermissionDenied,
+        // ERROR_BAD_FORMAT and ERROR_INVALID_EXE_SIGNATURE.
+        11 | 191 => ErrorKind::NotASharedObject,
+        // ERROR_INVALID_ORDINAL, which is reported when a library imports a symbol by an ordinal
+        // which its dependency does not export.
+        182 => ErrorKind::UndefinedSymbol,
+        // ERROR_PROC_NOT_FOUND.
+        127 => {
+            if function == "GetProcAddress" {
+                ErrorKind::SymbolNotFound
+            } else {
+                ErrorKind::UndefinedSymbol
+            }
+        },
+        // ERROR_BAD_EXE_FORMAT and ERROR_EXE_MACHINE_TYPE_MISMATCH.
+        193 | 216 => ErrorKind::WrongArchitecture,
+        // ERROR_DLL_NOT_FOUND.
+        1157 => ErrorKind::MissingDependency,
+        _ => ErrorKind::Other,
+    }
+}
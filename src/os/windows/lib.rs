@@ -2,46 +2,79 @@ use error::LibraryClose;
 use error::LibraryFindSymbol;
 use error::LibraryOpen;
 use kernel32;
+use os::windows::GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS;
+use os::windows::GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT;
 use os::windows::OkOrGetLastError;
 use SharedlibError as E;
 use SharedlibResult as R;
+use ResidentReason;
+use UnloadOutcome;
 use std::mem;
+use std::os::raw::c_void;
 use std::os::windows::ffi::OsStrExt;
 use std::path::Path;
-use util;
+use std::path::PathBuf;
+use std::ptr;
+use winapi::ERROR_MOD_NOT_FOUND;
 use winapi::HMODULE;
 use winapi::LPCSTR;
+use winapi::LPCWSTR;
 
 #[derive(Debug)]
 pub struct Lib {
-    handle: HMODULE
+    handle: HMODULE,
+    path_to_lib: PathBuf,
 }
 
 impl Lib {
     pub unsafe fn new<TPath>(path_to_lib: TPath) -> R<Lib>
         where TPath: AsRef<Path> {
-        let path_to_lib_vec: Vec<_> =
-            path_to_lib
-                .as_ref()
-                .as_os_str()
-                .encode_wide()
-                .chain((0..1))
-                .collect();
+        let path_to_lib_vec = Lib::to_wide(path_to_lib.as_ref());
         let path_to_lib_ptr = path_to_lib_vec.as_ptr();
 
-        util::error_guard(
-            || {
-                let handle = kernel32::LoadLibraryW(path_to_lib_ptr);
-                let lib_option =
-                    if handle.is_null()  {
-                        None
-                    } else {
-                        let lib = Lib { handle: handle };
-                        Some(lib)
+        let handle = kernel32::LoadLibraryW(path_to_lib_ptr);
+        let lib_option =
+            if handle.is_null()  {
+                None
+            } else {
+                let lib =
+                    Lib {
+                        handle: handle,
+                        path_to_lib: path_to_lib.as_ref().to_path_buf(),
                     };
-                lib_option.ok_or_get_last_error("LoadLibraryW")
+                Some(lib)
+            };
+        lib_option.ok_or_get_last_error("LoadLibraryW")
+        .map_err(
+            |err| {
+                let err = LibraryOpen::new(Box::new(err), path_to_lib.as_ref().to_path_buf());
+                E::from(err)
             }
-        ).map_err(
+        )
+    }
+
+    // Returns the library only if it is already loaded, without loading it. Unlike
+    // `GetModuleHandleW`, `GetModuleHandleExW` increments the reference count of the module.
+    pub unsafe fn get_loaded<TPath>(path_to_lib: TPath) -> R<Option<Lib>>
+        where TPath: AsRef<Path> {
+        let path_to_lib_vec = Lib::to_wide(path_to_lib.as_ref());
+        let path_to_lib_ptr = path_to_lib_vec.as_ptr();
+
+        let mut handle: HMODULE = ptr::null_mut();
+        let result =
+            if kernel32::GetModuleHandleExW(0, path_to_lib_ptr, &mut handle) != 0 {
+                let lib =
+                    Lib {
+                        handle: handle,
+                        path_to_lib: path_to_lib.as_ref().to_path_buf(),
+                    };
+                Ok(Some(lib))
+            } else if kernel32::GetLastError() == ERROR_MOD_NOT_FOUND {
+                Ok(None)
+            } else {
+                None.ok_or_get_last_error("GetModuleHandleExW")
+            };
+        result.map_err(
             |err| {
                 let err = LibraryOpen::new(Box::new(err), path_to_lib.as_ref().to_path_buf());
                 E::from(err)
@@ -49,45 +82,127 @@ impl Lib {
         )
     }
 
+    // Opens another handle to the same module, which increments its reference count. A module
+    // handle is the base address of the module, so it can be looked up by address.
+    pub fn try_clone(&self) -> R<Lib> {
+        let mut handle: HMODULE = ptr::null_mut();
+        let address = self.handle as LPCWSTR;
+        if unsafe { kernel32::GetModuleHandleExW(GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS, address, &mut handle) } != 0 {
+            let lib =
+                Lib {
+                    handle: handle,
+                    path_to_lib: self.path_to_lib.clone(),
+                };
+            Some(lib)
+        } else {
+            None
+        }.ok_or_get_last_error("GetModuleHandleExW")
+        .map_err(
+            |err| {
+                let err = LibraryOpen::new(Box::new(err), self.path_to_lib.clone());
+                E::from(err)
+            }
+        )
+    }
+
+    fn to_wide(path_to_lib: &Path) -> Vec<u16> {
+        path_to_lib
+            .as_os_str()
+            .encode_wide()
+            .chain((0..1))
+            .collect()
+    }
+
     pub unsafe fn find<T, TStr>(&self, symbol_str: TStr) -> R<*const T>
         where TStr: AsRef<str> {
         let symbol = symbol_str.as_ref();
         let symbol = symbol.as_ptr();
         let symbol = symbol as LPCSTR;
 
-        util::error_guard(
-            || {
-                let symbol = kernel32::GetProcAddress(self.handle, symbol);
-                if symbol.is_null() {
-                    None
-                } else {
-                    Some(mem::transmute(symbol))
-                }.ok_or_get_last_error("GetProcAddress")
-            }
-        ).map_err(
+        let symbol = kernel32::GetProcAddress(self.handle, symbol);
+        if symbol.is_null() {
+            None
+        } else {
+            Some(mem::transmute(symbol))
+        }.ok_or_get_last_error("GetProcAddress")
+        .map_err(
             |err| {
-                let err = LibraryFindSymbol::new(Box::new(err), symbol_str.as_ref().to_string());
+                let err = LibraryFindSymbol::new(Box::new(err), symbol_str.as_ref().trim_end_matches('\0').to_string());
                 E::from(err)
             }
         )
     }
-}
 
-impl Drop for Lib {
-    fn drop(&mut self) {
-        util::error_guard(
-            || {
-                if unsafe { kernel32::FreeLibrary(self.handle) } == 0 {
-                    None
-                } else {
-                    Some(())
-                }.ok_or_get_last_error("FreeLibrary")
-            }
-        ).map_err(
+    // Finds every symbol, reusing one buffer for the null terminated names.
+    pub unsafe fn find_many<TStr>(&self, symbols: &[TStr]) -> Vec<Result<*const c_void, LibraryFindSymbol>>
+        where TStr: AsRef<str> {
+        let mut buffer = Vec::new();
+        symbols
+            .iter()
+            .map(
+                |symbol| {
+                    let symbol = symbol.as_ref().trim_end_matches('\0');
+                    buffer.clear();
+                    buffer.extend_from_slice(symbol.as_bytes());
+                    buffer.push(0);
+                    let address = kernel32::GetProcAddress(self.handle, buffer.as_ptr() as LPCSTR);
+                    if address.is_null() {
+                        None
+                    } else {
+                        Some(address as *const c_void)
+                    }.ok_or_get_last_error("GetProcAddress")
+                    .map_err(|err| LibraryFindSymbol::new(Box::new(err), symbol.to_string()))
+                }
+            ).collect()
+    }
+
+    // Frees the handle, after which it is null so that dropping the library does nothing.
+    pub fn close(&mut self) -> R<()> {
+        let handle = mem::replace(&mut self.handle, ptr::null_mut());
+        if unsafe { kernel32::FreeLibrary(handle) } == 0 {
+            None
+        } else {
+            Some(())
+        }.ok_or_get_last_error("FreeLibrary")
+        .map_err(
             |err| {
                 let err = LibraryClose::new(Box::new(err));
                 E::from(err)
             }
-        ).unwrap()
+        )
+    }
+
+    // Frees the library and then looks the module up by its former base address. Windows has no
+    // equivalent of `DF_1_NODELETE` which could be inspected, so a resident module is attributed
+    // to other references.
+    pub fn close_and_verify(mut self) -> R<UnloadOutcome> {
+        let address = self.handle as LPCWSTR;
+        try!(self.close());
+        let mut handle: HMODULE = ptr::null_mut();
+        let flags = GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT;
+        let resident = unsafe { kernel32::GetModuleHandleExW(flags, address, &mut handle) != 0 };
+        let result =
+            if resident {
+                UnloadOutcome::StillResident(vec![ResidentReason::OtherReferences])
+            } else {
+                UnloadOutcome::Unloaded
+            };
+        Ok(result)
+    }
+}
+
+// The loader's functions may be called from any thread, and the last error they leave behind is
+// kept per thread, so it is read without any lock.
+unsafe impl Send for Lib {
+}
+
+unsafe impl Sync for Lib {
+}
+
+impl Drop for Lib {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            self.close().unwrap()
+        }
     }
 }
@@ -1,7 +1,17 @@
+pub mod get_module_handle_ex_flag;
+
+pub mod last_error_kind;
+
 pub mod lib;
 
 pub mod ok_or_get_last_error;
 
+pub use os::windows::get_module_handle_ex_flag::GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS;
+
+pub use os::windows::get_module_handle_ex_flag::GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT;
+
+pub use os::windows::last_error_kind::last_error_kind;
+
 pub use os::windows::lib::Lib;
 
 pub use os::windows::ok_or_get_last_error::OkOrGetLastError;
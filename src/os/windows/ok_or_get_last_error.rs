@@ -1,6 +1,7 @@
 use error::OsError;
 use error::OsErrorFailure;
 use kernel32;
+use os::windows::last_error_kind;
 use SharedlibError as E;
 use SharedlibResult as R;
 use std::io::Error as IoError;
@@ -22,11 +23,13 @@ impl <T> OkOrGetLastError<T> for Option<T> {
                         Err(E::from(err))
                     },
                     error_code => {
+                        let function = function.as_ref();
                         let cause = IoError::from_raw_os_error(error_code as i32);
                         let err =
                             OsError::new(
+                                last_error_kind(function, error_code),
                                 cause.to_string(),
-                                function.as_ref().to_string()
+                                function.to_string()
                             );
                         Err(E::from(err))
                     },
@@ -0,0 +1,74 @@
+/// Exports a [PluginDescriptor](plugin/struct.PluginDescriptor.html) which makes the shared library a plugin for the [PluginApi](plugin/trait.PluginApi.html) `$api`, implemented by the constant expression `$value`.
+/// The descriptor records the name and version of the crate which invokes the macro, so it should be invoked once, in the plugin's `cdylib` crate.
+/// A third argument, `dependencies { "name" => "requirement", ... }`, declares the plugins this plugin needs. A [PluginRegistry](plugin/struct.PluginRegistry.html) only loads the plugin once each of them is loaded with a version which meets its requirement.
+///
+/// # Examples
+/// ``` no_run
+/// # #[macro_use] extern crate sharedlib;
+/// # use sharedlib::plugin::PluginApi;
+/// #[repr(C)]
+/// pub struct GreeterApi {
+///     pub greet: extern "C" fn() -> u32,
+/// }
+///
+/// unsafe impl PluginApi for GreeterApi {
+///     const NAME: &'static str = "greeter";
+///     const VERSION: u32 = 1;
+/// }
+///
+/// extern "C" fn greet() -> u32 {
+///     42
+/// }
+///
+/// declare_plugin!(
+///     GreeterApi,
+///     GreeterApi { greet: greet },
+///     dependencies {
+///         "compression" => "^1.2",
+///     }
+/// );
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! declare_plugin {
+    ($api:ty, $value:expr) => {
+        $crate::declare_plugin!($api, $value, dependencies {});
+    };
+    ($api:ty, $value:expr, dependencies { $($name:expr => $requirement:expr),* $(,)* }) => {
+        #[no_mangle]
+        #[allow(non_upper_case_globals)]
+        pub static sharedlib_plugin_descriptor: $crate::plugin::PluginDescriptor =
+            $crate::plugin::PluginDescriptor {
+                magic: $crate::plugin::PLUGIN_MAGIC,
+                abi_version: $crate::plugin::PLUGIN_ABI_VERSION,
+                descriptor_size: ::std::mem::size_of::<$crate::plugin::PluginDescriptor>() as u32,
+                rustc_version_hash: $crate::plugin::RUSTC_VERSION_HASH,
+                name: env!("CARGO_PKG_NAME").as_ptr(),
+                name_len: env!("CARGO_PKG_NAME").len(),
+                version: env!("CARGO_PKG_VERSION").as_ptr(),
+                version_len: env!("CARGO_PKG_VERSION").len(),
+                api_name: <$api as $crate::plugin::PluginApi>::NAME.as_ptr(),
+                api_name_len: <$api as $crate::plugin::PluginApi>::NAME.len(),
+                api_version: <$api as $crate::plugin::PluginApi>::VERSION,
+                api: {
+                    static SHAREDLIB_PLUGIN_API: $api = $value;
+                    &SHAREDLIB_PLUGIN_API as *const $api as *const ::std::os::raw::c_void
+                },
+                dependencies: {
+                    static SHAREDLIB_PLUGIN_DEPENDENCIES: &[$crate::plugin::DependencyDescriptor] =
+                        &[
+                            $(
+                                $crate::plugin::DependencyDescriptor {
+                                    name: $name.as_ptr(),
+                                    name_len: $name.len(),
+                                    requirement: $requirement.as_ptr(),
+                                    requirement_len: $requirement.len(),
+                                },
+                            )*
+                        ];
+                    SHAREDLIB_PLUGIN_DEPENDENCIES.as_ptr()
+                },
+                dependencies_len: <[&str]>::len(&[$($name),*]),
+            };
+    };
+}
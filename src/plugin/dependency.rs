@@ -0,0 +1,27 @@
+/// A plugin which another plugin needs, named by the name it declares and restricted by a version requirement.
+/// Requirements use the syntax of Cargo, such as `^1.2`, `~1.2.3`, `>=1.0, <2.0`, `1.*`, or `*`, and a bare version means `^`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dependency {
+    name: String,
+    requirement: String,
+}
+
+impl Dependency {
+    /// Creates a new [Dependency](struct.Dependency.html).
+    pub fn new(name: String, requirement: String) -> Self {
+        Dependency {
+            name: name,
+            requirement: requirement,
+        }
+    }
+
+    /// Returns the name of the plugin which is depended on.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version requirement on the plugin which is depended on.
+    pub fn requirement(&self) -> &str {
+        &self.requirement
+    }
+}
@@ -0,0 +1,33 @@
+use plugin::Dependency;
+use plugin::PluginDescriptor;
+
+/// A dependency as a [PluginDescriptor](struct.PluginDescriptor.html) records it. It is created by [declare_plugin!](../macro.declare_plugin.html) and should not need to be built by hand.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DependencyDescriptor {
+    /// The name of the plugin which is depended on, as UTF-8 which is not null terminated.
+    pub name: *const u8,
+    /// The length of `name` in bytes.
+    pub name_len: usize,
+    /// The version requirement on the plugin which is depended on, as UTF-8 which is not null terminated.
+    pub requirement: *const u8,
+    /// The length of `requirement` in bytes.
+    pub requirement_len: usize,
+}
+
+// The descriptor only points at immutable statics of the plugin.
+unsafe impl Sync for DependencyDescriptor {
+}
+
+impl DependencyDescriptor {
+    /// Copies the dependency out of the plugin. Invalid UTF-8 is replaced.
+    pub fn to_dependency(&self) -> Dependency {
+        unsafe {
+            Dependency::new(
+                PluginDescriptor::string(self.name, self.name_len),
+                PluginDescriptor::string(self.requirement, self.requirement_len),
+            )
+        }
+    }
+
+}
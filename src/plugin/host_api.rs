@@ -0,0 +1,173 @@
+use plugin::HostApiRef;
+use std::alloc;
+use std::alloc::Layout;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr;
+use std::slice;
+
+/// The version of [HostApi](struct.HostApi.html). It grows whenever fields are appended to the end of [HostApi](struct.HostApi.html); fields are never removed or reordered.
+pub const HOST_API_VERSION: u32 = 1;
+
+/// The log level of messages about failures.
+pub const LOG_ERROR: u32 = 1;
+
+/// The log level of messages about problems which do not stop the plugin.
+pub const LOG_WARN: u32 = 2;
+
+/// The log level of informational messages.
+pub const LOG_INFO: u32 = 3;
+
+/// The log level of messages which help to debug the plugin.
+pub const LOG_DEBUG: u32 = 4;
+
+/// The log level of detailed tracing messages.
+pub const LOG_TRACE: u32 = 5;
+
+/// The services a host offers to its plugins, as a table of functions which the host passes to `sharedlib_plugin_init`. Plugins reach the host through it instead of through symbols of the host's executable, which are only visible to plugins when the host is linked with `-rdynamic`.
+/// Every function receives `context` as its first argument, so a host can keep its state there. Since plugins may call the functions from any thread, they must be thread safe.
+///
+/// A newer host may pass a larger [HostApi](struct.HostApi.html) than a plugin knows, and an older host a smaller one. Plugins reach it through the [HostApiRef](struct.HostApiRef.html) which [from_ptr](#method.from_ptr) returns, whose methods only read fields which lie within `size`, so a plugin which uses them keeps working with hosts of any version.
+///
+/// # Examples
+/// ``` no_run
+/// # use sharedlib::plugin::HostApi;
+/// # use sharedlib::plugin::HOST_API_UNSUPPORTED;
+/// # use sharedlib::plugin::LOG_INFO;
+/// # use std::os::raw::c_int;
+/// #[no_mangle]
+/// pub unsafe extern "C" fn sharedlib_plugin_init(host: *const HostApi) -> c_int {
+///     match HostApi::from_ptr(host, 1) {
+///         Some(host) => {
+///             host.log(LOG_INFO, "greeter is ready");
+///             0
+///         },
+///         None => HOST_API_UNSUPPORTED,
+///     }
+/// }
+/// ```
+#[repr(C)]
+pub struct HostApi {
+    /// The size of the host's [HostApi](struct.HostApi.html) in bytes.
+    pub size: u32,
+    /// The [HOST_API_VERSION](constant.HOST_API_VERSION.html) the host was built with.
+    pub version: u32,
+    /// The host's state, which is passed to every function.
+    pub context: *mut c_void,
+    /// Logs `message`, which is UTF-8 of `message_len` bytes, at one of the log levels such as [LOG_INFO](constant.LOG_INFO.html).
+    pub log: unsafe extern "C" fn(context: *mut c_void, level: u32, message: *const u8, message_len: usize),
+    /// Allocates `size` bytes aligned to `align` from the host's allocator. Returns null on failure.
+    pub alloc: unsafe extern "C" fn(context: *mut c_void, size: usize, align: usize) -> *mut u8,
+    /// Frees memory which `alloc` returned for the same `size` and `align`.
+    pub dealloc: unsafe extern "C" fn(context: *mut c_void, ptr: *mut u8, size: usize, align: usize),
+    /// Looks up the configuration value of the key, which is UTF-8 of `key_len` bytes. Copies as much of the value as fits into `buffer` and returns the length of the whole value, or returns -1 if the key is not set.
+    pub config: unsafe extern "C" fn(context: *mut c_void, key: *const u8, key_len: usize, buffer: *mut u8, buffer_len: usize) -> isize,
+}
+
+// The host promises that its functions may be called from any thread.
+unsafe impl Send for HostApi {
+}
+
+unsafe impl Sync for HostApi {
+}
+
+impl HostApi {
+    /// Creates a [HostApi](struct.HostApi.html) of the current version without a context. It logs to standard error, allocates from the global allocator, and has no configuration. Replace its functions to offer the host's own services.
+    pub const fn new() -> Self {
+        HostApi {
+            size: mem::size_of::<HostApi>() as u32,
+            version: HOST_API_VERSION,
+            context: ptr::null_mut(),
+            log: default_log,
+            alloc: default_alloc,
+            dealloc: default_dealloc,
+            config: default_config,
+        }
+    }
+
+    /// Returns the [HostApi](struct.HostApi.html) at `host` if it is at least of `min_version`, for a plugin to call from `sharedlib_plugin_init`.
+    /// The host's table may be shorter than the one the plugin was built with, so it is returned as a [HostApiRef](struct.HostApiRef.html), which only reads the fields the host provides.
+    ///
+    /// # Safety
+    /// `host` must be null or the pointer passed to `sharedlib_plugin_init`, and the returned [HostApiRef](struct.HostApiRef.html) must not outlive the plugin.
+    pub unsafe fn from_ptr<'a>(host: *const HostApi, min_version: u32) -> Option<HostApiRef<'a>> {
+        // `size` and `version` keep their offsets in every version, so they can be read before
+        // the size of the rest is known.
+        if host.is_null() || ((*host).size as usize) < 8 || (*host).version < min_version {
+            None
+        } else {
+            Some(HostApiRef::new(host))
+        }
+    }
+
+    /// Returns whether `field`, a field of this [HostApi](struct.HostApi.html), lies within `size`. A host may set `size` below the size of the struct to leave out the fields at its end.
+    /// Returns false if `field` is not inside this [HostApi](struct.HostApi.html).
+    pub fn provides<TField>(&self, field: &TField) -> bool {
+        let len = mem::size_of::<TField>();
+        match (field as *const TField as usize).checked_sub(self as *const HostApi as usize) {
+            Some(offset) if offset + len <= mem::size_of::<HostApi>() => HostApiRef::from(self).provides(offset, len),
+            _ => false,
+        }
+    }
+
+    /// Logs `message` through the host at `level`, or does nothing if the host does not log.
+    pub fn log(&self, level: u32, message: &str) {
+        HostApiRef::from(self).log(level, message)
+    }
+
+    /// Allocates `size` bytes aligned to `align` from the host's allocator. Returns null on failure, or if the host does not allocate.
+    pub fn alloc(&self, size: usize, align: usize) -> *mut u8 {
+        HostApiRef::from(self).alloc(size, align)
+    }
+
+    /// Frees memory which [alloc](#method.alloc) returned.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by [alloc](#method.alloc) of the same host for the same `size` and `align`, and must not be used afterwards.
+    pub unsafe fn dealloc(&self, ptr: *mut u8, size: usize, align: usize) {
+        HostApiRef::from(self).dealloc(ptr, size, align)
+    }
+
+    /// Returns the host's configuration value of `key`, or `None` if it is not set or the host has no configuration. Invalid UTF-8 is replaced.
+    pub fn config(&self, key: &str) -> Option<String> {
+        HostApiRef::from(self).config(key)
+    }
+}
+
+impl Default for HostApi {
+    fn default() -> Self {
+        HostApi::new()
+    }
+}
+
+unsafe extern "C" fn default_log(_context: *mut c_void, level: u32, message: *const u8, message_len: usize) {
+    let level =
+        match level {
+            LOG_ERROR => "error",
+            LOG_WARN => "warn",
+            LOG_INFO => "info",
+            LOG_DEBUG => "debug",
+            _ => "trace",
+        };
+    let message = String::from_utf8_lossy(slice::from_raw_parts(message, message_len));
+    eprintln!("[{}] {}", level, message);
+}
+
+unsafe extern "C" fn default_alloc(_context: *mut c_void, size: usize, align: usize) -> *mut u8 {
+    match Layout::from_size_align(size, align) {
+        Ok(layout) if size != 0 => alloc::alloc(layout),
+        _ => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn default_dealloc(_context: *mut c_void, ptr: *mut u8, size: usize, align: usize) {
+    if let Ok(layout) = Layout::from_size_align(size, align) {
+        if !ptr.is_null() && size != 0 {
+            alloc::dealloc(ptr, layout);
+        }
+    }
+}
+
+unsafe extern "C" fn default_config(_context: *mut c_void, _key: *const u8, _key_len: usize, _buffer: *mut u8, _buffer_len: usize) -> isize {
+    -1
+}
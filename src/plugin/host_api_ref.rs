@@ -0,0 +1,143 @@
+use plugin::HostApi;
+use std::marker::PhantomData;
+use std::mem;
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+use std::ptr;
+
+type LogFn = unsafe extern "C" fn(context: *mut c_void, level: u32, message: *const u8, message_len: usize);
+
+type AllocFn = unsafe extern "C" fn(context: *mut c_void, size: usize, align: usize) -> *mut u8;
+
+type DeallocFn = unsafe extern "C" fn(context: *mut c_void, ptr: *mut u8, size: usize, align: usize);
+
+type ConfigFn = unsafe extern "C" fn(context: *mut c_void, key: *const u8, key_len: usize, buffer: *mut u8, buffer_len: usize) -> isize;
+
+/// A [HostApi](struct.HostApi.html) which a host passed to a plugin, as returned by [HostApi::from_ptr](struct.HostApi.html#method.from_ptr).
+/// The host's table may be shorter than the [HostApi](struct.HostApi.html) the plugin was built with, if the host is older. Rather than a reference, which would claim that the whole struct is readable, it keeps a pointer and only reads the fields which lie within the host's `size`.
+#[derive(Clone, Copy, Debug)]
+pub struct HostApiRef<'a> {
+    host: *const HostApi,
+    lifetime: PhantomData<&'a HostApi>,
+}
+
+// The host promises that its functions may be called from any thread.
+unsafe impl <'a> Send for HostApiRef<'a> {
+}
+
+unsafe impl <'a> Sync for HostApiRef<'a> {
+}
+
+impl <'a> HostApiRef<'a> {
+    // `host` must be valid for at least its `size` bytes, which must be at least 8, for `'a`.
+    pub(crate) unsafe fn new(host: *const HostApi) -> Self {
+        HostApiRef {
+            host: host,
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Returns the size of the host's [HostApi](struct.HostApi.html) in bytes.
+    pub fn size(&self) -> u32 {
+        // `size` and `version` keep their offsets in every version.
+        unsafe { (*self.host).size }
+    }
+
+    /// Returns the [HOST_API_VERSION](constant.HOST_API_VERSION.html) the host was built with.
+    pub fn version(&self) -> u32 {
+        unsafe { (*self.host).version }
+    }
+
+    /// Returns the whole [HostApi](struct.HostApi.html), or `None` if the host's is shorter than the one the plugin was built with.
+    pub fn get(&self) -> Option<&'a HostApi> {
+        if self.size() as usize >= mem::size_of::<HostApi>() {
+            Some(unsafe { &*self.host })
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the field which starts at `offset` within [HostApi](struct.HostApi.html) and spans `len` bytes lies within the host's `size`. Plugins check fields which were appended in versions newer than the oldest host they support.
+    pub fn provides(&self, offset: usize, len: usize) -> bool {
+        match offset.checked_add(len) {
+            Some(end) => end <= self.size() as usize,
+            None => false,
+        }
+    }
+
+    /// Logs `message` through the host at `level`, or does nothing if the host does not log.
+    pub fn log(&self, level: u32, message: &str) {
+        if let (Some(context), Some(log)) = (self.context(), self.field::<LogFn>(field_offset(|host| unsafe { ptr::addr_of!((*host).log) }))) {
+            unsafe { log(context, level, message.as_ptr(), message.len()) };
+        }
+    }
+
+    /// Allocates `size` bytes aligned to `align` from the host's allocator. Returns null on failure, or if the host does not allocate.
+    pub fn alloc(&self, size: usize, align: usize) -> *mut u8 {
+        match (self.context(), self.field::<AllocFn>(field_offset(|host| unsafe { ptr::addr_of!((*host).alloc) }))) {
+            (Some(context), Some(alloc)) => unsafe { alloc(context, size, align) },
+            _ => ptr::null_mut(),
+        }
+    }
+
+    /// Frees memory which [alloc](#method.alloc) returned.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by [alloc](#method.alloc) of the same host for the same `size` and `align`, and must not be used afterwards.
+    pub unsafe fn dealloc(&self, ptr: *mut u8, size: usize, align: usize) {
+        if let (Some(context), Some(dealloc)) = (self.context(), self.field::<DeallocFn>(field_offset(|host| unsafe { ptr::addr_of!((*host).dealloc) }))) {
+            dealloc(context, ptr, size, align);
+        }
+    }
+
+    /// Returns the host's configuration value of `key`, or `None` if it is not set or the host has no configuration. Invalid UTF-8 is replaced.
+    pub fn config(&self, key: &str) -> Option<String> {
+        let (context, config) =
+            match (self.context(), self.field::<ConfigFn>(field_offset(|host| unsafe { ptr::addr_of!((*host).config) }))) {
+                (Some(context), Some(config)) => (context, config),
+                _ => return None,
+            };
+        let mut buffer: Vec<u8> = Vec::new();
+        loop {
+            let len = unsafe { config(context, key.as_ptr(), key.len(), buffer.as_mut_ptr(), buffer.len()) };
+            if len < 0 {
+                return None;
+            }
+            let len = len as usize;
+            if len <= buffer.len() {
+                buffer.truncate(len);
+                return Some(String::from_utf8_lossy(&buffer).into_owned());
+            }
+            buffer.resize(len, 0);
+        }
+    }
+
+    fn context(&self) -> Option<*mut c_void> {
+        self.field::<*mut c_void>(field_offset(|host| unsafe { ptr::addr_of!((*host).context) }))
+    }
+
+    // Reads the field at `offset` without ever forming a reference to the rest of the struct.
+    fn field<T>(&self, offset: usize) -> Option<T>
+        where T: Copy {
+        if self.provides(offset, mem::size_of::<T>()) {
+            Some(unsafe { ptr::read((self.host as *const u8).add(offset) as *const T) })
+        } else {
+            None
+        }
+    }
+}
+
+impl <'a> From<&'a HostApi> for HostApiRef<'a> {
+    fn from(host: &'a HostApi) -> Self {
+        unsafe { HostApiRef::new(host) }
+    }
+}
+
+// Returns the offset of the field of `HostApi` which `field` projects to. It is computed from an
+// uninitialized instance, since `mem::offset_of!` needs a much newer compiler than the rest of the
+// crate. `field` must only project, never read.
+pub(crate) fn field_offset<TField>(field: fn(*const HostApi) -> *const TField) -> usize {
+    let host = MaybeUninit::<HostApi>::uninit();
+    let base = host.as_ptr();
+    field(base) as usize - base as usize
+}
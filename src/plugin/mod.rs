@@ -0,0 +1,80 @@
+//! Loads shared libraries as plugins which identify themselves with a descriptor.
+//!
+//! A plugin exports a [PluginDescriptor](struct.PluginDescriptor.html) through [declare_plugin!](../macro.declare_plugin.html). The descriptor carries a magic number, the plugin ABI version, a hash of the compiler version, the plugin's crate name and version, and the [PluginApi](trait.PluginApi.html) the plugin implements. A [PluginLoader](struct.PluginLoader.html) checks all of them and passes the host's [HostApi](struct.HostApi.html) to the plugin's init entry point before it returns a typed [Plugin](struct.Plugin.html), and a [PluginRegistry](struct.PluginRegistry.html) loads every plugin in a directory.
+
+#[macro_use]
+mod declare_plugin;
+
+mod dependency;
+
+mod dependency_descriptor;
+
+mod host_api;
+
+mod host_api_ref;
+
+mod plugin;
+
+mod plugin_abi;
+
+mod plugin_api;
+
+mod plugin_descriptor;
+
+mod plugin_init;
+
+mod plugin_loader;
+
+mod plugin_registry;
+
+mod rejected_plugin;
+
+mod rejection_reason;
+
+pub use plugin::dependency::Dependency;
+
+pub use plugin::dependency_descriptor::DependencyDescriptor;
+
+pub use plugin::host_api::HOST_API_VERSION;
+
+pub use plugin::host_api::HostApi;
+
+pub use plugin::host_api_ref::HostApiRef;
+
+pub use plugin::host_api::LOG_DEBUG;
+
+pub use plugin::host_api::LOG_ERROR;
+
+pub use plugin::host_api::LOG_INFO;
+
+pub use plugin::host_api::LOG_TRACE;
+
+pub use plugin::host_api::LOG_WARN;
+
+pub use plugin::plugin::Plugin;
+
+pub use plugin::plugin_abi::PLUGIN_ABI_VERSION;
+
+pub use plugin::plugin_abi::PLUGIN_DESCRIPTOR_SYMBOL;
+
+pub use plugin::plugin_abi::PLUGIN_MAGIC;
+
+pub use plugin::plugin_abi::RUSTC_VERSION_HASH;
+
+pub use plugin::plugin_api::PluginApi;
+
+pub use plugin::plugin_descriptor::PluginDescriptor;
+
+pub use plugin::plugin_init::HOST_API_UNSUPPORTED;
+
+pub use plugin::plugin_init::InitPlugin;
+
+pub use plugin::plugin_init::PLUGIN_INIT_SYMBOL;
+
+pub use plugin::plugin_loader::PluginLoader;
+
+pub use plugin::plugin_registry::PluginRegistry;
+
+pub use plugin::rejected_plugin::RejectedPlugin;
+
+pub use plugin::rejection_reason::RejectionReason;
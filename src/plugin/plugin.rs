@@ -0,0 +1,109 @@
+use LibArc;
+use plugin::Dependency;
+use plugin::PluginApi;
+use std::ops::Deref;
+use std::path::Path;
+use std::path::PathBuf;
+use std::ptr;
+
+/// A loaded plugin whose descriptor matched the host, returned by [PluginLoader::load](struct.PluginLoader.html#method.load).
+/// The plugin's API is reached through [api](#method.api) or by dereferencing the [Plugin](struct.Plugin.html). The library stays loaded for as long as the [Plugin](struct.Plugin.html) or a clone of its [LibArc](../type.LibArc.html) exists.
+/// A plugin which a [PluginRegistry](struct.PluginRegistry.html) loaded also keeps the libraries of its dependencies loaded.
+#[derive(Debug)]
+pub struct Plugin<T> {
+    name: String,
+    version: String,
+    dependencies: Vec<Dependency>,
+    path_to_lib: PathBuf,
+    api: *const T,
+    lib: LibArc,
+    providers: Vec<LibArc>,
+}
+
+impl <T> Plugin<T>
+    where T: PluginApi {
+    pub(crate) fn new(name: String, version: String, dependencies: Vec<Dependency>, path_to_lib: PathBuf, api: *const T, lib: LibArc) -> Self {
+        Plugin {
+            name: name,
+            version: version,
+            dependencies: dependencies,
+            path_to_lib: path_to_lib,
+            api: api,
+            lib: lib,
+            providers: Vec::new(),
+        }
+    }
+
+    // Keeps the library of a dependency loaded for as long as this plugin is.
+    pub(crate) fn keep_loaded(&mut self, provider: LibArc) {
+        self.providers.push(provider);
+    }
+
+    // Returns whether this plugin keeps `lib` loaded as the library of a dependency.
+    pub(crate) fn keeps_loaded(&self, lib: &LibArc) -> bool {
+        self.providers.iter().any(|provider| ptr::eq(provider.as_ref(), lib.as_ref()))
+    }
+
+    /// Returns the name of the plugin's crate.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version of the plugin's crate.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the plugins this plugin needs.
+    pub fn dependencies(&self) -> &[Dependency] {
+        &self.dependencies
+    }
+
+    /// Returns the path the plugin was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+
+    /// Returns the plugin's API.
+    pub fn api(&self) -> &T {
+        unsafe { &*self.api }
+    }
+
+    /// Returns the library of the plugin.
+    pub fn lib(&self) -> &LibArc {
+        &self.lib
+    }
+}
+
+impl <T> Clone for Plugin<T>
+    where T: PluginApi {
+    fn clone(&self) -> Self {
+        Plugin {
+            name: self.name.clone(),
+            version: self.version.clone(),
+            dependencies: self.dependencies.clone(),
+            path_to_lib: self.path_to_lib.clone(),
+            api: self.api,
+            lib: self.lib.clone(),
+            providers: self.providers.clone(),
+        }
+    }
+}
+
+impl <T> Deref for Plugin<T>
+    where T: PluginApi {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.api()
+    }
+}
+
+// The API is an immutable static of the plugin, which is kept loaded by `lib`.
+unsafe impl <T> Send for Plugin<T>
+    where T: Sync {
+}
+
+unsafe impl <T> Sync for Plugin<T>
+    where T: Sync {
+}
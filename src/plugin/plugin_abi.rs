@@ -0,0 +1,11 @@
+/// The first field of every [PluginDescriptor](struct.PluginDescriptor.html), the bytes `SHLIBPLG` read as a little endian integer.
+pub const PLUGIN_MAGIC: u64 = 0x474c_5042_494c_4853;
+
+/// The version of the layout of [PluginDescriptor](struct.PluginDescriptor.html). Fields are only ever appended within a version, so a host accepts descriptors of its own version which are at least as large as its own.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// The name under which [declare_plugin!](../macro.declare_plugin.html) exports the [PluginDescriptor](struct.PluginDescriptor.html).
+pub const PLUGIN_DESCRIPTOR_SYMBOL: &str = "sharedlib_plugin_descriptor\0";
+
+/// A hash of the version and target of the compiler which built this crate. Rust types have no stable layout across compilers, so a plugin and its host must agree on this hash.
+pub const RUSTC_VERSION_HASH: u64 = include!(concat!(env!("OUT_DIR"), "/rustc_version_hash.rs"));
@@ -0,0 +1,26 @@
+/// The interface which a plugin exposes to its host, usually a `#[repr(C)]` struct of function pointers.
+/// A plugin exports one value of this type with [declare_plugin!](../macro.declare_plugin.html), and a host asks for the same type through [PluginLoader::load](struct.PluginLoader.html#method.load). The loader compares the name and version declared here, so a plugin is never used as an API it does not implement.
+///
+/// # Safety
+/// The name and version must change whenever the layout or meaning of the type changes, since the host trusts the plugin's value to have the layout of its own type once they match.
+///
+/// # Examples
+/// ```
+/// # use sharedlib::plugin::PluginApi;
+/// #[repr(C)]
+/// pub struct GreeterApi {
+///     pub greet: extern "C" fn() -> u32,
+/// }
+///
+/// unsafe impl PluginApi for GreeterApi {
+///     const NAME: &'static str = "greeter";
+///     const VERSION: u32 = 1;
+/// }
+/// ```
+pub unsafe trait PluginApi: 'static {
+    /// The name of the API.
+    const NAME: &'static str;
+
+    /// The version of the API.
+    const VERSION: u32;
+}
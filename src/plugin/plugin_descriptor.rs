@@ -0,0 +1,131 @@
+use error::PluginMismatch;
+use error::PluginMismatchKind;
+use plugin::Dependency;
+use plugin::DependencyDescriptor;
+use plugin::PLUGIN_ABI_VERSION;
+use plugin::PLUGIN_MAGIC;
+use plugin::PluginApi;
+use plugin::RUSTC_VERSION_HASH;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::mem;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::slice;
+
+/// The descriptor which a plugin exports to identify itself. It is created by [declare_plugin!](../macro.declare_plugin.html) and should not need to be built by hand.
+/// The first three fields keep their offsets in every ABI version, so a host can check them before it trusts the rest of the layout.
+#[repr(C)]
+#[derive(Debug)]
+pub struct PluginDescriptor {
+    /// Always [PLUGIN_MAGIC](constant.PLUGIN_MAGIC.html).
+    pub magic: u64,
+    /// The [PLUGIN_ABI_VERSION](constant.PLUGIN_ABI_VERSION.html) the plugin was built with.
+    pub abi_version: u32,
+    /// The size of the descriptor in bytes.
+    pub descriptor_size: u32,
+    /// The [RUSTC_VERSION_HASH](constant.RUSTC_VERSION_HASH.html) the plugin was built with.
+    pub rustc_version_hash: u64,
+    /// The name of the plugin's crate, as UTF-8 which is not null terminated.
+    pub name: *const u8,
+    /// The length of `name` in bytes.
+    pub name_len: usize,
+    /// The version of the plugin's crate, as UTF-8 which is not null terminated.
+    pub version: *const u8,
+    /// The length of `version` in bytes.
+    pub version_len: usize,
+    /// The [PluginApi::NAME](trait.PluginApi.html#associatedconstant.NAME) of the plugin's API, as UTF-8 which is not null terminated.
+    pub api_name: *const u8,
+    /// The length of `api_name` in bytes.
+    pub api_name_len: usize,
+    /// The [PluginApi::VERSION](trait.PluginApi.html#associatedconstant.VERSION) of the plugin's API.
+    pub api_version: u32,
+    /// A pointer to the value of the plugin's API.
+    pub api: *const c_void,
+    /// The plugins this plugin needs, which are loaded before it.
+    pub dependencies: *const DependencyDescriptor,
+    /// The number of `dependencies`.
+    pub dependencies_len: usize,
+}
+
+// The descriptor only points at immutable statics of the plugin.
+unsafe impl Sync for PluginDescriptor {
+}
+
+impl PluginDescriptor {
+    /// Checks the descriptor at `descriptor` field by field, so that no field is read before the fields in front of it have shown that it exists. Returns a [PluginMismatch](../error/struct.PluginMismatch.html) error on the first check which fails.
+    ///
+    /// # Errors
+    /// A `PluginMismatch` error will be returned as a [SharedlibError](../enum.SharedlibError.html) variant if the descriptor was not made by [declare_plugin!](../macro.declare_plugin.html) with the same ABI version, compiler, and API as the host.
+    ///
+    /// # Safety
+    /// `descriptor` must point to at least 16 readable bytes, which holds for any exported symbol that is not at the very end of its mapping.
+    pub unsafe fn check<T>(descriptor: *const PluginDescriptor, path_to_lib: &Path) -> R<&'static PluginDescriptor>
+        where T: PluginApi {
+        let mismatch = |kind| E::from(PluginMismatch::new(kind, path_to_lib.to_path_buf()));
+        let magic = (*descriptor).magic;
+        if magic != PLUGIN_MAGIC {
+            return Err(mismatch(PluginMismatchKind::BadMagic(magic)));
+        }
+        let abi_version = (*descriptor).abi_version;
+        if abi_version != PLUGIN_ABI_VERSION {
+            return Err(mismatch(PluginMismatchKind::AbiVersion { expected: PLUGIN_ABI_VERSION, actual: abi_version }));
+        }
+        let descriptor_size = (*descriptor).descriptor_size;
+        let expected_size = mem::size_of::<PluginDescriptor>() as u32;
+        if descriptor_size < expected_size {
+            return Err(mismatch(PluginMismatchKind::DescriptorSize { expected: expected_size, actual: descriptor_size }));
+        }
+
+        let descriptor = &*descriptor;
+        if descriptor.rustc_version_hash != RUSTC_VERSION_HASH {
+            return Err(mismatch(PluginMismatchKind::RustcVersion { expected: RUSTC_VERSION_HASH, actual: descriptor.rustc_version_hash }));
+        }
+        let api_name = descriptor.api_name();
+        if api_name != T::NAME || descriptor.api_version != T::VERSION {
+            let kind =
+                PluginMismatchKind::Api {
+                    expected_name: T::NAME.to_string(),
+                    expected_version: T::VERSION,
+                    actual_name: api_name,
+                    actual_version: descriptor.api_version,
+                };
+            return Err(mismatch(kind));
+        }
+        Ok(descriptor)
+    }
+
+    /// Returns the name of the plugin's crate. Invalid UTF-8 is replaced.
+    pub fn name(&self) -> String {
+        unsafe { PluginDescriptor::string(self.name, self.name_len) }
+    }
+
+    /// Returns the version of the plugin's crate. Invalid UTF-8 is replaced.
+    pub fn version(&self) -> String {
+        unsafe { PluginDescriptor::string(self.version, self.version_len) }
+    }
+
+    /// Returns the name of the plugin's API. Invalid UTF-8 is replaced.
+    pub fn api_name(&self) -> String {
+        unsafe { PluginDescriptor::string(self.api_name, self.api_name_len) }
+    }
+
+    /// Returns the plugins this plugin needs.
+    pub fn dependencies(&self) -> Vec<Dependency> {
+        if self.dependencies.is_null() {
+            Vec::new()
+        } else {
+            unsafe { slice::from_raw_parts(self.dependencies, self.dependencies_len) }.iter()
+                .map(DependencyDescriptor::to_dependency)
+                .collect()
+        }
+    }
+
+    pub(crate) unsafe fn string(ptr: *const u8, len: usize) -> String {
+        if ptr.is_null() {
+            String::new()
+        } else {
+            String::from_utf8_lossy(slice::from_raw_parts(ptr, len)).into_owned()
+        }
+    }
+}
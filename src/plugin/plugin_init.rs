@@ -0,0 +1,35 @@
+use error::PluginInit;
+use error::PluginInitKind;
+use LibArc;
+use plugin::HostApi;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::os::raw::c_int;
+use std::path::Path;
+
+/// The name of the function which a plugin may export to be initialized with the [HostApi](struct.HostApi.html). Its type is [InitPlugin](type.InitPlugin.html).
+pub const PLUGIN_INIT_SYMBOL: &str = "sharedlib_plugin_init\0";
+
+/// The code which `sharedlib_plugin_init` returns when the [HostApi](struct.HostApi.html) it was given is older than it needs.
+pub const HOST_API_UNSUPPORTED: c_int = 1;
+
+/// The type of `sharedlib_plugin_init`. It receives the host's [HostApi](struct.HostApi.html), which stays valid until the plugin is unloaded, and returns zero on success, [HOST_API_UNSUPPORTED](constant.HOST_API_UNSUPPORTED.html) if the host is too old, or any other code if it fails.
+pub type InitPlugin = unsafe extern "C" fn(host: *const HostApi) -> c_int;
+
+// Calls `sharedlib_plugin_init` of `lib` with `host`, if `lib` exports it. Errors other than the
+// symbol being missing are returned.
+pub unsafe fn init(lib: &LibArc, host: &'static HostApi, path_to_lib: &Path) -> R<()> {
+    let init =
+        match lib.as_ref().find_func::<InitPlugin, _>(PLUGIN_INIT_SYMBOL) {
+            Ok(init) => init,
+            Err(E::LibraryFindSymbol(_)) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+    let kind =
+        match init(host) {
+            0 => return Ok(()),
+            HOST_API_UNSUPPORTED => PluginInitKind::HostUnsupported(host.version),
+            code => PluginInitKind::Failed(code),
+        };
+    Err(E::from(PluginInit::new(kind, path_to_lib.to_path_buf())))
+}
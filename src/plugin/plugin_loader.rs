@@ -0,0 +1,107 @@
+use error::PluginMismatch;
+use error::PluginMismatchKind;
+use LibArc;
+use OpenOptions;
+use plugin::HostApi;
+use plugin::Plugin;
+use plugin::PluginApi;
+use plugin::PluginDescriptor;
+use plugin::PLUGIN_DESCRIPTOR_SYMBOL;
+use plugin::plugin_init;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::path::Path;
+
+/// Loads plugins, checking each plugin's descriptor before any other symbol of the plugin is used.
+///
+/// # Examples
+/// ``` no_run
+/// # use sharedlib::plugin::PluginApi;
+/// # use sharedlib::plugin::PluginLoader;
+/// # use sharedlib::SharedlibResult as R;
+/// # #[repr(C)]
+/// # pub struct GreeterApi {
+/// #     pub greet: extern "C" fn() -> u32,
+/// # }
+/// # unsafe impl PluginApi for GreeterApi {
+/// #     const NAME: &'static str = "greeter";
+/// #     const VERSION: u32 = 1;
+/// # }
+/// # fn test() -> R<()> {
+/// let loader = PluginLoader::new();
+/// let greeter = try!(unsafe { loader.load::<GreeterApi, _>("libgreeter.so") });
+/// println!("{} {} says {}", greeter.name(), greeter.version(), (greeter.greet)());
+/// # Ok(())
+/// # }
+/// ```
+pub struct PluginLoader {
+    options: OpenOptions,
+    host_api: &'static HostApi,
+}
+
+static DEFAULT_HOST_API: HostApi = HostApi::new();
+
+impl PluginLoader {
+    /// Creates a new [PluginLoader](struct.PluginLoader.html) which opens plugins the same way [LibUnsafe::new](../struct.LibUnsafe.html#method.new) does.
+    pub fn new() -> Self {
+        PluginLoader {
+            options: OpenOptions::new(),
+            host_api: &DEFAULT_HOST_API,
+        }
+    }
+
+    /// Opens plugins with `options`, so that they can be verified before they are loaded.
+    pub fn options(&mut self, options: OpenOptions) -> &mut Self {
+        self.options = options;
+        self
+    }
+
+    /// Passes `host_api` to the `sharedlib_plugin_init` of every plugin. Without it, plugins get [HostApi::new](struct.HostApi.html#method.new).
+    /// A host usually keeps its [HostApi](struct.HostApi.html) in a `static`, or leaks a `Box` of it, since plugins may keep using it until they are unloaded.
+    pub fn host_api(&mut self, host_api: &'static HostApi) -> &mut Self {
+        self.host_api = host_api;
+        self
+    }
+
+    /// Opens the plugin at the specified path, checks its descriptor against `T`, and initializes it.
+    /// Only the descriptor is read before the checks pass. If any of them fails, the library is unloaded again without any other symbol having been touched. Once they pass, the plugin's `sharedlib_plugin_init`, if it exports one, is called with the [HostApi](struct.HostApi.html). The plugin's dependencies are not loaded; a [PluginRegistry](struct.PluginRegistry.html) does that.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](../enum.SharedlibError.html) variant if there is a problem opening the shared library. A `PluginMismatch` error will be returned if the library does not export a descriptor, or if its descriptor does not match the host's ABI version, compiler, or `T`. A `PluginInit` error will be returned if `sharedlib_plugin_init` fails. Any error of the [OpenOptions](../struct.OpenOptions.html) is returned unchanged.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to load is unsafe.
+    pub unsafe fn load<T, TPath>(&self, path_to_lib: TPath) -> R<Plugin<T>>
+        where T: PluginApi,
+              TPath: AsRef<Path> {
+        let plugin = try!(self.open::<T>(path_to_lib.as_ref()));
+        try!(self.init(&plugin));
+        Ok(plugin)
+    }
+
+    // Opens the plugin and checks its descriptor without initializing it.
+    pub(crate) unsafe fn open<T>(&self, path_to_lib: &Path) -> R<Plugin<T>>
+        where T: PluginApi {
+        let lib: LibArc = try!(self.options.open(path_to_lib));
+        let descriptor =
+            try!(
+                lib.as_ref().find_data::<PluginDescriptor, _>(PLUGIN_DESCRIPTOR_SYMBOL)
+                    .map_err(|_| E::from(PluginMismatch::new(PluginMismatchKind::MissingDescriptor, path_to_lib.to_path_buf())))
+            );
+        let descriptor = try!(PluginDescriptor::check::<T>(descriptor, path_to_lib));
+        let result = Plugin::new(descriptor.name(), descriptor.version(), descriptor.dependencies(), path_to_lib.to_path_buf(), descriptor.api as *const T, lib);
+        Ok(result)
+    }
+
+    // Calls the plugin's `sharedlib_plugin_init`.
+    pub(crate) unsafe fn init<T>(&self, plugin: &Plugin<T>) -> R<()>
+        where T: PluginApi {
+        plugin_init::init(plugin.lib(), self.host_api, plugin.path())
+    }
+}
+
+impl Default for PluginLoader {
+    fn default() -> Self {
+        PluginLoader::new()
+    }
+}
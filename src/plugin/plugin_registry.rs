@@ -0,0 +1,367 @@
+use error::LibraryOpen;
+use error::PluginDependency;
+use error::PluginDependencyKind;
+use error::PluginMismatch;
+use error::PluginMismatchKind;
+use LibArc;
+use plugin::Plugin;
+use plugin::PluginApi;
+use plugin::PluginLoader;
+use plugin::RejectedPlugin;
+use plugin::RejectionReason;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use util;
+use util::Version;
+use util::VersionReq;
+
+#[cfg(any(target_os="macos", target_os="ios"))]
+const PLUGIN_EXTENSION: &str = "dylib";
+
+#[cfg(windows)]
+const PLUGIN_EXTENSION: &str = "dll";
+
+#[cfg(not(any(windows, target_os="macos", target_os="ios")))]
+const PLUGIN_EXTENSION: &str = "so";
+
+/// A set of plugins of one [PluginApi](trait.PluginApi.html), found by scanning directories and keyed by the names they declare.
+/// Candidates are the files whose extension is the platform's shared library extension, `so`, `dylib`, or `dll`, and whose file name matches at least one of the glob filters, if any were given. Every candidate is loaded on its own, so a candidate which fails to load is recorded in [rejected](#method.rejected) and the scan goes on.
+/// Plugins are initialized and registered in dependency order: a plugin is only initialized once every plugin it declares a [Dependency](struct.Dependency.html) on is registered with a version which meets the requirement. A plugin keeps the libraries of its dependencies loaded, and the registry unloads plugins in the reverse of the order it registered them.
+///
+/// # Examples
+/// ``` no_run
+/// # use sharedlib::plugin::PluginApi;
+/// # use sharedlib::plugin::PluginRegistry;
+/// # use sharedlib::SharedlibResult as R;
+/// # #[repr(C)]
+/// # pub struct GreeterApi {
+/// #     pub greet: extern "C" fn() -> u32,
+/// # }
+/// # unsafe impl PluginApi for GreeterApi {
+/// #     const NAME: &'static str = "greeter";
+/// #     const VERSION: u32 = 1;
+/// # }
+/// # fn test() -> R<()> {
+/// let mut registry = PluginRegistry::<GreeterApi>::new();
+/// registry.filter("libgreeter_*");
+/// try!(unsafe { registry.scan("plugins") });
+/// for plugin in registry.plugins() {
+///     println!("{} says {}", plugin.name(), (plugin.greet)());
+/// }
+/// for rejected in registry.rejected() {
+///     println!("rejected {}: {:?}", rejected.path().display(), rejected.reason());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PluginRegistry<T> {
+    loader: PluginLoader,
+    filters: Vec<String>,
+    plugins: BTreeMap<String, Plugin<T>>,
+    load_order: Vec<String>,
+    rejected: Vec<RejectedPlugin>,
+}
+
+impl <T> PluginRegistry<T>
+    where T: PluginApi {
+    /// Creates a new, empty [PluginRegistry](struct.PluginRegistry.html) which loads plugins with a default [PluginLoader](struct.PluginLoader.html).
+    pub fn new() -> Self {
+        PluginRegistry {
+            loader: PluginLoader::new(),
+            filters: Vec::new(),
+            plugins: BTreeMap::new(),
+            load_order: Vec::new(),
+            rejected: Vec::new(),
+        }
+    }
+
+    /// Loads plugins with `loader`.
+    pub fn loader(&mut self, loader: PluginLoader) -> &mut Self {
+        self.loader = loader;
+        self
+    }
+
+    /// Adds a glob filter for file names, in which `*` matches any run of characters and `?` matches a single character. A candidate must match at least one filter once any have been added.
+    pub fn filter<TStr>(&mut self, pattern: TStr) -> &mut Self
+        where TStr: Into<String> {
+        self.filters.push(pattern.into());
+        self
+    }
+
+    /// Loads every candidate in `dir` which is not registered yet, and registers it under the name it declares once its dependencies are registered. Subdirectories are not scanned.
+    /// The rejections of the previous scan are replaced by the rejections of this one.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](../enum.SharedlibError.html) variant if `dir` cannot be read. Errors of individual candidates are not returned but recorded in [rejected](#method.rejected), including `PluginDependency` errors for candidates whose dependencies are missing, do not meet their requirements, or depend on each other in a cycle.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to scan is unsafe.
+    pub unsafe fn scan<TPath>(&mut self, dir: TPath) -> R<()>
+        where TPath: AsRef<Path> {
+        let candidates = try!(self.candidates(dir.as_ref()));
+        self.rejected.clear();
+        let mut pending = Vec::new();
+        for path_to_lib in candidates {
+            if self.plugins.values().any(|plugin| plugin.path() == path_to_lib.as_path()) {
+                continue;
+            }
+            match self.loader.open::<T>(&path_to_lib) {
+                Ok(plugin) => pending.push(plugin),
+                Err(err) => self.rejected.push(RejectedPlugin::new(path_to_lib, RejectionReason::Error(err))),
+            }
+        }
+        self.register(pending);
+        Ok(())
+    }
+
+    // Initializes and registers the plugins of `pending` whose dependencies can be resolved,
+    // providers first, and rejects the others.
+    pub(crate) unsafe fn register(&mut self, pending: Vec<Plugin<T>>) {
+        let mut waiting: Vec<Plugin<T>> = Vec::new();
+        for plugin in pending {
+            let existing =
+                self.plugins.get(plugin.name()).map(|existing| existing.path())
+                    .or_else(|| waiting.iter().find(|waiting| waiting.name() == plugin.name()).map(|waiting| waiting.path()))
+                    .map(Path::to_path_buf);
+            match existing {
+                Some(existing) => self.rejected.push(RejectedPlugin::new(plugin.path().to_path_buf(), RejectionReason::DuplicateName(existing))),
+                None => waiting.push(plugin),
+            }
+        }
+        waiting.sort_by(|left, right| left.name().cmp(right.name()));
+
+        // Each pass registers every plugin whose providers are all registered, until a pass makes
+        // no progress. Whatever is left waits on a cycle.
+        loop {
+            let names: Vec<String> = waiting.iter().map(|plugin| plugin.name().to_string()).collect();
+            let mut progress = false;
+            let mut still_waiting = Vec::new();
+            for mut plugin in waiting {
+                match self.providers(&plugin, &names) {
+                    Ok(Some(providers)) => {
+                        progress = true;
+                        if let Err(err) = self.loader.init(&plugin) {
+                            self.rejected.push(RejectedPlugin::new(plugin.path().to_path_buf(), RejectionReason::Error(err)));
+                            continue;
+                        }
+                        for provider in providers {
+                            plugin.keep_loaded(provider);
+                        }
+                        self.load_order.push(plugin.name().to_string());
+                        self.plugins.insert(plugin.name().to_string(), plugin);
+                    },
+                    Ok(None) => still_waiting.push(plugin),
+                    Err(err) => {
+                        self.rejected.push(RejectedPlugin::new(plugin.path().to_path_buf(), RejectionReason::Error(err)));
+                        progress = true;
+                    },
+                }
+            }
+            waiting = still_waiting;
+            if !progress {
+                break;
+            }
+        }
+
+        for plugin in &waiting {
+            let kind = PluginDependencyKind::Cycle(PluginRegistry::cycle(plugin, &waiting));
+            let err = PluginDependency::new(kind, plugin.name().to_string(), plugin.path().to_path_buf());
+            self.rejected.push(RejectedPlugin::new(plugin.path().to_path_buf(), RejectionReason::Error(E::from(err))));
+        }
+    }
+
+    // Returns the libraries of the plugin's providers once they are all registered, or nothing
+    // while some of them are among `waiting`.
+    fn providers(&self, plugin: &Plugin<T>, waiting: &[String]) -> R<Option<Vec<LibArc>>> {
+        let unresolved = |kind| E::from(PluginDependency::new(kind, plugin.name().to_string(), plugin.path().to_path_buf()));
+        let mut result = Some(Vec::new());
+        for dependency in plugin.dependencies() {
+            let name = dependency.name().to_string();
+            let requirement = dependency.requirement().to_string();
+            let version_req =
+                match VersionReq::parse(&requirement) {
+                    Some(version_req) => version_req,
+                    None => return Err(unresolved(PluginDependencyKind::InvalidRequirement { name: name, requirement: requirement })),
+                };
+            match self.plugins.get(&name) {
+                Some(provider) => {
+                    let meets_requirement =
+                        match Version::parse(provider.version()) {
+                            Some(version) => version_req.matches(&version),
+                            None => false,
+                        };
+                    if !meets_requirement {
+                        let version = provider.version().to_string();
+                        return Err(unresolved(PluginDependencyKind::Unsatisfied { name: name, requirement: requirement, version: version }));
+                    }
+                    if let Some(ref mut providers) = result {
+                        providers.push(provider.lib().clone());
+                    }
+                },
+                None if waiting.contains(&name) => result = None,
+                None => return Err(unresolved(PluginDependencyKind::Missing { name: name, requirement: requirement })),
+            }
+        }
+        Ok(result)
+    }
+
+    // Follows the dependencies of `plugin` among `waiting` until a name repeats. Every plugin which
+    // is left waiting depends on another one which is, so this always ends in a cycle.
+    fn cycle(plugin: &Plugin<T>, waiting: &[Plugin<T>]) -> Vec<String> {
+        let mut result = vec![plugin.name().to_string()];
+        let mut current = plugin;
+        loop {
+            let next =
+                current.dependencies().iter()
+                    .filter_map(|dependency| waiting.iter().find(|waiting| waiting.name() == dependency.name()))
+                    .next();
+            match next {
+                Some(next) => {
+                    let repeats = result.iter().any(|name| name == next.name());
+                    result.push(next.name().to_string());
+                    if repeats {
+                        return result;
+                    }
+                    current = next;
+                },
+                None => return result,
+            }
+        }
+    }
+
+    /// Returns the names of the registered plugins, in order.
+    pub fn names<'a>(&'a self) -> btree_map::Keys<'a, String, Plugin<T>> {
+        self.plugins.keys()
+    }
+
+    /// Returns the registered plugins, in the order of their names.
+    pub fn plugins<'a>(&'a self) -> btree_map::Values<'a, String, Plugin<T>> {
+        self.plugins.values()
+    }
+
+    /// Returns the names of the registered plugins in the order they were registered, which puts every plugin after its dependencies.
+    pub fn load_order(&self) -> &[String] {
+        &self.load_order
+    }
+
+    /// Returns the plugin which declared `name`, if it is registered.
+    pub fn get(&self, name: &str) -> Option<&Plugin<T>> {
+        self.plugins.get(name)
+    }
+
+    /// Returns the candidates which the last scan rejected, and why.
+    pub fn rejected(&self) -> &[RejectedPlugin] {
+        &self.rejected
+    }
+
+    /// Removes the plugin which declared `name` from the registry and returns it. The plugin is unloaded once the returned [Plugin](struct.Plugin.html), every clone of it, and every plugin which depends on it are dropped.
+    pub fn unload(&mut self, name: &str) -> Option<Plugin<T>> {
+        self.load_order.retain(|loaded| loaded != name);
+        self.plugins.remove(name)
+    }
+
+    /// Unloads every plugin, in the reverse of the order they were registered. This also happens when the registry is dropped.
+    pub fn unload_all(&mut self) {
+        while let Some(name) = self.load_order.pop() {
+            self.plugins.remove(&name);
+        }
+    }
+
+    /// Unloads the plugin which declared `name` and loads it again from the same path, so that a new build of it is picked up. Returns false if no plugin declared `name`.
+    /// The old build must not be in use: no clone of its [Plugin](struct.Plugin.html), no symbol found in its library, and no plugin which depends on it may remain, since the loader would otherwise return the image which is still loaded, and it would be initialized a second time. The dependencies of the new build are resolved against the registered plugins.
+    ///
+    /// # Errors
+    /// A `PluginDependency` error of kind `InUse` will be returned as a [SharedlibError](../enum.SharedlibError.html) variant if the old build is still in use; the plugin then stays registered and loaded. A `LibraryOpen` error will be returned if the new build cannot be opened. A `PluginMismatch` error will be returned if the new build does not match the host or declares another name, a `PluginDependency` error if its dependencies cannot be resolved, and a `PluginInit` error if it fails to initialize. If the reload fails at any later step, the plugin is removed from the registry and is not registered again. Its old image is only closed once nothing references it; plugins which depend on it keep it loaded until they are unloaded themselves, so it may stay loaded even though the registry no longer lists it.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. Since it is impossible to guarantee that the code witin the shared library is safe, the call to reload is unsafe.
+    pub unsafe fn reload(&mut self, name: &str) -> R<bool> {
+        match self.plugins.get(name) {
+            Some(plugin) => try!(self.check_unused(plugin)),
+            None => return Ok(false),
+        }
+        let path_to_lib =
+            match self.plugins.remove(name) {
+                Some(plugin) => plugin.path().to_path_buf(),
+                None => return Ok(false),
+            };
+        let position = self.load_order.iter().position(|loaded| loaded == name).unwrap_or(self.load_order.len());
+        self.load_order.retain(|loaded| loaded != name);
+        let mut plugin = try!(self.loader.open::<T>(&path_to_lib));
+        if plugin.name() != name {
+            let kind =
+                PluginMismatchKind::Name {
+                    expected: name.to_string(),
+                    actual: plugin.name().to_string(),
+                };
+            return Err(E::from(PluginMismatch::new(kind, path_to_lib)));
+        }
+        let providers = try!(self.providers(&plugin, &[]));
+        try!(self.loader.init(&plugin));
+        for provider in providers.unwrap_or_default() {
+            plugin.keep_loaded(provider);
+        }
+        self.load_order.insert(position, name.to_string());
+        self.plugins.insert(name.to_string(), plugin);
+        Ok(true)
+    }
+
+    // Fails if anything but `plugin` itself references its library.
+    fn check_unused(&self, plugin: &Plugin<T>) -> R<()> {
+        if Arc::strong_count(plugin.lib().inner()) == 1 {
+            return Ok(());
+        }
+        let dependents =
+            self.load_order
+                .iter()
+                .filter_map(|name| self.plugins.get(name))
+                .filter(|other| other.keeps_loaded(plugin.lib()))
+                .map(|other| other.name().to_string())
+                .collect();
+        let kind = PluginDependencyKind::InUse(dependents);
+        Err(E::from(PluginDependency::new(kind, plugin.name().to_string(), plugin.path().to_path_buf())))
+    }
+
+    // Lists the files in `dir` which look like plugins, in order.
+    fn candidates(&self, dir: &Path) -> R<Vec<PathBuf>> {
+        let to_open_error = |err| E::from(LibraryOpen::new(Box::new(err), dir.to_path_buf()));
+        let entries = try!(fs::read_dir(dir).map_err(&to_open_error));
+        let mut result = Vec::new();
+        for entry in entries {
+            let entry = try!(entry.map_err(&to_open_error));
+            let path = entry.path();
+            if !path.is_file() || path.extension() != Some(OsStr::new(PLUGIN_EXTENSION)) {
+                continue;
+            }
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            if self.filters.is_empty() || self.filters.iter().any(|filter| util::glob_match(filter, &file_name)) {
+                result.push(path);
+            }
+        }
+        result.sort();
+        Ok(result)
+    }
+}
+
+impl <T> Default for PluginRegistry<T>
+    where T: PluginApi {
+    fn default() -> Self {
+        PluginRegistry::new()
+    }
+}
+
+impl <T> Drop for PluginRegistry<T> {
+    fn drop(&mut self) {
+        while let Some(name) = self.load_order.pop() {
+            self.plugins.remove(&name);
+        }
+    }
+}
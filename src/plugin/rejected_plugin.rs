@@ -0,0 +1,30 @@
+use plugin::RejectionReason;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// A candidate file which a [PluginRegistry](struct.PluginRegistry.html) did not register.
+#[derive(Debug)]
+pub struct RejectedPlugin {
+    path_to_lib: PathBuf,
+    reason: RejectionReason,
+}
+
+impl RejectedPlugin {
+    /// Creates a new [RejectedPlugin](struct.RejectedPlugin.html).
+    pub fn new(path_to_lib: PathBuf, reason: RejectionReason) -> Self {
+        RejectedPlugin {
+            path_to_lib: path_to_lib,
+            reason: reason,
+        }
+    }
+
+    /// Returns the path of the rejected file.
+    pub fn path(&self) -> &Path {
+        &self.path_to_lib
+    }
+
+    /// Returns why the file was rejected.
+    pub fn reason(&self) -> &RejectionReason {
+        &self.reason
+    }
+}
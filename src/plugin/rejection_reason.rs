@@ -0,0 +1,11 @@
+use SharedlibError;
+use std::path::PathBuf;
+
+/// Why a [PluginRegistry](struct.PluginRegistry.html) rejected a candidate file.
+#[derive(Debug)]
+pub enum RejectionReason {
+    /// Loading the file failed with the contained error. For instance, the file was not a plugin, or its descriptor did not match the host.
+    Error(SharedlibError),
+    /// The file declares the same name as a plugin which is already registered, from the contained path.
+    DuplicateName(PathBuf),
+}
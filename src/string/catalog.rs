@@ -0,0 +1,27 @@
+use string::EnglishCatalog;
+use string::MessageCatalog;
+use string::MessageId;
+use std::sync::RwLock;
+
+lazy_static! {
+    static ref CATALOG: RwLock<&'static (MessageCatalog + Sync)> = RwLock::new(&EnglishCatalog);
+}
+
+/// Installs the [MessageCatalog](trait.MessageCatalog.html) which errors are displayed through from now on, in every thread.
+/// Errors are displayed through the catalog which is installed when they are displayed. Installing [EnglishCatalog](struct.EnglishCatalog.html) restores the default.
+pub fn set_message_catalog(catalog: &'static (MessageCatalog + Sync)) {
+    match CATALOG.write() {
+        Ok(mut current) => *current = catalog,
+        Err(err) => *err.into_inner() = catalog,
+    }
+}
+
+// Looks a message up in the installed catalog, falling back to its English text.
+pub fn message(id: MessageId) -> &'static str {
+    let catalog =
+        match CATALOG.read() {
+            Ok(catalog) => *catalog,
+            Err(err) => *err.into_inner(),
+        };
+    catalog.message(id).unwrap_or_else(|| id.english())
+}
@@ -0,0 +1,12 @@
+use string::MessageCatalog;
+use string::MessageId;
+
+/// The [MessageCatalog](trait.MessageCatalog.html) which is installed unless another one is, and which shows every message in English.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnglishCatalog;
+
+impl MessageCatalog for EnglishCatalog {
+    fn message(&self, id: MessageId) -> Option<&str> {
+        Some(id.english())
+    }
+}
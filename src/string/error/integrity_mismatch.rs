@@ -0,0 +1,15 @@
+pub fn description() -> &'static str {
+    "A shared library did not match its expected digest."
+}
+
+pub fn display_1() -> &'static str {
+    "The shared library at path, '"
+}
+
+pub fn display_2() -> &'static str {
+    "', failed integrity verification. Expected digest: "
+}
+
+pub fn display_3() -> &'static str {
+    ", actual digest: "
+}
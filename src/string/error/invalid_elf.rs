@@ -0,0 +1,23 @@
+pub fn description() -> &'static str {
+    "A file is not a valid ELF object."
+}
+
+pub fn display_1() -> &'static str {
+    "The file is not a valid ELF object. Cause: \n"
+}
+
+pub fn bad_magic() -> &'static str {
+    "The file does not start with the ELF magic number."
+}
+
+pub fn truncated() -> &'static str {
+    "The file ends before a structure it describes."
+}
+
+pub fn unsupported_class() -> &'static str {
+    "The file is neither a 32-bit nor a 64-bit ELF object."
+}
+
+pub fn unsupported_encoding() -> &'static str {
+    "The file is neither little-endian nor big-endian."
+}
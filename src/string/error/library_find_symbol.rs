@@ -9,3 +9,15 @@ pub fn display_1() -> &'static str {
 pub fn display_2() -> &'static str {
     "', from a shared library failed. Cause: \n"
 }
+
+pub fn suggestions_1() -> &'static str {
+    "\ndid you mean '"
+}
+
+pub fn suggestions_separator() -> &'static str {
+    "', '"
+}
+
+pub fn suggestions_2() -> &'static str {
+    "'?"
+}
@@ -0,0 +1,7 @@
+pub fn description() -> &'static str {
+    "A shared library was used after it was unloaded."
+}
+
+pub fn display_1() -> &'static str {
+    "The shared library is being unloaded or has been unloaded, so its symbols can no longer be used."
+}
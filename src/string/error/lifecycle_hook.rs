@@ -0,0 +1,31 @@
+pub fn description() -> &'static str {
+    "A lifecycle hook of a shared library failed."
+}
+
+pub fn display_1() -> &'static str {
+    "The "
+}
+
+pub fn display_2() -> &'static str {
+    " hook, '"
+}
+
+pub fn display_3() -> &'static str {
+    "', of the shared library at path, '"
+}
+
+pub fn display_4() -> &'static str {
+    "', returned "
+}
+
+pub fn display_5() -> &'static str {
+    "."
+}
+
+pub fn load() -> &'static str {
+    "load"
+}
+
+pub fn unload() -> &'static str {
+    "unload"
+}
@@ -0,0 +1,15 @@
+pub fn description() -> &'static str {
+    "A shared library is missing requested symbols."
+}
+
+pub fn display_1() -> &'static str {
+    "The shared library does not contain the symbols, '"
+}
+
+pub fn separator() -> &'static str {
+    "', '"
+}
+
+pub fn display_2() -> &'static str {
+    "'. Causes:"
+}
@@ -1,9 +1,31 @@
+pub mod integrity_mismatch;
+
+pub mod invalid_elf;
+
 pub mod library_close;
 
 pub mod library_find_symbol;
 
 pub mod library_open;
 
+pub mod library_unloaded;
+
+pub mod lifecycle_hook;
+
+pub mod missing_symbols;
+
 pub mod os_error;
 
 pub mod os_error_failure;
+
+pub mod plugin_dependency;
+
+pub mod plugin_init;
+
+pub mod plugin_mismatch;
+
+pub mod policy_violation;
+
+pub mod state_handoff;
+
+pub mod unload_timeout;
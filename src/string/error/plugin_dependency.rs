@@ -0,0 +1,75 @@
+pub fn description() -> &'static str {
+    "The dependencies of a plugin cannot be resolved."
+}
+
+pub fn display_1() -> &'static str {
+    "The dependencies of the plugin, '"
+}
+
+pub fn display_2() -> &'static str {
+    "', at path, '"
+}
+
+pub fn display_3() -> &'static str {
+    "', cannot be resolved. Cause: \n"
+}
+
+pub fn invalid_requirement_1() -> &'static str {
+    "The version requirement, '"
+}
+
+pub fn invalid_requirement_2() -> &'static str {
+    "', of the dependency, '"
+}
+
+pub fn invalid_requirement_3() -> &'static str {
+    "', is not valid."
+}
+
+pub fn missing_1() -> &'static str {
+    "No plugin named, '"
+}
+
+pub fn missing_2() -> &'static str {
+    "', is loaded to meet the requirement, '"
+}
+
+pub fn missing_3() -> &'static str {
+    "'."
+}
+
+pub fn unsatisfied_1() -> &'static str {
+    "The loaded plugin, '"
+}
+
+pub fn unsatisfied_2() -> &'static str {
+    "', has version, '"
+}
+
+pub fn unsatisfied_3() -> &'static str {
+    "', which does not meet the requirement, '"
+}
+
+pub fn unsatisfied_4() -> &'static str {
+    "'."
+}
+
+pub fn cycle_1() -> &'static str {
+    "The plugin depends on itself: "
+}
+
+pub fn cycle_2() -> &'static str {
+    "."
+}
+
+pub fn in_use_1() -> &'static str {
+    "The plugin cannot be reloaded while its library is still in use"
+}
+
+pub fn in_use_2() -> &'static str {
+    ", for instance by the plugins which depend on it: "
+}
+
+pub fn in_use_3() -> &'static str {
+    "."
+}
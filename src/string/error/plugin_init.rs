@@ -0,0 +1,27 @@
+pub fn description() -> &'static str {
+    "A plugin failed to initialize."
+}
+
+pub fn display_1() -> &'static str {
+    "The plugin at path, '"
+}
+
+pub fn display_2() -> &'static str {
+    "', failed to initialize. Cause: \n"
+}
+
+pub fn host_unsupported_1() -> &'static str {
+    "The plugin needs a newer host API than version "
+}
+
+pub fn host_unsupported_2() -> &'static str {
+    "."
+}
+
+pub fn failed_1() -> &'static str {
+    "sharedlib_plugin_init returned "
+}
+
+pub fn failed_2() -> &'static str {
+    "."
+}
@@ -0,0 +1,83 @@
+pub fn description() -> &'static str {
+    "A shared library is not a compatible plugin."
+}
+
+pub fn display_1() -> &'static str {
+    "The shared library at path, '"
+}
+
+pub fn display_2() -> &'static str {
+    "', is not a compatible plugin. Cause: \n"
+}
+
+pub fn missing_descriptor() -> &'static str {
+    "The library does not export a plugin descriptor."
+}
+
+pub fn bad_magic_1() -> &'static str {
+    "The plugin descriptor starts with "
+}
+
+pub fn bad_magic_2() -> &'static str {
+    " instead of the plugin magic number."
+}
+
+pub fn abi_version_1() -> &'static str {
+    "The plugin uses ABI version "
+}
+
+pub fn abi_version_2() -> &'static str {
+    " but the host uses ABI version "
+}
+
+pub fn abi_version_3() -> &'static str {
+    "."
+}
+
+pub fn descriptor_size_1() -> &'static str {
+    "The plugin descriptor is "
+}
+
+pub fn descriptor_size_2() -> &'static str {
+    " bytes long but the host needs at least "
+}
+
+pub fn descriptor_size_3() -> &'static str {
+    " bytes."
+}
+
+pub fn rustc_version_1() -> &'static str {
+    "The plugin was built by a compiler with version hash "
+}
+
+pub fn rustc_version_2() -> &'static str {
+    " but the host was built by a compiler with version hash "
+}
+
+pub fn rustc_version_3() -> &'static str {
+    "."
+}
+
+pub fn api_1() -> &'static str {
+    "The plugin implements "
+}
+
+pub fn api_2() -> &'static str {
+    " but the host asked for "
+}
+
+pub fn api_3() -> &'static str {
+    "."
+}
+
+pub fn name_1() -> &'static str {
+    "The new build of the plugin declares the name, '"
+}
+
+pub fn name_2() -> &'static str {
+    "', instead of, '"
+}
+
+pub fn name_3() -> &'static str {
+    "'."
+}
@@ -0,0 +1,43 @@
+pub fn description() -> &'static str {
+    "A shared library violated the load policy."
+}
+
+pub fn display_1() -> &'static str {
+    "The path, '"
+}
+
+pub fn display_2() -> &'static str {
+    "', violates the load policy. Cause: \n"
+}
+
+pub fn insufficient_hardening_1() -> &'static str {
+    "The library's hardening level is "
+}
+
+pub fn insufficient_hardening_2() -> &'static str {
+    ", which is below the required level, "
+}
+
+pub fn insufficient_hardening_3() -> &'static str {
+    "."
+}
+
+pub fn relative_path() -> &'static str {
+    "The path is relative."
+}
+
+pub fn symlink() -> &'static str {
+    "The path is a symbolic link."
+}
+
+pub fn untrusted_owner_1() -> &'static str {
+    "The path is owned by user "
+}
+
+pub fn untrusted_owner_2() -> &'static str {
+    ", which is neither root nor the current user."
+}
+
+pub fn writable_by_others() -> &'static str {
+    "The path is writable by users other than its owner."
+}
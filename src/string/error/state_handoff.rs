@@ -0,0 +1,35 @@
+pub fn description() -> &'static str {
+    "The state of a shared library could not be handed to its new build."
+}
+
+pub fn display_1() -> &'static str {
+    "The reload of the shared library at path, '"
+}
+
+pub fn display_2() -> &'static str {
+    "', was rolled back because its state could not be handed over. Cause: \n"
+}
+
+pub fn save_failed_1() -> &'static str {
+    "sharedlib_save_state returned "
+}
+
+pub fn save_failed_2() -> &'static str {
+    "."
+}
+
+pub fn version_mismatch_1() -> &'static str {
+    "sharedlib_restore_state does not support state version "
+}
+
+pub fn version_mismatch_2() -> &'static str {
+    "."
+}
+
+pub fn restore_failed_1() -> &'static str {
+    "sharedlib_restore_state returned "
+}
+
+pub fn restore_failed_2() -> &'static str {
+    "."
+}
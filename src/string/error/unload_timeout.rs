@@ -0,0 +1,15 @@
+pub fn description() -> &'static str {
+    "A shared library was not unloaded because calls into it did not return in time."
+}
+
+pub fn display_1() -> &'static str {
+    "A shared library was not unloaded because "
+}
+
+pub fn display_2() -> &'static str {
+    " call(s) into it were still running after "
+}
+
+pub fn display_3() -> &'static str {
+    ". The library is still loaded."
+}
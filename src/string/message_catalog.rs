@@ -0,0 +1,28 @@
+use string::MessageId;
+
+/// A source of the text which [sharedlib](index.html) shows to users, most of all in the `Display` implementations of its errors.
+/// A catalog is installed with [set_message_catalog](fn.set_message_catalog.html). It only needs to translate the messages it knows; any other message falls back to its English text.
+///
+/// # Examples
+/// ```
+/// # use sharedlib::MessageCatalog;
+/// # use sharedlib::MessageId;
+/// struct German;
+///
+/// impl MessageCatalog for German {
+///     fn message(&self, id: MessageId) -> Option<&str> {
+///         match id {
+///             MessageId::LibraryOpenDisplay1 => Some("Die Bibliothek unter '"),
+///             MessageId::LibraryOpenDisplay2 => Some("' konnte nicht geöffnet werden. Ursache: \n"),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// sharedlib::set_message_catalog(&German);
+/// # sharedlib::set_message_catalog(&sharedlib::EnglishCatalog);
+/// ```
+pub trait MessageCatalog {
+    /// Returns the text of a message, or `None` to show its English text.
+    fn message(&self, id: MessageId) -> Option<&str>;
+}
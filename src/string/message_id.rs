@@ -0,0 +1,587 @@
+use string::error::*;
+
+/// Identifies a piece of text which [sharedlib](index.html) shows to users, for looking it up in a [MessageCatalog](trait.MessageCatalog.html).
+/// Most errors are displayed as several pieces of text with values, like a path, in between, so each piece is translated on its own. The documentation of each variant shows its English text.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MessageId {
+    /// `"A shared library did not match its expected digest."`
+    IntegrityMismatchDescription,
+    /// `"The shared library at path, '"`
+    IntegrityMismatchDisplay1,
+    /// `"', failed integrity verification. Expected digest: "`
+    IntegrityMismatchDisplay2,
+    /// `", actual digest: "`
+    IntegrityMismatchDisplay3,
+    /// `"A file is not a valid ELF object."`
+    InvalidElfDescription,
+    /// `"The file is not a valid ELF object. Cause: \n"`
+    InvalidElfDisplay1,
+    /// `"The file does not start with the ELF magic number."`
+    InvalidElfBadMagic,
+    /// `"The file ends before a structure it describes."`
+    InvalidElfTruncated,
+    /// `"The file is neither a 32-bit nor a 64-bit ELF object."`
+    InvalidElfUnsupportedClass,
+    /// `"The file is neither little-endian nor big-endian."`
+    InvalidElfUnsupportedEncoding,
+    /// `"A shared library failed to close."`
+    LibraryCloseDescription,
+    /// `"A shared library failed to close. Cause: \n"`
+    LibraryCloseDisplay1,
+    /// `"Failed to find a symbol in a shared library."`
+    LibraryFindSymbolDescription,
+    /// `"The search for symbol, '"`
+    LibraryFindSymbolDisplay1,
+    /// `"', from a shared library failed. Cause: \n"`
+    LibraryFindSymbolDisplay2,
+    /// `"\ndid you mean '"`
+    LibraryFindSymbolSuggestions1,
+    /// `"', '"`
+    LibraryFindSymbolSuggestionsSeparator,
+    /// `"'?"`
+    LibraryFindSymbolSuggestions2,
+    /// `"A shared library failed to open."`
+    LibraryOpenDescription,
+    /// `"The shared library at path, '"`
+    LibraryOpenDisplay1,
+    /// `"', failed to open. Cause: \n"`
+    LibraryOpenDisplay2,
+    /// `"A shared library was used after it was unloaded."`
+    LibraryUnloadedDescription,
+    /// `"The shared library is being unloaded or has been unloaded, so its symbols can no longer be used."`
+    LibraryUnloadedDisplay1,
+    /// `"A lifecycle hook of a shared library failed."`
+    LifecycleHookDescription,
+    /// `"The "`
+    LifecycleHookDisplay1,
+    /// `" hook, '"`
+    LifecycleHookDisplay2,
+    /// `"', of the shared library at path, '"`
+    LifecycleHookDisplay3,
+    /// `"', returned "`
+    LifecycleHookDisplay4,
+    /// `"."`
+    LifecycleHookDisplay5,
+    /// `"load"`
+    LifecycleHookLoad,
+    /// `"unload"`
+    LifecycleHookUnload,
+    /// `"A shared library is missing requested symbols."`
+    MissingSymbolsDescription,
+    /// `"The shared library does not contain the symbols, '"`
+    MissingSymbolsDisplay1,
+    /// `"', '"`
+    MissingSymbolsSeparator,
+    /// `"'. Causes:"`
+    MissingSymbolsDisplay2,
+    /// `"A call to a native function failed."`
+    OsErrorDescription,
+    /// `"A call to the native function, '"`
+    OsErrorDisplay1,
+    /// `"', failed. Cause: \n"`
+    OsErrorDisplay2,
+    /// `"A call to a native function failed but the operating system reported success."`
+    OsErrorFailureDescription,
+    /// `"A call to the native function, '"`
+    OsErrorFailureDisplay1,
+    /// `"', failed but the operating system reported success."`
+    OsErrorFailureDisplay2,
+    /// `"The dependencies of a plugin cannot be resolved."`
+    PluginDependencyDescription,
+    /// `"The dependencies of the plugin, '"`
+    PluginDependencyDisplay1,
+    /// `"', at path, '"`
+    PluginDependencyDisplay2,
+    /// `"', cannot be resolved. Cause: \n"`
+    PluginDependencyDisplay3,
+    /// `"The version requirement, '"`
+    PluginDependencyInvalidRequirement1,
+    /// `"', of the dependency, '"`
+    PluginDependencyInvalidRequirement2,
+    /// `"', is not valid."`
+    PluginDependencyInvalidRequirement3,
+    /// `"No plugin named, '"`
+    PluginDependencyMissing1,
+    /// `"', is loaded to meet the requirement, '"`
+    PluginDependencyMissing2,
+    /// `"'."`
+    PluginDependencyMissing3,
+    /// `"The loaded plugin, '"`
+    PluginDependencyUnsatisfied1,
+    /// `"', has version, '"`
+    PluginDependencyUnsatisfied2,
+    /// `"', which does not meet the requirement, '"`
+    PluginDependencyUnsatisfied3,
+    /// `"'."`
+    PluginDependencyUnsatisfied4,
+    /// `"The plugin depends on itself: "`
+    PluginDependencyCycle1,
+    /// `"."`
+    PluginDependencyCycle2,
+    /// `"The plugin cannot be reloaded while its library is still in use"`
+    PluginDependencyInUse1,
+    /// `", for instance by the plugins which depend on it: "`
+    PluginDependencyInUse2,
+    /// `"."`
+    PluginDependencyInUse3,
+    /// `"A plugin failed to initialize."`
+    PluginInitDescription,
+    /// `"The plugin at path, '"`
+    PluginInitDisplay1,
+    /// `"', failed to initialize. Cause: \n"`
+    PluginInitDisplay2,
+    /// `"The plugin needs a newer host API than version "`
+    PluginInitHostUnsupported1,
+    /// `"."`
+    PluginInitHostUnsupported2,
+    /// `"sharedlib_plugin_init returned "`
+    PluginInitFailed1,
+    /// `"."`
+    PluginInitFailed2,
+    /// `"A shared library is not a compatible plugin."`
+    PluginMismatchDescription,
+    /// `"The shared library at path, '"`
+    PluginMismatchDisplay1,
+    /// `"', is not a compatible plugin. Cause: \n"`
+    PluginMismatchDisplay2,
+    /// `"The library does not export a plugin descriptor."`
+    PluginMismatchMissingDescriptor,
+    /// `"The plugin descriptor starts with "`
+    PluginMismatchBadMagic1,
+    /// `" instead of the plugin magic number."`
+    PluginMismatchBadMagic2,
+    /// `"The plugin uses ABI version "`
+    PluginMismatchAbiVersion1,
+    /// `" but the host uses ABI version "`
+    PluginMismatchAbiVersion2,
+    /// `"."`
+    PluginMismatchAbiVersion3,
+    /// `"The plugin descriptor is "`
+    PluginMismatchDescriptorSize1,
+    /// `" bytes long but the host needs at least "`
+    PluginMismatchDescriptorSize2,
+    /// `" bytes."`
+    PluginMismatchDescriptorSize3,
+    /// `"The plugin was built by a compiler with version hash "`
+    PluginMismatchRustcVersion1,
+    /// `" but the host was built by a compiler with version hash "`
+    PluginMismatchRustcVersion2,
+    /// `"."`
+    PluginMismatchRustcVersion3,
+    /// `"The plugin implements "`
+    PluginMismatchApi1,
+    /// `" but the host asked for "`
+    PluginMismatchApi2,
+    /// `"."`
+    PluginMismatchApi3,
+    /// `"The new build of the plugin declares the name, '"`
+    PluginMismatchName1,
+    /// `"', instead of, '"`
+    PluginMismatchName2,
+    /// `"'."`
+    PluginMismatchName3,
+    /// `"A shared library violated the load policy."`
+    PolicyViolationDescription,
+    /// `"The path, '"`
+    PolicyViolationDisplay1,
+    /// `"', violates the load policy. Cause: \n"`
+    PolicyViolationDisplay2,
+    /// `"The library's hardening level is "`
+    PolicyViolationInsufficientHardening1,
+    /// `", which is below the required level, "`
+    PolicyViolationInsufficientHardening2,
+    /// `"."`
+    PolicyViolationInsufficientHardening3,
+    /// `"The path is relative."`
+    PolicyViolationRelativePath,
+    /// `"The path is a symbolic link."`
+    PolicyViolationSymlink,
+    /// `"The path is owned by user "`
+    PolicyViolationUntrustedOwner1,
+    /// `", which is neither root nor the current user."`
+    PolicyViolationUntrustedOwner2,
+    /// `"The path is writable by users other than its owner."`
+    PolicyViolationWritableByOthers,
+    /// `"The state of a shared library could not be handed to its new build."`
+    StateHandoffDescription,
+    /// `"The reload of the shared library at path, '"`
+    StateHandoffDisplay1,
+    /// `"', was rolled back because its state could not be handed over. Cause: \n"`
+    StateHandoffDisplay2,
+    /// `"sharedlib_save_state returned "`
+    StateHandoffSaveFailed1,
+    /// `"."`
+    StateHandoffSaveFailed2,
+    /// `"sharedlib_restore_state does not support state version "`
+    StateHandoffVersionMismatch1,
+    /// `"."`
+    StateHandoffVersionMismatch2,
+    /// `"sharedlib_restore_state returned "`
+    StateHandoffRestoreFailed1,
+    /// `"."`
+    StateHandoffRestoreFailed2,
+    /// `"A shared library was not unloaded because calls into it did not return in time."`
+    UnloadTimeoutDescription,
+    /// `"A shared library was not unloaded because "`
+    UnloadTimeoutDisplay1,
+    /// `" call(s) into it were still running after "`
+    UnloadTimeoutDisplay2,
+    /// `". The library is still loaded."`
+    UnloadTimeoutDisplay3,
+}
+
+impl MessageId {
+    /// Returns every [MessageId](enum.MessageId.html), for instance to check that a catalog translates all of them.
+    pub fn all() -> &'static [MessageId] {
+        &[
+            MessageId::IntegrityMismatchDescription,
+            MessageId::IntegrityMismatchDisplay1,
+            MessageId::IntegrityMismatchDisplay2,
+            MessageId::IntegrityMismatchDisplay3,
+            MessageId::InvalidElfDescription,
+            MessageId::InvalidElfDisplay1,
+            MessageId::InvalidElfBadMagic,
+            MessageId::InvalidElfTruncated,
+            MessageId::InvalidElfUnsupportedClass,
+            MessageId::InvalidElfUnsupportedEncoding,
+            MessageId::LibraryCloseDescription,
+            MessageId::LibraryCloseDisplay1,
+            MessageId::LibraryFindSymbolDescription,
+            MessageId::LibraryFindSymbolDisplay1,
+            MessageId::LibraryFindSymbolDisplay2,
+            MessageId::LibraryFindSymbolSuggestions1,
+            MessageId::LibraryFindSymbolSuggestionsSeparator,
+            MessageId::LibraryFindSymbolSuggestions2,
+            MessageId::LibraryOpenDescription,
+            MessageId::LibraryOpenDisplay1,
+            MessageId::LibraryOpenDisplay2,
+            MessageId::LibraryUnloadedDescription,
+            MessageId::LibraryUnloadedDisplay1,
+            MessageId::LifecycleHookDescription,
+            MessageId::LifecycleHookDisplay1,
+            MessageId::LifecycleHookDisplay2,
+            MessageId::LifecycleHookDisplay3,
+            MessageId::LifecycleHookDisplay4,
+            MessageId::LifecycleHookDisplay5,
+            MessageId::LifecycleHookLoad,
+            MessageId::LifecycleHookUnload,
+            MessageId::MissingSymbolsDescription,
+            MessageId::MissingSymbolsDisplay1,
+            MessageId::MissingSymbolsSeparator,
+            MessageId::MissingSymbolsDisplay2,
+            MessageId::OsErrorDescription,
+            MessageId::OsErrorDisplay1,
+            MessageId::OsErrorDisplay2,
+            MessageId::OsErrorFailureDescription,
+            MessageId::OsErrorFailureDisplay1,
+            MessageId::OsErrorFailureDisplay2,
+            MessageId::PluginDependencyDescription,
+            MessageId::PluginDependencyDisplay1,
+            MessageId::PluginDependencyDisplay2,
+            MessageId::PluginDependencyDisplay3,
+            MessageId::PluginDependencyInvalidRequirement1,
+            MessageId::PluginDependencyInvalidRequirement2,
+            MessageId::PluginDependencyInvalidRequirement3,
+            MessageId::PluginDependencyMissing1,
+            MessageId::PluginDependencyMissing2,
+            MessageId::PluginDependencyMissing3,
+            MessageId::PluginDependencyUnsatisfied1,
+            MessageId::PluginDependencyUnsatisfied2,
+            MessageId::PluginDependencyUnsatisfied3,
+            MessageId::PluginDependencyUnsatisfied4,
+            MessageId::PluginDependencyCycle1,
+            MessageId::PluginDependencyCycle2,
+            MessageId::PluginDependencyInUse1,
+            MessageId::PluginDependencyInUse2,
+            MessageId::PluginDependencyInUse3,
+            MessageId::PluginInitDescription,
+            MessageId::PluginInitDisplay1,
+            MessageId::PluginInitDisplay2,
+            MessageId::PluginInitHostUnsupported1,
+            MessageId::PluginInitHostUnsupported2,
+            MessageId::PluginInitFailed1,
+            MessageId::PluginInitFailed2,
+            MessageId::PluginMismatchDescription,
+            MessageId::PluginMismatchDisplay1,
+            MessageId::PluginMismatchDisplay2,
+            MessageId::PluginMismatchMissingDescriptor,
+            MessageId::PluginMismatchBadMagic1,
+            MessageId::PluginMismatchBadMagic2,
+            MessageId::PluginMismatchAbiVersion1,
+            MessageId::PluginMismatchAbiVersion2,
+            MessageId::PluginMismatchAbiVersion3,
+            MessageId::PluginMismatchDescriptorSize1,
+            MessageId::PluginMismatchDescriptorSize2,
+            MessageId::PluginMismatchDescriptorSize3,
+            MessageId::PluginMismatchRustcVersion1,
+            MessageId::PluginMismatchRustcVersion2,
+            MessageId::PluginMismatchRustcVersion3,
+            MessageId::PluginMismatchApi1,
+            MessageId::PluginMismatchApi2,
+            MessageId::PluginMismatchApi3,
+            MessageId::PluginMismatchName1,
+            MessageId::PluginMismatchName2,
+            MessageId::PluginMismatchName3,
+            MessageId::PolicyViolationDescription,
+            MessageId::PolicyViolationDisplay1,
+            MessageId::PolicyViolationDisplay2,
+            MessageId::PolicyViolationInsufficientHardening1,
+            MessageId::PolicyViolationInsufficientHardening2,
+            MessageId::PolicyViolationInsufficientHardening3,
+            MessageId::PolicyViolationRelativePath,
+            MessageId::PolicyViolationSymlink,
+            MessageId::PolicyViolationUntrustedOwner1,
+            MessageId::PolicyViolationUntrustedOwner2,
+            MessageId::PolicyViolationWritableByOthers,
+            MessageId::StateHandoffDescription,
+            MessageId::StateHandoffDisplay1,
+            MessageId::StateHandoffDisplay2,
+            MessageId::StateHandoffSaveFailed1,
+            MessageId::StateHandoffSaveFailed2,
+            MessageId::StateHandoffVersionMismatch1,
+            MessageId::StateHandoffVersionMismatch2,
+            MessageId::StateHandoffRestoreFailed1,
+            MessageId::StateHandoffRestoreFailed2,
+            MessageId::UnloadTimeoutDescription,
+            MessageId::UnloadTimeoutDisplay1,
+            MessageId::UnloadTimeoutDisplay2,
+            MessageId::UnloadTimeoutDisplay3,
+        ]
+    }
+
+    /// Returns a stable name for the message, like `library_open.display_1`, which can be used as the key of a translation file.
+    pub fn key(&self) -> &'static str {
+        match *self {
+            MessageId::IntegrityMismatchDescription => "integrity_mismatch.description",
+            MessageId::IntegrityMismatchDisplay1 => "integrity_mismatch.display_1",
+            MessageId::IntegrityMismatchDisplay2 => "integrity_mismatch.display_2",
+            MessageId::IntegrityMismatchDisplay3 => "integrity_mismatch.display_3",
+            MessageId::InvalidElfDescription => "invalid_elf.description",
+            MessageId::InvalidElfDisplay1 => "invalid_elf.display_1",
+            MessageId::InvalidElfBadMagic => "invalid_elf.bad_magic",
+            MessageId::InvalidElfTruncated => "invalid_elf.truncated",
+            MessageId::InvalidElfUnsupportedClass => "invalid_elf.unsupported_class",
+            MessageId::InvalidElfUnsupportedEncoding => "invalid_elf.unsupported_encoding",
+            MessageId::LibraryCloseDescription => "library_close.description",
+            MessageId::LibraryCloseDisplay1 => "library_close.display_1",
+            MessageId::LibraryFindSymbolDescription => "library_find_symbol.description",
+            MessageId::LibraryFindSymbolDisplay1 => "library_find_symbol.display_1",
+            MessageId::LibraryFindSymbolDisplay2 => "library_find_symbol.display_2",
+            MessageId::LibraryFindSymbolSuggestions1 => "library_find_symbol.suggestions_1",
+            MessageId::LibraryFindSymbolSuggestionsSeparator => "library_find_symbol.suggestions_separator",
+            MessageId::LibraryFindSymbolSuggestions2 => "library_find_symbol.suggestions_2",
+            MessageId::LibraryOpenDescription => "library_open.description",
+            MessageId::LibraryOpenDisplay1 => "library_open.display_1",
+            MessageId::LibraryOpenDisplay2 => "library_open.display_2",
+            MessageId::LibraryUnloadedDescription => "library_unloaded.description",
+            MessageId::LibraryUnloadedDisplay1 => "library_unloaded.display_1",
+            MessageId::LifecycleHookDescription => "lifecycle_hook.description",
+            MessageId::LifecycleHookDisplay1 => "lifecycle_hook.display_1",
+            MessageId::LifecycleHookDisplay2 => "lifecycle_hook.display_2",
+            MessageId::LifecycleHookDisplay3 => "lifecycle_hook.display_3",
+            MessageId::LifecycleHookDisplay4 => "lifecycle_hook.display_4",
+            MessageId::LifecycleHookDisplay5 => "lifecycle_hook.display_5",
+            MessageId::LifecycleHookLoad => "lifecycle_hook.load",
+            MessageId::LifecycleHookUnload => "lifecycle_hook.unload",
+            MessageId::MissingSymbolsDescription => "missing_symbols.description",
+            MessageId::MissingSymbolsDisplay1 => "missing_symbols.display_1",
+            MessageId::MissingSymbolsSeparator => "missing_symbols.separator",
+            MessageId::MissingSymbolsDisplay2 => "missing_symbols.display_2",
+            MessageId::OsErrorDescription => "os_error.description",
+            MessageId::OsErrorDisplay1 => "os_error.display_1",
+            MessageId::OsErrorDisplay2 => "os_error.display_2",
+            MessageId::OsErrorFailureDescription => "os_error_failure.description",
+            MessageId::OsErrorFailureDisplay1 => "os_error_failure.display_1",
+            MessageId::OsErrorFailureDisplay2 => "os_error_failure.display_2",
+            MessageId::PluginDependencyDescription => "plugin_dependency.description",
+            MessageId::PluginDependencyDisplay1 => "plugin_dependency.display_1",
+            MessageId::PluginDependencyDisplay2 => "plugin_dependency.display_2",
+            MessageId::PluginDependencyDisplay3 => "plugin_dependency.display_3",
+            MessageId::PluginDependencyInvalidRequirement1 => "plugin_dependency.invalid_requirement_1",
+            MessageId::PluginDependencyInvalidRequirement2 => "plugin_dependency.invalid_requirement_2",
+            MessageId::PluginDependencyInvalidRequirement3 => "plugin_dependency.invalid_requirement_3",
+            MessageId::PluginDependencyMissing1 => "plugin_dependency.missing_1",
+            MessageId::PluginDependencyMissing2 => "plugin_dependency.missing_2",
+            MessageId::PluginDependencyMissing3 => "plugin_dependency.missing_3",
+            MessageId::PluginDependencyUnsatisfied1 => "plugin_dependency.unsatisfied_1",
+            MessageId::PluginDependencyUnsatisfied2 => "plugin_dependency.unsatisfied_2",
+            MessageId::PluginDependencyUnsatisfied3 => "plugin_dependency.unsatisfied_3",
+            MessageId::PluginDependencyUnsatisfied4 => "plugin_dependency.unsatisfied_4",
+            MessageId::PluginDependencyCycle1 => "plugin_dependency.cycle_1",
+            MessageId::PluginDependencyCycle2 => "plugin_dependency.cycle_2",
+            MessageId::PluginDependencyInUse1 => "plugin_dependency.in_use_1",
+            MessageId::PluginDependencyInUse2 => "plugin_dependency.in_use_2",
+            MessageId::PluginDependencyInUse3 => "plugin_dependency.in_use_3",
+            MessageId::PluginInitDescription => "plugin_init.description",
+            MessageId::PluginInitDisplay1 => "plugin_init.display_1",
+            MessageId::PluginInitDisplay2 => "plugin_init.display_2",
+            MessageId::PluginInitHostUnsupported1 => "plugin_init.host_unsupported_1",
+            MessageId::PluginInitHostUnsupported2 => "plugin_init.host_unsupported_2",
+            MessageId::PluginInitFailed1 => "plugin_init.failed_1",
+            MessageId::PluginInitFailed2 => "plugin_init.failed_2",
+            MessageId::PluginMismatchDescription => "plugin_mismatch.description",
+            MessageId::PluginMismatchDisplay1 => "plugin_mismatch.display_1",
+            MessageId::PluginMismatchDisplay2 => "plugin_mismatch.display_2",
+            MessageId::PluginMismatchMissingDescriptor => "plugin_mismatch.missing_descriptor",
+            MessageId::PluginMismatchBadMagic1 => "plugin_mismatch.bad_magic_1",
+            MessageId::PluginMismatchBadMagic2 => "plugin_mismatch.bad_magic_2",
+            MessageId::PluginMismatchAbiVersion1 => "plugin_mismatch.abi_version_1",
+            MessageId::PluginMismatchAbiVersion2 => "plugin_mismatch.abi_version_2",
+            MessageId::PluginMismatchAbiVersion3 => "plugin_mismatch.abi_version_3",
+            MessageId::PluginMismatchDescriptorSize1 => "plugin_mismatch.descriptor_size_1",
+            MessageId::PluginMismatchDescriptorSize2 => "plugin_mismatch.descriptor_size_2",
+            MessageId::PluginMismatchDescriptorSize3 => "plugin_mismatch.descriptor_size_3",
+            MessageId::PluginMismatchRustcVersion1 => "plugin_mismatch.rustc_version_1",
+            MessageId::PluginMismatchRustcVersion2 => "plugin_mismatch.rustc_version_2",
+            MessageId::PluginMismatchRustcVersion3 => "plugin_mismatch.rustc_version_3",
+            MessageId::PluginMismatchApi1 => "plugin_mismatch.api_1",
+            MessageId::PluginMismatchApi2 => "plugin_mismatch.api_2",
+            MessageId::PluginMismatchApi3 => "plugin_mismatch.api_3",
+            MessageId::PluginMismatchName1 => "plugin_mismatch.name_1",
+            MessageId::PluginMismatchName2 => "plugin_mismatch.name_2",
+            MessageId::PluginMismatchName3 => "plugin_mismatch.name_3",
+            MessageId::PolicyViolationDescription => "policy_violation.description",
+            MessageId::PolicyViolationDisplay1 => "policy_violation.display_1",
+            MessageId::PolicyViolationDisplay2 => "policy_violation.display_2",
+            MessageId::PolicyViolationInsufficientHardening1 => "policy_violation.insufficient_hardening_1",
+            MessageId::PolicyViolationInsufficientHardening2 => "policy_violation.insufficient_hardening_2",
+            MessageId::PolicyViolationInsufficientHardening3 => "policy_violation.insufficient_hardening_3",
+            MessageId::PolicyViolationRelativePath => "policy_violation.relative_path",
+            MessageId::PolicyViolationSymlink => "policy_violation.symlink",
+            MessageId::PolicyViolationUntrustedOwner1 => "policy_violation.untrusted_owner_1",
+            MessageId::PolicyViolationUntrustedOwner2 => "policy_violation.untrusted_owner_2",
+            MessageId::PolicyViolationWritableByOthers => "policy_violation.writable_by_others",
+            MessageId::StateHandoffDescription => "state_handoff.description",
+            MessageId::StateHandoffDisplay1 => "state_handoff.display_1",
+            MessageId::StateHandoffDisplay2 => "state_handoff.display_2",
+            MessageId::StateHandoffSaveFailed1 => "state_handoff.save_failed_1",
+            MessageId::StateHandoffSaveFailed2 => "state_handoff.save_failed_2",
+            MessageId::StateHandoffVersionMismatch1 => "state_handoff.version_mismatch_1",
+            MessageId::StateHandoffVersionMismatch2 => "state_handoff.version_mismatch_2",
+            MessageId::StateHandoffRestoreFailed1 => "state_handoff.restore_failed_1",
+            MessageId::StateHandoffRestoreFailed2 => "state_handoff.restore_failed_2",
+            MessageId::UnloadTimeoutDescription => "unload_timeout.description",
+            MessageId::UnloadTimeoutDisplay1 => "unload_timeout.display_1",
+            MessageId::UnloadTimeoutDisplay2 => "unload_timeout.display_2",
+            MessageId::UnloadTimeoutDisplay3 => "unload_timeout.display_3",
+        }
+    }
+
+    /// Returns the English text of the message, which is shown when the installed catalog does not translate it.
+    pub fn english(&self) -> &'static str {
+        match *self {
+            MessageId::IntegrityMismatchDescription => integrity_mismatch::description(),
+            MessageId::IntegrityMismatchDisplay1 => integrity_mismatch::display_1(),
+            MessageId::IntegrityMismatchDisplay2 => integrity_mismatch::display_2(),
+            MessageId::IntegrityMismatchDisplay3 => integrity_mismatch::display_3(),
+            MessageId::InvalidElfDescription => invalid_elf::description(),
+            MessageId::InvalidElfDisplay1 => invalid_elf::display_1(),
+            MessageId::InvalidElfBadMagic => invalid_elf::bad_magic(),
+            MessageId::InvalidElfTruncated => invalid_elf::truncated(),
+            MessageId::InvalidElfUnsupportedClass => invalid_elf::unsupported_class(),
+            MessageId::InvalidElfUnsupportedEncoding => invalid_elf::unsupported_encoding(),
+            MessageId::LibraryCloseDescription => library_close::description(),
+            MessageId::LibraryCloseDisplay1 => library_close::display_1(),
+            MessageId::LibraryFindSymbolDescription => library_find_symbol::description(),
+            MessageId::LibraryFindSymbolDisplay1 => library_find_symbol::display_1(),
+            MessageId::LibraryFindSymbolDisplay2 => library_find_symbol::display_2(),
+            MessageId::LibraryFindSymbolSuggestions1 => library_find_symbol::suggestions_1(),
+            MessageId::LibraryFindSymbolSuggestionsSeparator => library_find_symbol::suggestions_separator(),
+            MessageId::LibraryFindSymbolSuggestions2 => library_find_symbol::suggestions_2(),
+            MessageId::LibraryOpenDescription => library_open::description(),
+            MessageId::LibraryOpenDisplay1 => library_open::display_1(),
+            MessageId::LibraryOpenDisplay2 => library_open::display_2(),
+            MessageId::LibraryUnloadedDescription => library_unloaded::description(),
+            MessageId::LibraryUnloadedDisplay1 => library_unloaded::display_1(),
+            MessageId::LifecycleHookDescription => lifecycle_hook::description(),
+            MessageId::LifecycleHookDisplay1 => lifecycle_hook::display_1(),
+            MessageId::LifecycleHookDisplay2 => lifecycle_hook::display_2(),
+            MessageId::LifecycleHookDisplay3 => lifecycle_hook::display_3(),
+            MessageId::LifecycleHookDisplay4 => lifecycle_hook::display_4(),
+            MessageId::LifecycleHookDisplay5 => lifecycle_hook::display_5(),
+            MessageId::LifecycleHookLoad => lifecycle_hook::load(),
+            MessageId::LifecycleHookUnload => lifecycle_hook::unload(),
+            MessageId::MissingSymbolsDescription => missing_symbols::description(),
+            MessageId::MissingSymbolsDisplay1 => missing_symbols::display_1(),
+            MessageId::MissingSymbolsSeparator => missing_symbols::separator(),
+            MessageId::MissingSymbolsDisplay2 => missing_symbols::display_2(),
+            MessageId::OsErrorDescription => os_error::description(),
+            MessageId::OsErrorDisplay1 => os_error::display_1(),
+            MessageId::OsErrorDisplay2 => os_error::display_2(),
+            MessageId::OsErrorFailureDescription => os_error_failure::description(),
+            MessageId::OsErrorFailureDisplay1 => os_error_failure::display_1(),
+            MessageId::OsErrorFailureDisplay2 => os_error_failure::display_2(),
+            MessageId::PluginDependencyDescription => plugin_dependency::description(),
+            MessageId::PluginDependencyDisplay1 => plugin_dependency::display_1(),
+            MessageId::PluginDependencyDisplay2 => plugin_dependency::display_2(),
+            MessageId::PluginDependencyDisplay3 => plugin_dependency::display_3(),
+            MessageId::PluginDependencyInvalidRequirement1 => plugin_dependency::invalid_requirement_1(),
+            MessageId::PluginDependencyInvalidRequirement2 => plugin_dependency::invalid_requirement_2(),
+            MessageId::PluginDependencyInvalidRequirement3 => plugin_dependency::invalid_requirement_3(),
+            MessageId::PluginDependencyMissing1 => plugin_dependency::missing_1(),
+            MessageId::PluginDependencyMissing2 => plugin_dependency::missing_2(),
+            MessageId::PluginDependencyMissing3 => plugin_dependency::missing_3(),
+            MessageId::PluginDependencyUnsatisfied1 => plugin_dependency::unsatisfied_1(),
+            MessageId::PluginDependencyUnsatisfied2 => plugin_dependency::unsatisfied_2(),
+            MessageId::PluginDependencyUnsatisfied3 => plugin_dependency::unsatisfied_3(),
+            MessageId::PluginDependencyUnsatisfied4 => plugin_dependency::unsatisfied_4(),
+            MessageId::PluginDependencyCycle1 => plugin_dependency::cycle_1(),
+            MessageId::PluginDependencyCycle2 => plugin_dependency::cycle_2(),
+            MessageId::PluginDependencyInUse1 => plugin_dependency::in_use_1(),
+            MessageId::PluginDependencyInUse2 => plugin_dependency::in_use_2(),
+            MessageId::PluginDependencyInUse3 => plugin_dependency::in_use_3(),
+            MessageId::PluginInitDescription => plugin_init::description(),
+            MessageId::PluginInitDisplay1 => plugin_init::display_1(),
+            MessageId::PluginInitDisplay2 => plugin_init::display_2(),
+            MessageId::PluginInitHostUnsupported1 => plugin_init::host_unsupported_1(),
+            MessageId::PluginInitHostUnsupported2 => plugin_init::host_unsupported_2(),
+            MessageId::PluginInitFailed1 => plugin_init::failed_1(),
+            MessageId::PluginInitFailed2 => plugin_init::failed_2(),
+            MessageId::PluginMismatchDescription => plugin_mismatch::description(),
+            MessageId::PluginMismatchDisplay1 => plugin_mismatch::display_1(),
+            MessageId::PluginMismatchDisplay2 => plugin_mismatch::display_2(),
+            MessageId::PluginMismatchMissingDescriptor => plugin_mismatch::missing_descriptor(),
+            MessageId::PluginMismatchBadMagic1 => plugin_mismatch::bad_magic_1(),
+            MessageId::PluginMismatchBadMagic2 => plugin_mismatch::bad_magic_2(),
+            MessageId::PluginMismatchAbiVersion1 => plugin_mismatch::abi_version_1(),
+            MessageId::PluginMismatchAbiVersion2 => plugin_mismatch::abi_version_2(),
+            MessageId::PluginMismatchAbiVersion3 => plugin_mismatch::abi_version_3(),
+            MessageId::PluginMismatchDescriptorSize1 => plugin_mismatch::descriptor_size_1(),
+            MessageId::PluginMismatchDescriptorSize2 => plugin_mismatch::descriptor_size_2(),
+            MessageId::PluginMismatchDescriptorSize3 => plugin_mismatch::descriptor_size_3(),
+            MessageId::PluginMismatchRustcVersion1 => plugin_mismatch::rustc_version_1(),
+            MessageId::PluginMismatchRustcVersion2 => plugin_mismatch::rustc_version_2(),
+            MessageId::PluginMismatchRustcVersion3 => plugin_mismatch::rustc_version_3(),
+            MessageId::PluginMismatchApi1 => plugin_mismatch::api_1(),
+            MessageId::PluginMismatchApi2 => plugin_mismatch::api_2(),
+            MessageId::PluginMismatchApi3 => plugin_mismatch::api_3(),
+            MessageId::PluginMismatchName1 => plugin_mismatch::name_1(),
+            MessageId::PluginMismatchName2 => plugin_mismatch::name_2(),
+            MessageId::PluginMismatchName3 => plugin_mismatch::name_3(),
+            MessageId::PolicyViolationDescription => policy_violation::description(),
+            MessageId::PolicyViolationDisplay1 => policy_violation::display_1(),
+            MessageId::PolicyViolationDisplay2 => policy_violation::display_2(),
+            MessageId::PolicyViolationInsufficientHardening1 => policy_violation::insufficient_hardening_1(),
+            MessageId::PolicyViolationInsufficientHardening2 => policy_violation::insufficient_hardening_2(),
+            MessageId::PolicyViolationInsufficientHardening3 => policy_violation::insufficient_hardening_3(),
+            MessageId::PolicyViolationRelativePath => policy_violation::relative_path(),
+            MessageId::PolicyViolationSymlink => policy_violation::symlink(),
+            MessageId::PolicyViolationUntrustedOwner1 => policy_violation::untrusted_owner_1(),
+            MessageId::PolicyViolationUntrustedOwner2 => policy_violation::untrusted_owner_2(),
+            MessageId::PolicyViolationWritableByOthers => policy_violation::writable_by_others(),
+            MessageId::StateHandoffDescription => state_handoff::description(),
+            MessageId::StateHandoffDisplay1 => state_handoff::display_1(),
+            MessageId::StateHandoffDisplay2 => state_handoff::display_2(),
+            MessageId::StateHandoffSaveFailed1 => state_handoff::save_failed_1(),
+            MessageId::StateHandoffSaveFailed2 => state_handoff::save_failed_2(),
+            MessageId::StateHandoffVersionMismatch1 => state_handoff::version_mismatch_1(),
+            MessageId::StateHandoffVersionMismatch2 => state_handoff::version_mismatch_2(),
+            MessageId::StateHandoffRestoreFailed1 => state_handoff::restore_failed_1(),
+            MessageId::StateHandoffRestoreFailed2 => state_handoff::restore_failed_2(),
+            MessageId::UnloadTimeoutDescription => unload_timeout::description(),
+            MessageId::UnloadTimeoutDisplay1 => unload_timeout::display_1(),
+            MessageId::UnloadTimeoutDisplay2 => unload_timeout::display_2(),
+            MessageId::UnloadTimeoutDisplay3 => unload_timeout::display_3(),
+        }
+    }
+}
@@ -1 +1,19 @@
+pub mod catalog;
+
+pub mod english_catalog;
+
 pub mod error;
+
+pub mod message_catalog;
+
+pub mod message_id;
+
+pub use string::catalog::message;
+
+pub use string::catalog::set_message_catalog;
+
+pub use string::english_catalog::EnglishCatalog;
+
+pub use string::message_catalog::MessageCatalog;
+
+pub use string::message_id::MessageId;
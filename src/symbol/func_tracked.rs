@@ -1,4 +1,6 @@
 use FuncUnsafe;
+use LibUnsafe;
+use SharedlibResult as R;
 use Symbol;
 
 /// A pointer to a shared function which allows a user-provided ref-counting implementation to avoid outliving its library.
@@ -19,6 +21,36 @@ impl <T, TLib> FuncTracked<T, TLib> {
     }
 }
 
+impl <T, TLib> FuncTracked<T, TLib>
+    where T: Copy,
+          TLib: AsRef<LibUnsafe> {
+    /// Calls `call` with the function while counting the call as running inside the library, so that [LibTracked::unload_when_idle](struct.LibTracked.html#method.unload_when_idle) waits for it to return before closing the library.
+    ///
+    /// # Errors
+    /// A `LibraryUnloaded` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant, without calling `call`, if the library is being unloaded or has been unloaded.
+    ///
+    /// # Safety
+    /// The function passed to `call` must not be kept after `call` returns.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::FuncArc;
+    /// # use sharedlib::LibArc;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # fn test() -> R<()> {
+    /// # let lib = try!(unsafe { LibArc::new("examplelib.dll") });
+    /// let add: FuncArc<extern "C" fn(u32, u32) -> u32> = try!(unsafe { lib.find_func("add") });
+    /// let sum = try!(unsafe { add.call(|add| add(1, 2)) });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn call<TResult, TCall>(&self, call: TCall) -> R<TResult>
+        where TCall: FnOnce(T) -> TResult {
+        let _guard = try!(self._lib.as_ref().enter_call());
+        Ok(call(self.func))
+    }
+}
+
 impl <T, TLib> Symbol<T> for FuncTracked<T, TLib>
     where T: Copy {
     unsafe fn get(&self) -> T {
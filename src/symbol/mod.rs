@@ -18,8 +18,24 @@ pub mod func_tracked;
 
 pub mod func_unsafe;
 
+pub mod owned_symbols;
+
 pub mod symbol;
 
+pub mod symbol_batch;
+
+pub mod symbol_table;
+
+pub mod symbol_table_loader;
+
+pub mod table;
+
+pub mod table_arc;
+
+pub mod table_rc;
+
+pub mod table_tracked;
+
 pub use symbol::data::Data;
 
 pub use symbol::data_arc::DataArc;
@@ -40,4 +56,20 @@ pub use symbol::func_tracked::FuncTracked;
 
 pub use symbol::func_unsafe::FuncUnsafe;
 
+pub use symbol::owned_symbols::OwnedSymbols;
+
 pub use symbol::symbol::Symbol;
+
+pub use symbol::symbol_batch::SymbolBatch;
+
+pub use symbol::symbol_table::SymbolTable;
+
+pub use symbol::symbol_table_loader::SymbolTableLoader;
+
+pub use symbol::table::Table;
+
+pub use symbol::table_arc::TableArc;
+
+pub use symbol::table_rc::TableRc;
+
+pub use symbol::table_tracked::TableTracked;
@@ -0,0 +1,96 @@
+use LibUnsafe;
+use SharedlibResult as R;
+use SymbolTable;
+use std::ops::Deref;
+use std::path::Path;
+
+/// A shared library which owns a [SymbolTable](trait.SymbolTable.html) loaded from it.
+/// Unlike a [Table](struct.Table.html), it does not borrow the library, so it can be moved and stored in ordinary structs. Unlike a [TableTracked](struct.TableTracked.html), it needs no ref-count at all. The table is only reached through `&self`, by dereferencing the [OwnedSymbols](struct.OwnedSymbols.html) or through [table](#method.table), and it is dropped before the library.
+#[derive(Debug)]
+pub struct OwnedSymbols<T> {
+    table: T,
+    lib: LibUnsafe,
+}
+
+impl <T> OwnedSymbols<T>
+    where T: SymbolTable {
+    /// Opens a shared library at the specified path and finds every symbol of the table within it.
+    /// See [LibUnsafe::new](struct.LibUnsafe.html#method.new) for how the path is used.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if there is a problem opening the shared library. A `MissingSymbols` error will be returned if the shared library does not contain every required symbol of the table.
+    ///
+    /// # Safety
+    /// Opening a shared library may execute code within the shared library. The fields of `T` are not checked against the types of the symbols. Symbols copied out of the table must not be used after the [OwnedSymbols](struct.OwnedSymbols.html) is dropped.
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use sharedlib::OwnedSymbols;
+    /// # use sharedlib::SharedlibResult as R;
+    /// # use sharedlib::SymbolTable;
+    /// #[derive(SymbolTable)]
+    /// struct Example {
+    ///     some_func: extern "C" fn(),
+    /// }
+    ///
+    /// struct Plugin {
+    ///     example: OwnedSymbols<Example>,
+    /// }
+    ///
+    /// # fn test() -> R<()> {
+    /// let plugin =
+    ///     Plugin {
+    ///         example: try!(unsafe { OwnedSymbols::new("examplelib.dll") }),
+    ///     };
+    /// (plugin.example.some_func)();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn new<TPath>(path_to_lib: TPath) -> R<Self>
+        where TPath: AsRef<Path> {
+        let lib = try!(LibUnsafe::new(path_to_lib));
+        OwnedSymbols::from_lib(lib)
+    }
+
+    /// Finds every symbol of the table within an already opened shared library, and takes ownership of the library.
+    ///
+    /// # Errors
+    /// A `MissingSymbols` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if the shared library does not contain every required symbol of the table. The library is closed in that case.
+    ///
+    /// # Safety
+    /// The fields of `T` are not checked against the types of the symbols. Symbols copied out of the table must not be used after the [OwnedSymbols](struct.OwnedSymbols.html) is dropped.
+    pub unsafe fn from_lib(lib: LibUnsafe) -> R<Self> {
+        let table = try!(lib.load_table::<T>());
+        let result =
+            OwnedSymbols {
+                table: table,
+                lib: lib,
+            };
+        Ok(result)
+    }
+}
+
+impl <T> OwnedSymbols<T> {
+    /// Returns the table.
+    pub fn table(&self) -> &T {
+        &self.table
+    }
+
+    /// Returns the library which the table was loaded from.
+    pub fn lib(&self) -> &LibUnsafe {
+        &self.lib
+    }
+
+    /// Drops the table and returns the library, for instance to close it with [LibUnsafe::close_and_verify](struct.LibUnsafe.html#method.close_and_verify).
+    pub fn into_lib(self) -> LibUnsafe {
+        self.lib
+    }
+}
+
+impl <T> Deref for OwnedSymbols<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.table
+    }
+}
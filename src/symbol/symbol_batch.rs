@@ -0,0 +1,98 @@
+use DataUnsafe;
+use error::LibraryFindSymbol;
+use error::MissingSymbols;
+use FuncUnsafe;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::mem;
+use std::os::raw::c_void;
+
+/// The symbols found by [LibUnsafe::find_many](struct.LibUnsafe.html#method.find_many), in the order they were requested.
+/// Symbols which were found are reached by their index. Symbols which were missing are collected into a single `MissingSymbols` error, which names each of them together with its cause. Like the other unsafe symbols, the symbols of a [SymbolBatch](struct.SymbolBatch.html) provide no protection against outliving their library.
+#[derive(Debug)]
+pub struct SymbolBatch {
+    addresses: Vec<Option<*const c_void>>,
+    missing: Option<MissingSymbols>,
+}
+
+impl SymbolBatch {
+    /// Creates a new [SymbolBatch](struct.SymbolBatch.html) from the result of each lookup.
+    /// This should only be called within the library.
+    pub fn new(results: Vec<Result<*const c_void, LibraryFindSymbol>>) -> Self {
+        let mut addresses = Vec::with_capacity(results.len());
+        let mut errors = Vec::new();
+        for result in results {
+            match result {
+                Ok(address) => addresses.push(Some(address)),
+                Err(err) => {
+                    addresses.push(None);
+                    errors.push(err);
+                },
+            }
+        }
+        let missing =
+            if errors.is_empty() {
+                None
+            } else {
+                Some(MissingSymbols::new(errors))
+            };
+        SymbolBatch {
+            addresses: addresses,
+            missing: missing,
+        }
+    }
+
+    /// Returns the number of symbols which were requested.
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    /// Returns whether no symbols were requested.
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
+    /// Returns the address of the symbol at `index`, or `None` if it is missing.
+    ///
+    /// # Panics
+    /// Panics if `index` is not less than [len](#method.len).
+    pub fn address(&self, index: usize) -> Option<*const c_void> {
+        self.addresses[index]
+    }
+
+    /// Returns the data symbol at `index`, or `None` if it is missing.
+    ///
+    /// # Panics
+    /// Panics if `index` is not less than [len](#method.len).
+    pub fn data<T>(&self, index: usize) -> Option<DataUnsafe<T>> {
+        self.address(index).map(|address| address as DataUnsafe<T>)
+    }
+
+    /// Returns the function symbol at `index`, or `None` if it is missing.
+    ///
+    /// # Panics
+    /// Panics if `index` is not less than [len](#method.len).
+    ///
+    /// # Safety
+    /// This function is not type safe so there is no guarntee that `T` is really the type of the symbol. Using a symbol as a `T` when the symbol is not really of type `T` causes undefined behavior.
+    pub unsafe fn func<T>(&self, index: usize) -> Option<FuncUnsafe<T>>
+        where T: Copy {
+        self.address(index).map(|address| mem::transmute_copy(&address))
+    }
+
+    /// Returns the error which names every missing symbol, or `None` if every symbol was found.
+    pub fn missing(&self) -> Option<&MissingSymbols> {
+        self.missing.as_ref()
+    }
+
+    /// Returns the batch only if every symbol was found.
+    ///
+    /// # Errors
+    /// A `MissingSymbols` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant, which names every missing symbol together with its cause.
+    pub fn require_all(mut self) -> R<Self> {
+        match self.missing.take() {
+            Some(missing) => Err(E::from(missing)),
+            None => Ok(self),
+        }
+    }
+}
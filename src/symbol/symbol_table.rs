@@ -0,0 +1,41 @@
+use LibUnsafe;
+use SharedlibResult as R;
+
+/// A struct whose fields are symbols which are loaded from a shared library all at once, through [LibUnsafe::load_table](struct.LibUnsafe.html#method.load_table), [Lib::load_table](struct.Lib.html#method.load_table), or [LibTracked::load_table](struct.LibTracked.html#method.load_table).
+/// It is usually derived. Every field is looked up under its own name, or under the name given by `#[symbol(name = "...")]`. A field of type `Option<T>` is an optional symbol, which is `None` if the library does not contain it. If any other symbol is missing, loading fails with a `MissingSymbols` error which names every missing symbol.
+///
+/// # Safety
+/// Every field must have the type of the symbol it is loaded from.
+///
+/// # Examples
+/// ``` no_run
+/// # #[macro_use] extern crate sharedlib;
+/// # use sharedlib::Lib;
+/// # use sharedlib::SharedlibResult as R;
+/// # use sharedlib::SymbolTable;
+/// #[derive(SymbolTable)]
+/// struct Libm {
+///     ceil: extern "C" fn(f64) -> f64,
+///     #[symbol(name = "floor")]
+///     round_down: extern "C" fn(f64) -> f64,
+///     exp10: Option<extern "C" fn(f64) -> f64>,
+/// }
+///
+/// # fn test() -> R<()> {
+/// let lib = try!(unsafe { Lib::new("libm.so.6") });
+/// let libm = try!(unsafe { lib.load_table::<Libm>() });
+/// assert_eq!((libm.ceil)(0.45), 1.0);
+/// # Ok(())
+/// # }
+/// # fn main() {}
+/// ```
+pub unsafe trait SymbolTable: Sized {
+    /// Loads every symbol of the table from `lib`.
+    ///
+    /// # Errors
+    /// A `MissingSymbols` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant if `lib` does not contain every required symbol.
+    ///
+    /// # Safety
+    /// The symbols must not be used after `lib` is unloaded.
+    unsafe fn load(lib: &LibUnsafe) -> R<Self>;
+}
@@ -0,0 +1,83 @@
+use error::LibraryFindSymbol;
+use error::LibraryUnloaded;
+use error::MissingSymbols;
+use LibUnsafe;
+use SharedlibError as E;
+use SharedlibResult as R;
+
+/// Finds the symbols of a [SymbolTable](trait.SymbolTable.html) while collecting the names of the missing ones. It is used by `#[derive(SymbolTable)]`.
+pub struct SymbolTableLoader<'a> {
+    lib: &'a LibUnsafe,
+    missing: Vec<LibraryFindSymbol>,
+    unloaded: bool,
+}
+
+impl <'a> SymbolTableLoader<'a> {
+    /// Creates a new [SymbolTableLoader](struct.SymbolTableLoader.html) which finds symbols in `lib`.
+    pub fn new(lib: &'a LibUnsafe) -> Self {
+        SymbolTableLoader {
+            lib: lib,
+            missing: Vec::new(),
+            unloaded: false,
+        }
+    }
+
+    /// Finds a required symbol. If it is missing, its error is recorded for [finish](#method.finish) and `None` is returned.
+    ///
+    /// # Safety
+    /// `T` must be the type of the symbol.
+    pub unsafe fn required<T>(&mut self, symbol: &str) -> Option<T>
+        where T: Copy {
+        match self.lib.find_func::<T, _>(symbol) {
+            Ok(result) => Some(result),
+            Err(E::LibraryUnloaded(_)) => {
+                self.unloaded = true;
+                None
+            },
+            Err(E::LibraryFindSymbol(err)) => {
+                self.missing.push(err);
+                None
+            },
+            Err(err) => {
+                let err = LibraryFindSymbol::new(Box::new(err), symbol.trim_end_matches('\0').to_string());
+                self.missing.push(err);
+                None
+            },
+        }
+    }
+
+    /// Finds an optional symbol, or returns `None` if it is missing. Any other error is recorded for [finish](#method.finish), as it is for a required symbol.
+    ///
+    /// # Safety
+    /// `T` must be the type of the symbol.
+    pub unsafe fn optional<T>(&mut self, symbol: &str) -> Option<T>
+        where T: Copy {
+        match self.lib.find_func::<T, _>(symbol) {
+            Ok(result) => Some(result),
+            Err(E::LibraryFindSymbol(_)) => None,
+            Err(E::LibraryUnloaded(_)) => {
+                self.unloaded = true;
+                None
+            },
+            Err(err) => {
+                let err = LibraryFindSymbol::new(Box::new(err), symbol.trim_end_matches('\0').to_string());
+                self.missing.push(err);
+                None
+            },
+        }
+    }
+
+    /// Returns whether every required symbol was found.
+    ///
+    /// # Errors
+    /// A `MissingSymbols` error will be returned as a [SharedlibError](enum.SharedlibError.html) variant, which names every required symbol that was not found. A `LibraryUnloaded` error will be returned if the library was unloaded.
+    pub fn finish(self) -> R<()> {
+        if self.unloaded {
+            Err(E::from(LibraryUnloaded::new()))
+        } else if !self.missing.is_empty() {
+            Err(E::from(MissingSymbols::new(self.missing)))
+        } else {
+            Ok(())
+        }
+    }
+}
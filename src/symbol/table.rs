@@ -0,0 +1,29 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// A [SymbolTable](trait.SymbolTable.html) which uses a bound lifetime to avoid outliving its library.
+/// The table is reached by dereferencing the [Table](struct.Table.html).
+#[derive(Debug)]
+pub struct Table<'a, T> {
+    table: T,
+    lifetime: PhantomData<&'a ()>,
+}
+
+impl <'a, T> Table<'a, T> {
+    /// Creates a new [Table](struct.Table.html).
+    /// This should only be called within the library.
+    pub fn new(table: T) -> Self {
+        Table {
+            table: table,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+impl <'a, T> Deref for Table<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.table
+    }
+}
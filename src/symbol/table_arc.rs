@@ -0,0 +1,6 @@
+use LibUnsafe;
+use std::sync::Arc;
+use TableTracked;
+
+/// A [SymbolTable](trait.SymbolTable.html) which uses atomic ref-counting to avoid outliving its library.
+pub type TableArc<T> = TableTracked<T, Arc<LibUnsafe>>;
@@ -0,0 +1,6 @@
+use LibUnsafe;
+use std::rc::Rc;
+use TableTracked;
+
+/// A [SymbolTable](trait.SymbolTable.html) which uses non-atomic ref-counting to avoid outliving its library.
+pub type TableRc<T> = TableTracked<T, Rc<LibUnsafe>>;
@@ -0,0 +1,39 @@
+use std::ops::Deref;
+
+/// A [SymbolTable](trait.SymbolTable.html) which allows a user-provided ref-counting implementation to avoid outliving its library.
+/// The table is reached by dereferencing the [TableTracked](struct.TableTracked.html). The whole table holds a single ref-count to the library.
+#[derive(Debug)]
+pub struct TableTracked<T, TLib> {
+    table: T,
+    _lib: TLib,
+}
+
+impl <T, TLib> TableTracked<T, TLib> {
+    /// Creates a new [TableTracked](struct.TableTracked.html).
+    /// This should only be called within the library.
+    pub fn new(table: T, lib: TLib) -> Self {
+        TableTracked {
+            table: table,
+            _lib: lib,
+        }
+    }
+}
+
+impl <T, TLib> Deref for TableTracked<T, TLib> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.table
+    }
+}
+
+impl <T, TLib> Clone for TableTracked<T, TLib>
+    where T: Clone,
+          TLib: Clone {
+    fn clone(&self) -> Self {
+        TableTracked {
+            table: self.table.clone(),
+            _lib: self._lib.clone(),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use util::demangle;
+
+#[test]
+fn not_mangled() {
+    assert_eq!(demangle("plugin_init"), None);
+    assert_eq!(demangle("_Zfoo"), None);
+}
+
+#[test]
+fn cpp() {
+    assert_eq!(demangle("_Z11plugin_initv"), Some("plugin_init".to_string()));
+    assert_eq!(demangle("_ZN6plugin4initEv"), Some("plugin::init".to_string()));
+    assert_eq!(demangle("_ZNK6Plugin4nameEv"), Some("Plugin::name".to_string()));
+    assert_eq!(demangle("__Z11plugin_initv"), Some("plugin_init".to_string()));
+}
+
+#[test]
+fn cpp_std() {
+    assert_eq!(demangle("_ZSt9terminatev"), Some("std::terminate".to_string()));
+    assert_eq!(demangle("_ZNSt9exceptionD2Ev"), Some("std::exception".to_string()));
+    assert_eq!(demangle("_ZSt"), None);
+}
+
+#[test]
+fn rust_legacy() {
+    assert_eq!(demangle("_ZN6plugin4init17h0123456789abcdefE"), Some("plugin::init".to_string()));
+}
+
+#[test]
+fn truncated() {
+    assert_eq!(demangle("_ZN6plugin40initEv"), None);
+    assert_eq!(demangle("_Z18446744073709551615x"), None);
+}
@@ -0,0 +1,22 @@
+use util::edit_distance;
+
+#[test]
+fn identical() {
+    assert_eq!(edit_distance("plugin_init", "plugin_init"), 0);
+    assert_eq!(edit_distance("", ""), 0);
+}
+
+#[test]
+fn single_edits() {
+    assert_eq!(edit_distance("plugin_init", "plugin_int"), 1);
+    assert_eq!(edit_distance("plugin_init", "plugin_inits"), 1);
+    assert_eq!(edit_distance("plugin_init", "plugin_inin"), 1);
+}
+
+#[test]
+fn several_edits() {
+    assert_eq!(edit_distance("plugin_init", "plugin_init_v2"), 3);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("", "ceil"), 4);
+    assert_eq!(edit_distance("ceil", ""), 4);
+}
@@ -0,0 +1,56 @@
+// Builds a minimal little-endian 64-bit ELF object with the specified program headers, each given
+// as `(p_type, p_flags)`, and a dynamic section holding the specified `(d_tag, d_val)` entries.
+pub fn build_elf(program_headers: &[(u32, u32)], dynamic: &[(u64, u64)]) -> Vec<u8> {
+    const PT_DYNAMIC: u32 = 2;
+    let phoff = 0x40;
+    let dynamic_offset = phoff + 0x38 * (program_headers.len() + 1);
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"\x7fELF\x02\x01\x01");
+    bytes.resize(0x10, 0);
+    push_u16(&mut bytes, 3);
+    push_u16(&mut bytes, 0x3e);
+    push_u32(&mut bytes, 1);
+    push_u64(&mut bytes, 0);
+    push_u64(&mut bytes, phoff as u64);
+    push_u64(&mut bytes, 0);
+    push_u32(&mut bytes, 0);
+    push_u16(&mut bytes, 0x40);
+    push_u16(&mut bytes, 0x38);
+    push_u16(&mut bytes, program_headers.len() as u16 + 1);
+    push_u16(&mut bytes, 0x40);
+    push_u16(&mut bytes, 0);
+    push_u16(&mut bytes, 0);
+
+    let dynamic_size = 0x10 * (dynamic.len() + 1);
+    let headers = program_headers.iter().cloned().chain(Some((PT_DYNAMIC, 6)));
+    for (p_type, p_flags) in headers {
+        push_u32(&mut bytes, p_type);
+        push_u32(&mut bytes, p_flags);
+        let (offset, size) = if p_type == PT_DYNAMIC { (dynamic_offset, dynamic_size) } else { (0, 0) };
+        push_u64(&mut bytes, offset as u64);
+        push_u64(&mut bytes, 0);
+        push_u64(&mut bytes, 0);
+        push_u64(&mut bytes, size as u64);
+        push_u64(&mut bytes, size as u64);
+        push_u64(&mut bytes, 8);
+    }
+
+    for &(tag, value) in dynamic.iter().chain(Some(&(0, 0))) {
+        push_u64(&mut bytes, tag);
+        push_u64(&mut bytes, value);
+    }
+    bytes
+}
+
+fn push_u16(bytes: &mut Vec<u8>, value: u16) {
+    bytes.extend_from_slice(&value.to_le_bytes());
+}
+
+fn push_u32(bytes: &mut Vec<u8>, value: u32) {
+    bytes.extend_from_slice(&value.to_le_bytes());
+}
+
+fn push_u64(bytes: &mut Vec<u8>, value: u64) {
+    bytes.extend_from_slice(&value.to_le_bytes());
+}
@@ -0,0 +1,84 @@
+use std::panic;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use util;
+use util::ErrorLock;
+
+#[test]
+fn reentrant() {
+    let lock = ErrorLock::new();
+    let outer = lock.lock();
+    let inner = lock.lock();
+    drop(inner);
+    drop(outer);
+    drop(lock.lock());
+}
+
+#[test]
+fn error_guard_reentrant() {
+    let result = util::error_guard(|| util::error_guard(|| util::error_guard(|| 42)));
+    assert_eq!(result, 42);
+}
+
+#[test]
+fn released_after_panic() {
+    let lock = Arc::new(ErrorLock::new());
+    let panicking = lock.clone();
+    let result =
+        thread::spawn(
+            move || {
+                let _guard = panicking.lock();
+                panic!("panic while holding the error lock");
+            }
+        ).join();
+    assert!(result.is_err());
+
+    let (sender, receiver) = mpsc::channel();
+    let locking = lock.clone();
+    thread::spawn(
+        move || {
+            drop(locking.lock());
+            sender.send(()).unwrap();
+        }
+    );
+    receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+}
+
+#[test]
+fn error_guard_after_panic() {
+    let result = panic::catch_unwind(|| util::error_guard(|| panic!("panic inside error_guard")));
+    assert!(result.is_err());
+    assert_eq!(util::error_guard(|| 42), 42);
+}
+
+#[test]
+fn excludes_other_threads() {
+    let lock = Arc::new(ErrorLock::new());
+    let inside = Arc::new(AtomicUsize::new(0));
+    let threads: Vec<_> =
+        (0..8)
+            .map(
+                |_| {
+                    let lock = lock.clone();
+                    let inside = inside.clone();
+                    thread::spawn(
+                        move || {
+                            for _ in 0..1000 {
+                                let _outer = lock.lock();
+                                let _inner = lock.lock();
+                                assert_eq!(inside.fetch_add(1, Ordering::SeqCst), 0);
+                                thread::yield_now();
+                                inside.fetch_sub(1, Ordering::SeqCst);
+                            }
+                        }
+                    )
+                }
+            ).collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
@@ -0,0 +1,67 @@
+use error::*;
+use LibUnsafe;
+use std::error::Error;
+use std::sync::Arc;
+use std::thread;
+
+fn assert_shareable<T>()
+    where T: Clone + Error + Send + Sync + 'static {
+}
+
+#[test]
+fn errors_are_shareable() {
+    assert_shareable::<IntegrityMismatch>();
+    assert_shareable::<InvalidElf>();
+    assert_shareable::<LibraryClose>();
+    assert_shareable::<LibraryFindSymbol>();
+    assert_shareable::<LibraryOpen>();
+    assert_shareable::<LibraryUnloaded>();
+    assert_shareable::<LifecycleHook>();
+    assert_shareable::<MissingSymbols>();
+    assert_shareable::<OsError>();
+    assert_shareable::<OsErrorFailure>();
+    assert_shareable::<PluginDependency>();
+    assert_shareable::<PluginInit>();
+    assert_shareable::<PluginMismatch>();
+    assert_shareable::<PolicyViolation>();
+    assert_shareable::<SharedlibError>();
+    assert_shareable::<StateHandoff>();
+    assert_shareable::<UnloadTimeout>();
+}
+
+#[test]
+fn error_moves_across_threads() {
+    let err =
+        thread::spawn(|| unsafe { LibUnsafe::new("libsharedlib_does_not_exist") }.unwrap_err())
+            .join()
+            .unwrap();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    let library_open = err.source().unwrap();
+    assert!(library_open.source().is_some());
+}
+
+#[test]
+fn error_shared_through_arc() {
+    let err = Arc::new(unsafe { LibUnsafe::new("libsharedlib_does_not_exist") }.unwrap_err());
+    let message = err.to_string();
+    let threads: Vec<_> =
+        (0..4)
+            .map(|_| {
+                let err = err.clone();
+                thread::spawn(move || err.to_string())
+            })
+            .collect();
+    for thread in threads {
+        assert_eq!(thread.join().unwrap(), message);
+    }
+}
+
+#[test]
+fn error_clone_keeps_cause() {
+    let err = unsafe { LibUnsafe::new("libsharedlib_does_not_exist") }.unwrap_err();
+    let clone = err.clone();
+    assert_eq!(clone.to_string(), err.to_string());
+    assert_eq!(clone.kind(), err.kind());
+    let boxed: Box<Error + Send + Sync> = Box::new(clone);
+    assert!(boxed.source().unwrap().source().is_some());
+}
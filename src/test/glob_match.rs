@@ -0,0 +1,24 @@
+use util::glob_match;
+
+#[test]
+fn literal() {
+    assert!(glob_match("libfoo.so", "libfoo.so"));
+    assert!(!glob_match("libfoo.so", "libfoo.so.1"));
+}
+
+#[test]
+fn star() {
+    assert!(glob_match("lib*.so", "libfoo.so"));
+    assert!(glob_match("lib*.so", "lib.so"));
+    assert!(glob_match("*", ""));
+    assert!(glob_match("*foo*", "libfoobar.so"));
+    assert!(glob_match("lib*o*.so", "libxoyo.so"));
+    assert!(!glob_match("lib*.so", "libfoo.dll"));
+}
+
+#[test]
+fn question_mark() {
+    assert!(glob_match("lib?.so", "liba.so"));
+    assert!(!glob_match("lib?.so", "lib.so"));
+    assert!(!glob_match("lib?.so", "libab.so"));
+}
@@ -0,0 +1,110 @@
+use plugin::HostApi;
+use plugin::HOST_API_VERSION;
+use std::collections::HashMap;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr;
+use std::slice;
+use std::str;
+
+unsafe extern "C" fn map_config(context: *mut c_void, key: *const u8, key_len: usize, buffer: *mut u8, buffer_len: usize) -> isize {
+    let map = &*(context as *const HashMap<String, String>);
+    let key = str::from_utf8(slice::from_raw_parts(key, key_len)).unwrap();
+    match map.get(key) {
+        Some(value) => {
+            ptr::copy_nonoverlapping(value.as_ptr(), buffer, value.len().min(buffer_len));
+            value.len() as isize
+        },
+        None => -1,
+    }
+}
+
+#[test]
+fn default_host() {
+    let host = HostApi::new();
+    assert_eq!(host.size as usize, mem::size_of::<HostApi>());
+    assert_eq!(host.version, HOST_API_VERSION);
+    assert!(host.provides(&host.config));
+    assert_eq!(host.config("level"), None);
+    let ptr = host.alloc(64, 16);
+    assert!(!ptr.is_null());
+    assert_eq!(ptr as usize % 16, 0);
+    unsafe { host.dealloc(ptr, 64, 16) };
+    assert!(host.alloc(64, 3).is_null());
+}
+
+#[test]
+fn config() {
+    let mut map = HashMap::new();
+    map.insert("level".to_string(), "a value longer than nothing".to_string());
+    let mut host = HostApi::new();
+    host.context = &mut map as *mut HashMap<String, String> as *mut c_void;
+    host.config = map_config;
+    assert_eq!(host.config("level"), Some("a value longer than nothing".to_string()));
+    assert_eq!(host.config("missing"), None);
+}
+
+#[test]
+fn older_host() {
+    let mut host = HostApi::new();
+    host.config = map_config;
+    // A host which only knew the fields up to `log`.
+    host.size = (&host.alloc as *const _ as usize - &host as *const HostApi as usize) as u32;
+    assert!(host.provides(&host.log));
+    assert!(!host.provides(&host.alloc));
+    // Fields of another HostApi are never provided.
+    let other = HostApi::new();
+    assert!(!host.provides(&other.log));
+    assert!(host.alloc(64, 16).is_null());
+    assert_eq!(host.config("level"), None);
+}
+
+#[test]
+fn from_ptr() {
+    let host = HostApi::new();
+    unsafe {
+        assert!(HostApi::from_ptr(ptr::null(), 1).is_none());
+        assert!(HostApi::from_ptr(&host, HOST_API_VERSION).is_some());
+        assert!(HostApi::from_ptr(&host, HOST_API_VERSION + 1).is_none());
+    }
+}
+
+unsafe extern "C" fn count_log(context: *mut c_void, _level: u32, _message: *const u8, _message_len: usize) {
+    *(context as *mut u32) += 1;
+}
+
+// The table of a host which only knew the fields up to `log`.
+#[repr(C)]
+struct OldHost {
+    size: u32,
+    version: u32,
+    context: *mut c_void,
+    log: unsafe extern "C" fn(context: *mut c_void, level: u32, message: *const u8, message_len: usize),
+}
+
+fn offset_of<TField>(host: &HostApi, field: &TField) -> usize {
+    field as *const TField as usize - host as *const HostApi as usize
+}
+
+#[test]
+fn from_ptr_older_host() {
+    let full = HostApi::new();
+    let mut logged = 0u32;
+    let old = OldHost {
+        size: mem::size_of::<OldHost>() as u32,
+        version: 1,
+        context: &mut logged as *mut u32 as *mut c_void,
+        log: count_log,
+    };
+    unsafe {
+        let host = HostApi::from_ptr(&old as *const OldHost as *const HostApi, 1).unwrap();
+        assert_eq!(host.size() as usize, mem::size_of::<OldHost>());
+        assert!(host.get().is_none());
+        assert!(host.provides(offset_of(&full, &full.log), mem::size_of_val(&old.log)));
+        assert!(!host.provides(offset_of(&full, &full.alloc), 1));
+        host.log(1, "message");
+        assert!(host.alloc(64, 16).is_null());
+        assert_eq!(host.config("level"), None);
+    }
+    assert_eq!(logged, 1);
+}
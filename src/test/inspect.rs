@@ -0,0 +1,64 @@
+use error::SharedlibError;
+use inspect::ElfFile;
+use inspect::HardeningLevel;
+use inspect::Relro;
+use string::MessageId;
+use test::elf::build_elf;
+
+const PT_GNU_STACK: u32 = 0x6474e551;
+const PT_GNU_RELRO: u32 = 0x6474e552;
+const DT_FLAGS: u64 = 30;
+const DT_TEXTREL: u64 = 22;
+
+#[test]
+fn hardening_full() {
+    let bytes = build_elf(&[(PT_GNU_STACK, 6), (PT_GNU_RELRO, 4)], &[(DT_FLAGS, 8)]);
+    let hardening = ElfFile::parse(&bytes).unwrap().hardening();
+    assert_eq!(hardening.relro(), Relro::Full);
+    assert!(hardening.nx_stack());
+    assert!(hardening.bind_now());
+    assert!(!hardening.text_relocations());
+    assert_eq!(hardening.level(), HardeningLevel::Full);
+}
+
+#[test]
+fn hardening_partial() {
+    let bytes = build_elf(&[(PT_GNU_STACK, 6), (PT_GNU_RELRO, 4)], &[]);
+    let hardening = ElfFile::parse(&bytes).unwrap().hardening();
+    assert_eq!(hardening.relro(), Relro::Partial);
+    assert!(!hardening.bind_now());
+    assert_eq!(hardening.level(), HardeningLevel::Partial);
+}
+
+#[test]
+fn hardening_executable_stack() {
+    let bytes = build_elf(&[(PT_GNU_STACK, 7), (PT_GNU_RELRO, 4)], &[(DT_FLAGS, 8)]);
+    let hardening = ElfFile::parse(&bytes).unwrap().hardening();
+    assert!(!hardening.nx_stack());
+    assert_eq!(hardening.level(), HardeningLevel::None);
+}
+
+#[test]
+fn hardening_text_relocations() {
+    let bytes = build_elf(&[(PT_GNU_STACK, 6), (PT_GNU_RELRO, 4)], &[(DT_TEXTREL, 0)]);
+    let hardening = ElfFile::parse(&bytes).unwrap().hardening();
+    assert!(hardening.text_relocations());
+    assert_eq!(hardening.level(), HardeningLevel::None);
+}
+
+#[test]
+fn parse_not_elf() {
+    match ElfFile::parse(&[0; 64]).err().unwrap() {
+        SharedlibError::InvalidElf(err) => assert_eq!(err.reason(), MessageId::InvalidElfBadMagic),
+        err => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn parse_truncated() {
+    let bytes = build_elf(&[(PT_GNU_STACK, 6)], &[(DT_FLAGS, 8)]);
+    match ElfFile::parse(&bytes[..bytes.len() - 16]).err().unwrap() {
+        SharedlibError::InvalidElf(err) => assert_eq!(err.reason(), MessageId::InvalidElfTruncated),
+        err => panic!("unexpected error: {}", err),
+    }
+}
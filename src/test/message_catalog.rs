@@ -0,0 +1,52 @@
+use EnglishCatalog;
+use error::OsErrorFailure;
+use MessageCatalog;
+use MessageId;
+use set_message_catalog;
+use std::collections::HashSet;
+use std::error::Error;
+
+struct Pirate;
+
+impl MessageCatalog for Pirate {
+    fn message(&self, id: MessageId) -> Option<&str> {
+        match id {
+            MessageId::OsErrorFailureDisplay1 => Some("Arr, the native function '"),
+            MessageId::OsErrorFailureDescription => Some("Arr, a native function sank."),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn installed_catalog_renders_errors() {
+    let err = OsErrorFailure::new("dlopen".to_string());
+    let english = err.to_string();
+
+    set_message_catalog(&Pirate);
+    let pirate = err.to_string();
+    #[allow(deprecated)]
+    let description = err.description().to_string();
+    set_message_catalog(&EnglishCatalog);
+
+    assert!(pirate.starts_with("Arr, the native function 'dlopen"), "{}", pirate);
+    // Messages the catalog does not translate fall back to English.
+    assert!(pirate.ends_with(MessageId::OsErrorFailureDisplay2.english()), "{}", pirate);
+    assert_eq!(description, "Arr, a native function sank.");
+    assert_eq!(err.to_string(), english);
+}
+
+#[test]
+fn english_catalog_is_complete() {
+    for &id in MessageId::all() {
+        assert_eq!(EnglishCatalog.message(id), Some(id.english()));
+        assert!(!id.english().is_empty(), "{}", id.key());
+    }
+}
+
+#[test]
+fn keys_are_unique() {
+    let keys: HashSet<&str> = MessageId::all().iter().map(MessageId::key).collect();
+    assert_eq!(keys.len(), MessageId::all().len());
+    assert_eq!(MessageId::LibraryOpenDisplay1.key(), "library_open.display_1");
+}
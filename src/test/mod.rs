@@ -1,5 +1,33 @@
+mod demangle;
+
+mod edit_distance;
+
+mod elf;
+
+#[cfg(unix)]
+mod error_lock;
+
+mod error_send;
+
+mod glob_match;
+
+mod host_api;
+
+mod inspect;
+
+mod message_catalog;
+
+pub mod plugin;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+mod sha256;
+
+mod state_handoff;
+
 #[cfg(unix)]
 pub mod unix;
 
+mod version_req;
+
 #[cfg(windows)]
 pub mod windows;
@@ -0,0 +1,96 @@
+use error::PluginMismatchKind;
+use plugin::PLUGIN_ABI_VERSION;
+use plugin::PluginApi;
+use plugin::PluginDescriptor;
+use plugin::RUSTC_VERSION_HASH;
+use SharedlibError;
+use std::mem;
+use std::path::Path;
+use std::ptr;
+
+#[repr(C)]
+pub struct GreeterApi {
+    pub greet: extern "C" fn() -> u32,
+}
+
+unsafe impl PluginApi for GreeterApi {
+    const NAME: &'static str = "greeter";
+    const VERSION: u32 = 2;
+}
+
+pub struct OtherApi;
+
+unsafe impl PluginApi for OtherApi {
+    const NAME: &'static str = "other";
+    const VERSION: u32 = 2;
+}
+
+extern "C" fn greet() -> u32 {
+    42
+}
+
+declare_plugin!(GreeterApi, GreeterApi { greet: greet });
+
+fn copy_descriptor() -> PluginDescriptor {
+    unsafe { ptr::read(&sharedlib_plugin_descriptor) }
+}
+
+fn mismatch_kind<T>(descriptor: &PluginDescriptor) -> PluginMismatchKind
+    where T: PluginApi {
+    match unsafe { PluginDescriptor::check::<T>(descriptor, Path::new("libgreeter.so")) } {
+        Err(SharedlibError::PluginMismatch(err)) => err.kind().clone(),
+        _ => panic!("expected a PluginMismatch error"),
+    }
+}
+
+#[test]
+fn declared_descriptor() {
+    let descriptor = unsafe { PluginDescriptor::check::<GreeterApi>(&sharedlib_plugin_descriptor, Path::new("libgreeter.so")) }.unwrap();
+    assert_eq!(descriptor.name(), "sharedlib");
+    assert_eq!(descriptor.version(), env!("CARGO_PKG_VERSION"));
+    assert_eq!(descriptor.api_name(), "greeter");
+    assert_eq!(descriptor.descriptor_size as usize, mem::size_of::<PluginDescriptor>());
+    let api = unsafe { &*(descriptor.api as *const GreeterApi) };
+    assert_eq!((api.greet)(), 42);
+}
+
+#[test]
+fn bad_magic() {
+    let mut descriptor = copy_descriptor();
+    descriptor.magic = 0x7f45_4c46;
+    assert_eq!(mismatch_kind::<GreeterApi>(&descriptor), PluginMismatchKind::BadMagic(0x7f45_4c46));
+}
+
+#[test]
+fn abi_version() {
+    let mut descriptor = copy_descriptor();
+    descriptor.abi_version = PLUGIN_ABI_VERSION + 1;
+    assert_eq!(mismatch_kind::<GreeterApi>(&descriptor), PluginMismatchKind::AbiVersion { expected: PLUGIN_ABI_VERSION, actual: PLUGIN_ABI_VERSION + 1 });
+}
+
+#[test]
+fn descriptor_size() {
+    let mut descriptor = copy_descriptor();
+    descriptor.descriptor_size = 16;
+    let expected = mem::size_of::<PluginDescriptor>() as u32;
+    assert_eq!(mismatch_kind::<GreeterApi>(&descriptor), PluginMismatchKind::DescriptorSize { expected: expected, actual: 16 });
+}
+
+#[test]
+fn rustc_version() {
+    let mut descriptor = copy_descriptor();
+    descriptor.rustc_version_hash = !RUSTC_VERSION_HASH;
+    assert_eq!(mismatch_kind::<GreeterApi>(&descriptor), PluginMismatchKind::RustcVersion { expected: RUSTC_VERSION_HASH, actual: !RUSTC_VERSION_HASH });
+}
+
+#[test]
+fn api() {
+    let expected =
+        PluginMismatchKind::Api {
+            expected_name: "other".to_string(),
+            expected_version: 2,
+            actual_name: "greeter".to_string(),
+            actual_version: 2,
+        };
+    assert_eq!(mismatch_kind::<OtherApi>(&sharedlib_plugin_descriptor), expected);
+}
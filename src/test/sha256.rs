@@ -0,0 +1,34 @@
+use util::Sha256;
+use util::to_hex;
+
+fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    to_hex(hasher.finish())
+}
+
+#[test]
+fn sha256_empty() {
+    assert_eq!(sha256_hex(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+}
+
+#[test]
+fn sha256_abc() {
+    assert_eq!(sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+}
+
+#[test]
+fn sha256_two_blocks() {
+    let data = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+    assert_eq!(sha256_hex(data), "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+}
+
+#[test]
+fn sha256_incremental() {
+    let data = [0x61; 1000];
+    let mut hasher = Sha256::new();
+    for chunk in data.chunks(7) {
+        hasher.update(chunk);
+    }
+    assert_eq!(to_hex(hasher.finish()), sha256_hex(&data));
+}
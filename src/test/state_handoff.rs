@@ -0,0 +1,75 @@
+use error::StateHandoffKind;
+use hot::hand_off;
+use hot::StateWriter;
+use hot::STATE_VERSION_MISMATCH;
+use SharedlibError;
+use std::os::raw::c_int;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::slice;
+
+const STATE: &[u8] = b"counter=42";
+
+unsafe extern "C" fn save(version: *mut u32, write: StateWriter, context: *mut c_void) -> c_int {
+    *version = 3;
+    write(context, STATE.as_ptr(), 8);
+    write(context, STATE[8..].as_ptr(), STATE.len() - 8);
+    0
+}
+
+unsafe extern "C" fn save_failing(_version: *mut u32, _write: StateWriter, _context: *mut c_void) -> c_int {
+    7
+}
+
+unsafe extern "C" fn restore(version: u32, data: *const u8, len: usize) -> c_int {
+    if version != 3 {
+        return STATE_VERSION_MISMATCH;
+    }
+    if slice::from_raw_parts(data, len) == STATE {
+        0
+    } else {
+        -1
+    }
+}
+
+unsafe extern "C" fn restore_version_2(version: u32, _data: *const u8, _len: usize) -> c_int {
+    if version == 2 {
+        0
+    } else {
+        STATE_VERSION_MISMATCH
+    }
+}
+
+unsafe extern "C" fn restore_failing(_version: u32, _data: *const u8, _len: usize) -> c_int {
+    5
+}
+
+fn handoff_kind(err: SharedlibError) -> StateHandoffKind {
+    match err {
+        SharedlibError::StateHandoff(err) => err.kind(),
+        _ => panic!("expected a StateHandoff error"),
+    }
+}
+
+#[test]
+fn hand_off_state() {
+    unsafe { hand_off(save, restore, Path::new("libplugin.so")) }.unwrap();
+}
+
+#[test]
+fn hand_off_save_failed() {
+    let err = unsafe { hand_off(save_failing, restore, Path::new("libplugin.so")) }.unwrap_err();
+    assert_eq!(handoff_kind(err), StateHandoffKind::SaveFailed(7));
+}
+
+#[test]
+fn hand_off_version_mismatch() {
+    let err = unsafe { hand_off(save, restore_version_2, Path::new("libplugin.so")) }.unwrap_err();
+    assert_eq!(handoff_kind(err), StateHandoffKind::VersionMismatch(3));
+}
+
+#[test]
+fn hand_off_restore_failed() {
+    let err = unsafe { hand_off(save, restore_failing, Path::new("libplugin.so")) }.unwrap_err();
+    assert_eq!(handoff_kind(err), StateHandoffKind::RestoreFailed(5));
+}
@@ -0,0 +1,18 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use test::unix::scratch_dir;
+
+// Compiles `source` into a shared library in a scratch directory named after `name`, or returns
+// `None` if there is no C compiler, so that tests which need one can be skipped.
+pub fn build_c_lib(name: &str, source: &str) -> Option<PathBuf> {
+    let dir = scratch_dir(name);
+    let source_path = dir.join("lib.c");
+    let path = dir.join("lib.so");
+    fs::write(&source_path, source).unwrap();
+    let status = Command::new("cc").arg("-shared").arg("-fPIC").arg("-o").arg(&path).arg(&source_path).status();
+    match status {
+        Ok(ref status) if status.success() => Some(path),
+        _ => None,
+    }
+}
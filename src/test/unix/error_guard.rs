@@ -0,0 +1,75 @@
+use LibUnsafe;
+use SharedlibError as E;
+use std::sync::Arc;
+use std::sync::Barrier;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use test::unix::LIBM;
+use util;
+
+type Ceil = extern "C" fn(f64) -> f64;
+
+// Holding the error lock must not keep another thread from loading a library, since constructors
+// run inside the loader and may use this crate themselves.
+#[test]
+fn load_while_error_lock_held() {
+    let (sender, receiver) = mpsc::channel();
+    util::error_guard(
+        || {
+            thread::spawn(
+                move || {
+                    let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+                    let ceil = unsafe { lib.find_func::<Ceil, _>("ceil") }.unwrap();
+                    sender.send(ceil(0.45)).unwrap();
+                }
+            );
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(10)).unwrap(), 1.0);
+        }
+    );
+}
+
+// Errors are read on the thread which caused them, so each thread must see its own.
+#[test]
+fn errors_attributed_under_contention() {
+    let threads = 8;
+    let barrier = Arc::new(Barrier::new(threads));
+    let handles: Vec<_> =
+        (0..threads)
+            .map(
+                |i| {
+                    let barrier = barrier.clone();
+                    thread::spawn(
+                        move || {
+                            let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+                            let missing_lib = format!("libsharedlib_missing_{}.so", i);
+                            let missing_symbol = format!("sharedlib_missing_{}", i);
+                            barrier.wait();
+                            for _ in 0..200 {
+                                match unsafe { LibUnsafe::new(&missing_lib) } {
+                                    Err(E::LibraryOpen(err)) => {
+                                        let message = format!("{}", err);
+                                        assert_eq!(message.matches(&missing_lib[..]).count(), 2, "{}", message);
+                                    },
+                                    Err(err) => panic!("unexpected error: {}", err),
+                                    Ok(_) => panic!("opened a missing library"),
+                                }
+                                match unsafe { lib.find_func::<Ceil, _>(&missing_symbol) } {
+                                    Err(E::LibraryFindSymbol(err)) => {
+                                        let message = format!("{}", err);
+                                        assert!(message.ends_with(&format!("undefined symbol: {}", missing_symbol)), "{}", message);
+                                    },
+                                    Err(err) => panic!("unexpected error: {}", err),
+                                    Ok(_) => panic!("found a missing symbol"),
+                                }
+                                let ceil = unsafe { lib.find_func::<Ceil, _>("ceil") }.unwrap();
+                                assert_eq!(ceil(i as f64 + 0.45), i as f64 + 1.0);
+                            }
+                        }
+                    )
+                }
+            ).collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+use error::ErrorKind;
+use error::OsError;
+use LibUnsafe;
+use os::unix::dlerror_kind;
+use SharedlibError as E;
+use std::error::Error;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use test::unix::LIBM;
+use test::unix::scratch_dir;
+
+#[test]
+fn error_kind_not_found() {
+    let err = unsafe { LibUnsafe::new("libsharedlib_does_not_exist.so") }.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    match err {
+        E::LibraryOpen(ref err) => {
+            assert_eq!(err.path(), Path::new("libsharedlib_does_not_exist.so"));
+            assert_eq!(err.kind(), ErrorKind::NotFound);
+        },
+        ref err => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn error_kind_not_a_shared_object() {
+    let dir = scratch_dir("error-kind");
+    let path = dir.join("libnot_elf.so");
+    let mut file = File::create(&path).unwrap();
+    file.write_all(&[b'x'; 256]).unwrap();
+    let err = unsafe { LibUnsafe::new(&path) }.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotASharedObject);
+}
+
+#[test]
+fn error_kind_symbol_not_found() {
+    let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+    let err = unsafe { lib.find_func::<extern "C" fn(), _>("not_a_libm_function") }.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::SymbolNotFound);
+    match err {
+        E::LibraryFindSymbol(ref err) => assert_eq!(err.symbol(), "not_a_libm_function"),
+        ref err => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn error_source_chain() {
+    let err = unsafe { LibUnsafe::new("libsharedlib_does_not_exist.so") }.unwrap_err();
+    let library_open = err.source().unwrap();
+    assert!(library_open.source().is_some());
+
+    let mut innermost: &Error = &err;
+    while let Some(source) = innermost.source() {
+        innermost = source;
+    }
+    let os_error = innermost.downcast_ref::<OsError>().unwrap();
+    assert_eq!(os_error.function_called(), "dlopen");
+    assert_eq!(os_error.kind(), ErrorKind::NotFound);
+    assert!(os_error.raw_message().contains("libsharedlib_does_not_exist.so"), "{}", os_error.raw_message());
+}
+
+#[test]
+fn error_kind_messages() {
+    let cases =
+        [
+            ("dlopen", "libfoo.so: cannot open shared object file: No such file or directory", ErrorKind::NotFound),
+            ("dlopen", "dlopen(libfoo.dylib, 1): image not found", ErrorKind::NotFound),
+            ("dlopen", "/lib/libfoo.so: cannot open shared object file: Permission denied", ErrorKind::PermissionDenied),
+            ("dlopen", "/lib/libfoo.so: wrong ELF class: ELFCLASS32", ErrorKind::WrongArchitecture),
+            ("dlopen", "dlopen(libfoo.dylib, 1): no suitable image found.  Did find:\n\tlibfoo.dylib: mach-o, but wrong architecture", ErrorKind::WrongArchitecture),
+            ("dlopen", "/lib/libfoo.so: invalid ELF header", ErrorKind::NotASharedObject),
+            ("dlopen", "Error loading shared library /lib/libfoo.so: Exec format error", ErrorKind::NotASharedObject),
+            ("dlopen", "Error loading shared library libbar.so: No such file or directory (needed by /lib/libfoo.so)", ErrorKind::MissingDependency),
+            ("dlopen", "dlopen(libfoo.dylib, 1): Library not loaded: @rpath/libbar.dylib\n  Referenced from: libfoo.dylib\n  Reason: image not found", ErrorKind::MissingDependency),
+            ("dlopen", "/lib/libc.so.6: version `GLIBC_9.99' not found (required by /lib/libfoo.so)", ErrorKind::MissingDependency),
+            ("dlopen", "/lib/libfoo.so: undefined symbol: bar", ErrorKind::UndefinedSymbol),
+            ("dlopen", "Error relocating /lib/libfoo.so: bar: symbol not found", ErrorKind::UndefinedSymbol),
+            ("dlsym", "/lib/libfoo.so: undefined symbol: bar", ErrorKind::SymbolNotFound),
+            ("dlsym", "dlsym(0x1, bar): symbol not found", ErrorKind::SymbolNotFound),
+            ("dlvsym", "/lib/libfoo.so: undefined symbol: bar, version FOO_1", ErrorKind::SymbolNotFound),
+            ("dlclose", "shared object not open", ErrorKind::Other),
+        ];
+    for &(function, message, kind) in cases.iter() {
+        assert_eq!(dlerror_kind(function, message), kind, "{}", message);
+    }
+}
@@ -0,0 +1,52 @@
+use LibUnsafe;
+use SharedlibError as E;
+use std::time::Duration;
+use test::unix::LIBM;
+
+#[test]
+fn find_many_found() {
+    let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+    let batch = lib.find_many(&["ceil", "floor\0"]).unwrap().require_all().unwrap();
+    assert_eq!(batch.len(), 2);
+    let ceil: extern "C" fn(f64) -> f64 = unsafe { batch.func(0) }.unwrap();
+    let floor: extern "C" fn(f64) -> f64 = unsafe { batch.func(1) }.unwrap();
+    assert_eq!(ceil(0.45), 1.0);
+    assert_eq!(floor(0.45), 0.0);
+    assert_eq!(batch.address(0), Some(unsafe { lib.find_func::<*const u8, _>("ceil") }.unwrap() as _));
+}
+
+#[test]
+fn find_many_missing() {
+    let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+    let symbols = vec!["not_a_libm_function".to_string(), "ceil".to_string(), "also_not_a_libm_function\0".to_string()];
+    let batch = lib.find_many(&symbols).unwrap();
+    assert_eq!(batch.len(), 3);
+    assert!(batch.address(0).is_none());
+    assert!(batch.address(1).is_some());
+    assert!(batch.address(2).is_none());
+
+    {
+        let missing = batch.missing().unwrap();
+        assert_eq!(missing.symbols(), vec!["not_a_libm_function", "also_not_a_libm_function"]);
+        let message = format!("{}", missing);
+        assert!(message.contains("undefined symbol: not_a_libm_function"), "{}", message);
+        assert!(message.contains("undefined symbol: also_not_a_libm_function"), "{}", message);
+    }
+
+    match batch.require_all() {
+        Err(E::MissingSymbols(err)) => assert_eq!(err.errors().len(), 2),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("every symbol was found"),
+    }
+}
+
+#[test]
+fn find_many_unloaded() {
+    let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+    unsafe { lib.unload_when_idle(Duration::from_secs(1)) }.unwrap();
+    match lib.find_many(&["ceil"]) {
+        Err(E::LibraryUnloaded(_)) => (),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("found a symbol in an unloaded library"),
+    }
+}
@@ -0,0 +1,61 @@
+use HotFunc;
+use HotLib;
+use LibUnsafe;
+use std::fs;
+use std::sync::Arc;
+use Symbol;
+use test::unix::libm_path;
+use test::unix::scratch_dir;
+
+type Ceil = extern fn(f64) -> f64;
+
+#[test]
+fn hot_func_follows_reload() {
+    unsafe {
+        let dir = scratch_dir("hot-func");
+        let path = dir.join("libhot.so");
+        fs::copy(libm_path(), &path).unwrap();
+        let mut lib: HotLib<Arc<LibUnsafe>> = HotLib::new(&path).unwrap();
+        let ceil: HotFunc<Ceil, _> = lib.find_hot_func("ceil").unwrap();
+        assert_eq!(ceil.name(), "ceil");
+        assert_eq!(ceil.generation(), 0);
+        let old_ceil = ceil.current();
+
+        lib.reload().unwrap();
+        assert_eq!(ceil.generation(), 1);
+        assert!(ceil.current().get() as usize != old_ceil.get() as usize);
+        assert_eq!(ceil.call(|ceil| ceil(0.45)).unwrap(), 1.0);
+
+        // A clone shares the slot, so it follows reloads as well.
+        let clone = ceil.clone();
+        lib.reload().unwrap();
+        assert_eq!(clone.generation(), 2);
+        assert_eq!(clone.call(|ceil| ceil(1.45)).unwrap(), 2.0);
+    }
+}
+
+#[test]
+fn hot_func_releases_old_generation() {
+    unsafe {
+        let dir = scratch_dir("hot-func-release");
+        let path = dir.join("libhot.so");
+        fs::copy(libm_path(), &path).unwrap();
+        let mut lib: HotLib<Arc<LibUnsafe>> = HotLib::new(&path).unwrap();
+        let ceil: HotFunc<Ceil, _> = lib.find_hot_func("ceil").unwrap();
+        let shadow =
+            lib.lib().as_ref().loaded_objects().unwrap()
+                .into_iter()
+                .find(|object| object.to_string_lossy().contains(".hot-"))
+                .unwrap();
+
+        // A call which is in progress keeps its generation loaded.
+        ceil.call(
+            |_| {
+                lib.reload().unwrap();
+                assert!(LibUnsafe::get_loaded(&shadow).unwrap().is_some());
+            }
+        ).unwrap();
+        assert!(LibUnsafe::get_loaded(&shadow).unwrap().is_none());
+        assert_eq!(ceil.generation(), 1);
+    }
+}
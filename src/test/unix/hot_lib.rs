@@ -0,0 +1,113 @@
+use FuncArc;
+use HotFunc;
+use HotLib;
+use LibUnsafe;
+use SharedlibError;
+use std::fs;
+use std::os::raw::c_int;
+use std::sync::Arc;
+use Symbol;
+use test::unix::build_c_lib;
+use test::unix::libm_path;
+use test::unix::scratch_dir;
+
+#[test]
+fn reload_if_changed() {
+    unsafe {
+        let libm = libm_path();
+        let dir = scratch_dir("hot-lib");
+        let path = dir.join("libhot.so");
+        fs::copy(&libm, &path).unwrap();
+        let mut lib: HotLib<Arc<LibUnsafe>> = HotLib::new(&path).unwrap();
+        assert_eq!(lib.generation(), 0);
+        assert!(!lib.reload_if_changed().unwrap());
+        let old_ceil: FuncArc<extern fn(f64) -> f64> = lib.find_func("ceil").unwrap();
+
+        // Builds usually replace the file, which gives it a new inode.
+        let staged = dir.join("libhot.so.tmp");
+        fs::copy(&libm, &staged).unwrap();
+        fs::rename(&staged, &path).unwrap();
+        assert!(lib.has_changed().unwrap());
+        assert!(lib.reload_if_changed().unwrap());
+        assert_eq!(lib.generation(), 1);
+        assert!(!lib.has_changed().unwrap());
+
+        let new_ceil: FuncArc<extern fn(f64) -> f64> = lib.find_func("ceil").unwrap();
+        assert!(new_ceil.get() as usize != old_ceil.get() as usize);
+        assert_eq!(old_ceil.get()(0.45), 1.0);
+        assert_eq!(new_ceil.get()(0.45), 1.0);
+
+        // Shadow copies are unlinked once they are loaded.
+        let names: Vec<_> = fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().file_name()).collect();
+        assert_eq!(names, vec!["libhot.so"]);
+    }
+}
+
+#[test]
+fn old_generation_kept_alive() {
+    unsafe {
+        let dir = scratch_dir("hot-lib-old-generation");
+        let path = dir.join("libhot.so");
+        fs::copy(libm_path(), &path).unwrap();
+        let mut lib: HotLib<Arc<LibUnsafe>> = HotLib::new(&path).unwrap();
+        let old_lib = lib.lib().clone();
+        let old_ceil: FuncArc<extern fn(f64) -> f64> = lib.find_func("ceil").unwrap();
+        lib.reload().unwrap();
+        let old_objects = old_lib.as_ref().loaded_objects().unwrap();
+        drop(old_lib);
+        let shadow = old_objects.iter().find(|object| object.to_string_lossy().contains(".hot-")).unwrap().clone();
+        assert!(LibUnsafe::get_loaded(&shadow).unwrap().is_some());
+        assert_eq!(old_ceil.get()(0.45), 1.0);
+        drop(old_ceil);
+        assert!(LibUnsafe::get_loaded(&shadow).unwrap().is_none());
+    }
+}
+
+// A build which saves its state and one whose restore refuses every version of it.
+const SAVING_BUILD: &str = "int value(void) { return 1; }\nint sharedlib_save_state(unsigned *version, void (*write)(void *, const unsigned char *, unsigned long), void *context) { *version = 1; write(context, (const unsigned char *)\"x\", 1); return 0; }\n";
+const REFUSING_BUILD: &str = "int value(void) { return 2; }\nint sharedlib_restore_state(unsigned version, const unsigned char *data, unsigned long len) { (void)version; (void)data; (void)len; return 1; }\n";
+
+// Checks that a failed reload left `lib` and `value` on the old build.
+unsafe fn assert_not_reloaded(lib: &HotLib<Arc<LibUnsafe>>, value: &HotFunc<extern "C" fn() -> c_int, Arc<LibUnsafe>>, old_value: usize) {
+    assert_eq!(lib.generation(), 0);
+    assert_eq!(value.generation(), 0);
+    assert_eq!(value.current().get() as usize, old_value);
+    assert_eq!(value.call(|value| value()).unwrap(), 1);
+    let current: FuncArc<extern "C" fn() -> c_int> = lib.find_func("value").unwrap();
+    assert_eq!(current.get()(), 1);
+}
+
+#[test]
+fn failed_reload_keeps_old_generation() {
+    unsafe {
+        let (saving, refusing) =
+            match (build_c_lib("hot-lib-saving", SAVING_BUILD), build_c_lib("hot-lib-refusing", REFUSING_BUILD)) {
+                (Some(saving), Some(refusing)) => (saving, refusing),
+                _ => return,
+            };
+        let dir = scratch_dir("hot-lib-failed-reload");
+        let path = dir.join("libhot.so");
+        fs::copy(&saving, &path).unwrap();
+        let mut lib: HotLib<Arc<LibUnsafe>> = HotLib::new(&path).unwrap();
+        let value: HotFunc<extern "C" fn() -> c_int, _> = lib.find_hot_func("value").unwrap();
+        let old_value = value.current().get() as usize;
+
+        // The handoff fails.
+        let staged = dir.join("libhot.so.tmp");
+        fs::copy(&refusing, &staged).unwrap();
+        fs::rename(&staged, &path).unwrap();
+        match lib.reload() {
+            Err(SharedlibError::StateHandoff(_)) => (),
+            _ => panic!("expected a StateHandoff error"),
+        }
+        assert_not_reloaded(&lib, &value, old_value);
+
+        // The new build cannot be loaded.
+        fs::write(&path, b"not a shared library").unwrap();
+        match lib.reload() {
+            Err(SharedlibError::LibraryOpen(_)) => (),
+            _ => panic!("expected a LibraryOpen error"),
+        }
+        assert_not_reloaded(&lib, &value, old_value);
+    }
+}
@@ -0,0 +1,17 @@
+use inspect::ElfFile;
+use inspect::HardeningLevel;
+use test::unix::libm_path;
+
+#[test]
+fn libm_hardening() {
+    let hardening = ElfFile::open(libm_path()).unwrap().hardening();
+    assert!(hardening.nx_stack());
+    assert!(!hardening.text_relocations());
+    assert!(hardening.level() >= HardeningLevel::Partial);
+}
+
+#[test]
+fn libm_dynamic_symbols() {
+    let elf = ElfFile::open(libm_path()).unwrap();
+    assert!(elf.dynamic_symbols().iter().any(|symbol| symbol.name() == "ceil" && symbol.is_defined()));
+}
@@ -1,3 +1,24 @@
+#[cfg(any(target_os="linux", target_os="android"))]
+use Lib;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::fs;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::fs::File;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::fs::Permissions;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::io::BufRead;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::io::BufReader;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::mem;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::os::unix::fs::PermissionsExt;
+#[cfg(any(target_os="linux", target_os="android"))]
+use std::path::PathBuf;
+#[cfg(any(target_os="linux", target_os="android"))]
+use test::unix::scratch_dir;
+
 #[cfg(not(any(target_os="android", target_os="ios", target_os="macos")))]
 pub const LIBM: &'static str = "libm.so.6";
 
@@ -6,3 +27,38 @@ pub const LIBM: &'static str = "libm.so";
 
 #[cfg(any(target_os="ios", target_os="macos"))]
 pub const LIBM: &'static str = "libm.dylib";
+
+#[cfg(any(target_os="linux", target_os="android"))]
+lazy_static! {
+    static ref LIBM_PATH: PathBuf = find_libm_path();
+}
+
+// Finds the file which the loader maps for `LIBM`.
+#[cfg(any(target_os="linux", target_os="android"))]
+pub fn libm_path() -> PathBuf {
+    LIBM_PATH.clone()
+}
+
+// Copies libm into a scratch directory named after `name`. The copy has its own identity, so it is
+// only resident while a test keeps it loaded. It is only writable by its owner.
+#[cfg(any(target_os="linux", target_os="android"))]
+pub fn copy_libm(name: &str) -> PathBuf {
+    let lib = scratch_dir(name).join("libm-copy.so");
+    fs::copy(libm_path(), &lib).unwrap();
+    fs::set_permissions(&lib, Permissions::from_mode(0o755)).unwrap();
+    lib
+}
+
+// Copies of libm which tests load share its soname, and the loader resolves `LIBM` to whichever
+// object with that soname it finds first. Keeping the real libm loaded for the whole run makes that
+// the real libm, for this lookup and for any other test which opens `LIBM`.
+#[cfg(any(target_os="linux", target_os="android"))]
+fn find_libm_path() -> PathBuf {
+    let lib = unsafe { Lib::new(LIBM).unwrap() };
+    mem::forget(lib);
+    let maps = BufReader::new(File::open("/proc/self/maps").unwrap());
+    maps.lines()
+        .filter_map(|line| line.unwrap().split_whitespace().nth(5).map(PathBuf::from))
+        .find(|path| path.file_name().map(|name| name.to_string_lossy().starts_with("libm")) == Some(true))
+        .unwrap()
+}
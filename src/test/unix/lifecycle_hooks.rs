@@ -0,0 +1,91 @@
+use error::LifecycleHookKind;
+use LibArc;
+use LibUnsafe;
+use OpenOptions;
+use SharedlibError;
+use std::process;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use test::unix::build_c_lib;
+
+// `sched_yield` returns zero and `getpid` a non-zero code, which makes them stand-ins for hooks
+// which succeed and fail.
+const LIBC: &str = "libc.so.6";
+
+fn hook_error(result: Result<(), SharedlibError>) -> (LifecycleHookKind, String, i32) {
+    match result {
+        Err(SharedlibError::LifecycleHook(err)) => (err.kind(), err.symbol().to_string(), err.code()),
+        _ => panic!("expected a LifecycleHook error"),
+    }
+}
+
+#[test]
+fn load_hook_succeeds() {
+    let lib: LibArc = unsafe { OpenOptions::new().on_load("sched_yield").open(LIBC) }.unwrap();
+    drop(lib);
+}
+
+#[test]
+fn load_hook_fails() {
+    let result: Result<LibArc, SharedlibError> = unsafe { OpenOptions::new().on_load("getpid").on_unload("getpid").open(LIBC) };
+    let (kind, symbol, code) = hook_error(result.map(|_| ()));
+    assert_eq!(kind, LifecycleHookKind::Load);
+    assert_eq!(symbol, "getpid");
+    assert_eq!(code as u32, process::id());
+}
+
+#[test]
+fn missing_hook() {
+    match unsafe { OpenOptions::new().on_unload("sharedlib_no_such_hook").open::<LibUnsafe, _>(LIBC) } {
+        Err(SharedlibError::LibraryFindSymbol(_)) => (),
+        _ => panic!("expected a LibraryFindSymbol error"),
+    }
+}
+
+#[test]
+fn unload_hook_runs_once() {
+    let lib: LibUnsafe = unsafe { OpenOptions::new().on_unload("getpid").open(LIBC) }.unwrap();
+    let (kind, _, code) = hook_error(unsafe { lib.unload_when_idle(Default::default()) });
+    assert_eq!(kind, LifecycleHookKind::Unload);
+    assert_eq!(code as u32, process::id());
+    // The library is closed even though the hook failed, so the hook does not run again.
+    match unsafe { lib.unload_when_idle(Default::default()) } {
+        Err(SharedlibError::LibraryUnloaded(_)) => (),
+        _ => panic!("expected a LibraryUnloaded error"),
+    }
+}
+
+#[test]
+fn unload_hook_on_close_and_verify() {
+    let lib: LibUnsafe = unsafe { OpenOptions::new().on_unload("sched_yield").open(LIBC) }.unwrap();
+    assert!(lib.close_and_verify().is_ok());
+}
+
+// A library whose unload hook calls back into the test through `on_unload`, so that the test can
+// count how often the hook ran after the library is gone.
+const COUNTING_HOOK: &str = "void (*on_unload)(void);\nint unload_hook(void) { if (on_unload) on_unload(); return 0; }\n";
+
+static UNLOADS: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn count_unload() {
+    UNLOADS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn unload_hook_runs_on_last_drop() {
+    let path =
+        match build_c_lib("unload-hook-last-drop", COUNTING_HOOK) {
+            Some(path) => path,
+            None => return,
+        };
+    let lib: LibArc = unsafe { OpenOptions::new().on_unload("unload_hook").open(&path) }.unwrap();
+    unsafe {
+        let on_unload = lib.as_ref().find_data::<Option<extern "C" fn()>, _>("on_unload").unwrap();
+        *(on_unload as *mut Option<extern "C" fn()>) = Some(count_unload);
+    }
+    let clone = lib.clone();
+    drop(lib);
+    assert_eq!(UNLOADS.load(Ordering::SeqCst), 0);
+    drop(clone);
+    assert_eq!(UNLOADS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,176 @@
+use error::PolicyViolationKind;
+use error::SharedlibError;
+use inspect::HardeningLevel;
+use Lib;
+use LoadPolicy;
+use OpenOptions;
+use std::fs;
+use std::fs::File;
+use std::fs::Permissions;
+use std::os::unix::fs::chown;
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::fs::symlink;
+use std::path::Path;
+use test::elf::build_elf;
+use test::unix::copy_libm;
+use test::unix::scratch_dir;
+use test::unix::scratch_root;
+
+// Returns whether the directories above the scratch directories pass the load policy. If one of
+// them does not, no library in a scratch directory can pass it either, so the tests which expect a
+// library to pass are skipped.
+fn scratch_root_trusted() -> bool {
+    for dir in scratch_root().ancestors() {
+        let metadata = fs::metadata(dir).unwrap();
+        if let Err(err) = LoadPolicy::check_directory(&metadata, dir) {
+            eprintln!("skipping a load policy test, since the scratch directories are below {}: {}", dir.display(), err);
+            return false;
+        }
+    }
+    true
+}
+
+fn open_with(policy: LoadPolicy, path: &Path) -> Result<Lib, SharedlibError> {
+    unsafe { OpenOptions::new().policy(policy).open(path) }
+}
+
+fn assert_violation(result: Result<Lib, SharedlibError>, kind: PolicyViolationKind, path: &Path) {
+    match result {
+        Err(SharedlibError::PolicyViolation(err)) => {
+            assert_eq!(err.kind(), kind);
+            assert_eq!(err.path(), path);
+        },
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("the load policy was not enforced"),
+    }
+}
+
+#[test]
+fn policy_accepts_trusted() {
+    if !scratch_root_trusted() {
+        return;
+    }
+    let lib = copy_libm("policy-trusted");
+    open_with(LoadPolicy::new(), &lib).unwrap();
+}
+
+#[test]
+fn policy_refuses_writable_file() {
+    let lib = copy_libm("policy-writable-file");
+    fs::set_permissions(&lib, Permissions::from_mode(0o777)).unwrap();
+    assert_violation(open_with(LoadPolicy::new(), &lib), PolicyViolationKind::WritableByOthers, &lib);
+}
+
+#[test]
+fn policy_refuses_writable_directory() {
+    let lib = copy_libm("policy-writable-directory");
+    let dir = lib.parent().unwrap();
+    fs::set_permissions(dir, Permissions::from_mode(0o777)).unwrap();
+    assert_violation(open_with(LoadPolicy::new(), &lib), PolicyViolationKind::WritableByOthers, dir);
+}
+
+#[test]
+fn policy_refuses_relative_path() {
+    let mut policy = LoadPolicy::new();
+    policy.refuse_relative_paths(true);
+    let path = Path::new("libm.so");
+    assert_violation(open_with(policy, path), PolicyViolationKind::RelativePath, path);
+}
+
+#[test]
+fn policy_refuses_symlink() {
+    if !scratch_root_trusted() {
+        return;
+    }
+    let lib = copy_libm("policy-symlink");
+    let link = lib.with_file_name("libm-link.so");
+    symlink(&lib, &link).unwrap();
+    open_with(LoadPolicy::new(), &link).unwrap();
+
+    let mut policy = LoadPolicy::new();
+    policy.refuse_symlinks(true);
+    assert_violation(open_with(policy, &link), PolicyViolationKind::Symlink, &link);
+}
+
+#[test]
+fn policy_checks_directories_of_open_file() {
+    let lib = copy_libm("policy-open-file-writable");
+    let dir = lib.parent().unwrap();
+    fs::set_permissions(dir, Permissions::from_mode(0o777)).unwrap();
+    let file = File::open(&lib).unwrap();
+    // The path now names a trusted file, as it would after an attacker swapped it back.
+    let trusted = copy_libm("policy-open-file-trusted");
+    match LoadPolicy::new().check(&file, &trusted) {
+        Err(SharedlibError::PolicyViolation(err)) => {
+            assert_eq!(err.kind(), PolicyViolationKind::WritableByOthers);
+            assert_eq!(err.path(), dir);
+        },
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(()) => panic!("the directories of the open file were not checked"),
+    }
+}
+
+#[test]
+fn policy_opens_without_following_symlink() {
+    let lib = copy_libm("policy-nofollow");
+    let link = lib.with_file_name("libm-link.so");
+    symlink(&lib, &link).unwrap();
+    LoadPolicy::new().open(&link).unwrap();
+
+    let mut policy = LoadPolicy::new();
+    policy.refuse_symlinks(true);
+    assert!(policy.open(&link).is_err());
+    policy.open(&lib).unwrap();
+}
+
+#[test]
+fn policy_refuses_untrusted_owner() {
+    let lib = copy_libm("policy-untrusted-owner");
+    // Changing the owner of a file requires root, so there is nothing to check otherwise.
+    if chown(&lib, Some(4242), None).is_ok() {
+        assert_violation(open_with(LoadPolicy::new(), &lib), PolicyViolationKind::UntrustedOwner(4242), &lib);
+    }
+}
+
+#[test]
+fn policy_refuses_insufficient_hardening() {
+    if !scratch_root_trusted() {
+        return;
+    }
+    let dir = scratch_dir("policy-hardening");
+    let lib = dir.join("libexecstack.so");
+    // An executable stack and no RELRO.
+    fs::write(&lib, build_elf(&[(0x6474e551, 7)], &[])).unwrap();
+    fs::set_permissions(&lib, Permissions::from_mode(0o755)).unwrap();
+
+    let mut policy = LoadPolicy::new();
+    policy.require_hardening(HardeningLevel::Partial);
+    let kind =
+        PolicyViolationKind::InsufficientHardening {
+            actual: HardeningLevel::None,
+            required: HardeningLevel::Partial,
+        };
+    assert_violation(open_with(policy, &lib), kind, &lib);
+}
+
+#[test]
+fn policy_accepts_sufficient_hardening() {
+    if !scratch_root_trusted() {
+        return;
+    }
+    let lib = copy_libm("policy-sufficient-hardening");
+    let mut policy = LoadPolicy::new();
+    policy.require_hardening(HardeningLevel::Partial);
+    open_with(policy, &lib).unwrap();
+}
+
+#[test]
+fn policy_accepts_sticky_root_directory() {
+    let tmp = Path::new("/tmp");
+    let metadata = fs::metadata(tmp).unwrap();
+    // Only meaningful where /tmp is the usual root-owned, world-writable sticky directory.
+    if metadata.uid() == 0 && metadata.mode() & 0o1777 == 0o1777 {
+        LoadPolicy::check_directory(&metadata, tmp).unwrap();
+    }
+}
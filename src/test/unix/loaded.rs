@@ -0,0 +1,57 @@
+use Func;
+use Lib;
+use LibUnsafe;
+use std::fs;
+use Symbol;
+use test::unix::copy_libm;
+
+#[test]
+fn get_loaded_not_resident() {
+    let lib = copy_libm("get-loaded-not-resident");
+    assert!(unsafe { LibUnsafe::get_loaded(&lib) }.unwrap().is_none());
+}
+
+#[test]
+fn get_loaded_resident() {
+    unsafe {
+        let path = copy_libm("get-loaded-resident");
+        let lib = Lib::new(&path).unwrap();
+        let loaded = LibUnsafe::get_loaded(&path).unwrap().unwrap();
+        drop(lib);
+        // The handle from `get_loaded` holds its own reference.
+        let ceil = loaded.find_func::<extern fn(f64) -> f64, _>("ceil").unwrap();
+        assert_eq!(ceil(0.45), 1.0);
+        drop(loaded);
+        assert!(LibUnsafe::get_loaded(&path).unwrap().is_none());
+    }
+}
+
+#[test]
+fn try_clone_keeps_loaded() {
+    unsafe {
+        let path = copy_libm("try-clone");
+        let lib = Lib::new(&path).unwrap();
+        let clone = lib.try_clone().unwrap();
+        drop(lib);
+        assert!(LibUnsafe::get_loaded(&path).unwrap().is_some());
+        let ceil: Func<extern fn(f64) -> f64> = clone.find_func("ceil").unwrap();
+        assert_eq!(ceil.get()(0.45), 1.0);
+        drop(clone);
+        assert!(LibUnsafe::get_loaded(&path).unwrap().is_none());
+    }
+}
+
+#[test]
+fn try_clone_after_file_replaced() {
+    unsafe {
+        let path = copy_libm("try-clone-replaced");
+        let lib = Lib::new(&path).unwrap();
+        // The clone shares the loaded object, whatever the path names by now.
+        fs::remove_file(&path).unwrap();
+        fs::write(&path, b"not a shared library").unwrap();
+        let clone = lib.try_clone().unwrap();
+        drop(lib);
+        let ceil: Func<extern fn(f64) -> f64> = clone.find_func("ceil").unwrap();
+        assert_eq!(ceil.get()(0.45), 1.0);
+    }
+}
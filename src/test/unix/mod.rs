@@ -1,5 +1,77 @@
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod c_lib;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub mod error_guard;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod error_kind;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub mod find_many;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod hot_func;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod hot_lib;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod inspect;
+
 pub mod libm;
 
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub mod lifecycle_hooks;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod loaded;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod load_policy;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub mod namespace;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod open_options;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod plugin_loader;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod plugin_registry;
+
+pub mod scratch;
+
 pub mod shared;
 
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub mod symbol_cache;
+
+#[cfg(target_os="linux")]
+pub mod symbol_suggestions;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod symbol_table_loader;
+
+#[cfg(all(target_os="linux", target_env="gnu"))]
+pub mod unload;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod unload_when_idle;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub use test::unix::c_lib::build_c_lib;
+
 pub use test::unix::libm::LIBM;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub use test::unix::libm::copy_libm;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub use test::unix::libm::libm_path;
+
+pub use test::unix::scratch::scratch_dir;
+
+pub use test::unix::scratch::scratch_root;
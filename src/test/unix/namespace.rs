@@ -0,0 +1,65 @@
+use Func;
+use Lib;
+use LibArc;
+use LibUnsafe;
+use Namespace;
+use Symbol;
+use test::unix::LIBM;
+
+#[test]
+fn namespace_libm_ceil() {
+    unsafe {
+        let namespace = Namespace::new();
+        let lib: Lib = namespace.open(LIBM).unwrap();
+        let ceil: Func<extern fn(f64) -> f64> = lib.find_func("ceil").unwrap();
+        assert_eq!(ceil.get()(0.45), 1.0);
+    }
+}
+
+#[test]
+fn namespace_tracked() {
+    unsafe {
+        let namespace = Namespace::new();
+        let lib: LibArc = namespace.open(LIBM).unwrap();
+        let ceil = lib.find_func::<extern fn(f64) -> f64, _>("ceil").unwrap();
+        drop(lib);
+        assert_eq!(ceil.get()(0.45), 1.0);
+    }
+}
+
+#[test]
+fn namespace_isolates_copies() {
+    unsafe {
+        let base = Lib::new(LIBM).unwrap();
+        let namespace = Namespace::new();
+        assert_eq!(namespace.id(), None);
+        let isolated: Lib = namespace.open(LIBM).unwrap();
+        let again: Lib = namespace.open(LIBM).unwrap();
+
+        assert_eq!(base.as_ref().namespace_id().unwrap(), 0);
+        let id = namespace.id().unwrap();
+        assert!(id != 0);
+        assert_eq!(isolated.as_ref().namespace_id().unwrap(), id);
+        assert_eq!(again.as_ref().namespace_id().unwrap(), id);
+
+        let base_ceil: Func<extern fn(f64) -> f64> = base.find_func("ceil").unwrap();
+        let isolated_ceil: Func<extern fn(f64) -> f64> = isolated.find_func("ceil").unwrap();
+        let again_ceil: Func<extern fn(f64) -> f64> = again.find_func("ceil").unwrap();
+        assert!(base_ceil.get() as usize != isolated_ceil.get() as usize);
+        assert_eq!(isolated_ceil.get() as usize, again_ceil.get() as usize);
+    }
+}
+
+#[test]
+fn namespace_loaded_objects() {
+    unsafe {
+        let namespace = Namespace::new();
+        let lib: LibUnsafe = namespace.open(LIBM).unwrap();
+        let objects = lib.loaded_objects().unwrap();
+        let is_libm = |path: &::std::path::PathBuf| path.to_string_lossy().contains("libm");
+        assert!(objects.iter().any(&is_libm));
+        // The namespace carries its own copy of libc rather than sharing the program's.
+        assert!(objects.iter().any(|path| path.to_string_lossy().contains("libc")));
+        assert!(!objects.iter().any(|path| path.to_string_lossy().contains("sharedlib")));
+    }
+}
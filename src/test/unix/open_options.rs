@@ -0,0 +1,55 @@
+use error::SharedlibError;
+use Func;
+use Lib;
+use OpenOptions;
+use std::fs::File;
+use Symbol;
+use test::unix::libm_path;
+use test::unix::LIBM;
+use verify::Sha256Verifier;
+
+fn libm_digest() -> [u8; 32] {
+    let path = libm_path();
+    let mut file = File::open(&path).unwrap();
+    Sha256Verifier::digest_of(&mut file, &path).unwrap()
+}
+
+#[test]
+fn open_default() {
+    unsafe {
+        let lib: Lib = OpenOptions::new().open(LIBM).unwrap();
+        let ceil: Func<extern fn(f64) -> f64> = lib.find_func("ceil").unwrap();
+        assert_eq!(ceil.get()(0.45), 1.0);
+    }
+}
+
+#[test]
+fn verify_sha256_matches() {
+    unsafe {
+        let lib: Lib = OpenOptions::new().verify_sha256(libm_digest()).open(libm_path()).unwrap();
+        let ceil: Func<extern fn(f64) -> f64> = lib.find_func("ceil").unwrap();
+        assert_eq!(ceil.get()(0.45), 1.0);
+    }
+}
+
+#[test]
+fn verify_sha256_mismatch() {
+    let expected = [0; 32];
+    let err = unsafe { OpenOptions::new().verify_sha256(expected).open::<Lib, _>(libm_path()) }.err().unwrap();
+    match err {
+        SharedlibError::IntegrityMismatch(err) => {
+            assert_eq!(err.expected(), &expected[..]);
+            assert_eq!(err.actual(), &libm_digest()[..]);
+        },
+        err => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn verify_sha256_missing_file() {
+    let err = unsafe { OpenOptions::new().verify_sha256([0; 32]).open::<Lib, _>("/nonexistent/libm.so") }.err().unwrap();
+    match err {
+        SharedlibError::LibraryOpen(_) => (),
+        err => panic!("unexpected error: {}", err),
+    }
+}
@@ -0,0 +1,62 @@
+use error::PluginInitKind;
+use error::PluginMismatchKind;
+use LibArc;
+use plugin::HOST_API_UNSUPPORTED;
+use plugin::HOST_API_VERSION;
+use plugin::Plugin;
+use plugin::PluginLoader;
+use SharedlibError;
+use SharedlibResult;
+use std::os::raw::c_int;
+use std::ptr;
+use test::plugin::GreeterApi;
+use test::unix::build_c_lib;
+use test::unix::libm_path;
+
+#[test]
+fn load_without_descriptor() {
+    match unsafe { PluginLoader::new().load::<GreeterApi, _>(libm_path()) } {
+        Err(SharedlibError::PluginMismatch(err)) => {
+            assert_eq!(*err.kind(), PluginMismatchKind::MissingDescriptor);
+            assert_eq!(err.path(), libm_path().as_path());
+        },
+        _ => panic!("expected a PluginMismatch error"),
+    }
+}
+
+// Returns what `PluginLoader::init` makes of a plugin whose `sharedlib_plugin_init` returns
+// `code`, or `None` if there is no C compiler.
+fn init_plugin(name: &str, code: c_int) -> Option<SharedlibResult<()>> {
+    let source = format!("int sharedlib_plugin_init(const void *host) {{ (void)host; return {}; }}\n", code);
+    build_c_lib(name, &source).map(|path| {
+        let loader = PluginLoader::new();
+        let lib = unsafe { LibArc::new(&path) }.unwrap();
+        let plugin: Plugin<GreeterApi> = Plugin::new("init".to_string(), "1.0.0".to_string(), Vec::new(), path, ptr::null(), lib);
+        unsafe { loader.init(&plugin) }
+    })
+}
+
+#[test]
+fn init_host_unsupported() {
+    match init_plugin("init-host-unsupported", HOST_API_UNSUPPORTED) {
+        Some(Err(SharedlibError::PluginInit(err))) => assert_eq!(err.kind(), PluginInitKind::HostUnsupported(HOST_API_VERSION)),
+        Some(_) => panic!("expected a PluginInit error"),
+        None => {},
+    }
+}
+
+#[test]
+fn init_failed() {
+    match init_plugin("init-failed", 7) {
+        Some(Err(SharedlibError::PluginInit(err))) => assert_eq!(err.kind(), PluginInitKind::Failed(7)),
+        Some(_) => panic!("expected a PluginInit error"),
+        None => {},
+    }
+}
+
+#[test]
+fn init_succeeded() {
+    if let Some(result) = init_plugin("init-succeeded", 0) {
+        result.unwrap();
+    }
+}
@@ -0,0 +1,175 @@
+use error::PluginDependencyKind;
+use error::PluginMismatchKind;
+use LibArc;
+use plugin::Dependency;
+use plugin::Plugin;
+use plugin::PluginRegistry;
+use plugin::RejectedPlugin;
+use plugin::RejectionReason;
+use SharedlibError;
+use std::fs;
+use std::path::PathBuf;
+use std::ptr;
+use test::plugin::GreeterApi;
+use test::unix::libm_path;
+use test::unix::scratch_dir;
+
+#[test]
+fn scan_rejects_without_descriptor() {
+    let dir = scratch_dir("plugin-registry");
+    fs::copy(libm_path(), dir.join("libplugin_a.so")).unwrap();
+    fs::copy(libm_path(), dir.join("libother.so")).unwrap();
+    fs::write(dir.join("libplugin_b.txt"), "not a library").unwrap();
+
+    let mut registry = PluginRegistry::<GreeterApi>::new();
+    registry.filter("libplugin_*");
+    unsafe { registry.scan(&dir) }.unwrap();
+    assert_eq!(registry.names().count(), 0);
+    assert!(registry.get("greeter").is_none());
+
+    let rejected = registry.rejected();
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0].path(), dir.join("libplugin_a.so").as_path());
+    match *rejected[0].reason() {
+        RejectionReason::Error(SharedlibError::PluginMismatch(ref err)) => {
+            assert_eq!(*err.kind(), PluginMismatchKind::MissingDescriptor);
+        },
+        _ => panic!("expected a PluginMismatch error"),
+    }
+    assert!(!unsafe { registry.reload("greeter") }.unwrap());
+}
+
+#[test]
+fn scan_missing_dir() {
+    let dir = scratch_dir("plugin-registry-missing").join("missing");
+    let mut registry = PluginRegistry::<GreeterApi>::new();
+    match unsafe { registry.scan(&dir) } {
+        Err(SharedlibError::LibraryOpen(_)) => (),
+        _ => panic!("expected a LibraryOpen error"),
+    }
+}
+
+fn fake_plugin(name: &str, version: &str, dependencies: &[(&str, &str)]) -> Plugin<GreeterApi> {
+    let lib = unsafe { LibArc::new(libm_path()) }.unwrap();
+    let dependencies = dependencies.iter().map(|&(name, requirement)| Dependency::new(name.to_string(), requirement.to_string())).collect();
+    Plugin::new(name.to_string(), version.to_string(), dependencies, PathBuf::from(format!("lib{}.so", name)), ptr::null(), lib)
+}
+
+fn dependency_kind(rejected: &RejectedPlugin) -> PluginDependencyKind {
+    match *rejected.reason() {
+        RejectionReason::Error(SharedlibError::PluginDependency(ref err)) => err.kind().clone(),
+        _ => panic!("expected a PluginDependency error"),
+    }
+}
+
+#[test]
+fn register_in_dependency_order() {
+    let mut registry = PluginRegistry::<GreeterApi>::new();
+    unsafe {
+        registry.register(
+            vec![
+                fake_plugin("app", "0.1.0", &[("codec", "*")]),
+                fake_plugin("codec", "2.0.0", &[("compression", "^1.2")]),
+                fake_plugin("compression", "1.3.0", &[]),
+            ]
+        );
+    }
+    assert!(registry.rejected().is_empty());
+    assert_eq!(registry.load_order(), &["compression".to_string(), "codec".to_string(), "app".to_string()]);
+
+    // A dependent keeps its provider's library, so unloading the provider leaves it usable.
+    let compression = registry.unload("compression").unwrap();
+    assert_eq!(registry.load_order(), &["codec".to_string(), "app".to_string()]);
+    assert_eq!(registry.get("codec").unwrap().dependencies()[0].name(), compression.name());
+    drop(compression);
+
+    registry.unload_all();
+    assert!(registry.load_order().is_empty());
+    assert_eq!(registry.names().count(), 0);
+}
+
+#[test]
+fn reject_unresolved_dependencies() {
+    let mut registry = PluginRegistry::<GreeterApi>::new();
+    unsafe {
+        registry.register(
+            vec![
+                fake_plugin("codec", "2.0.0", &[("compression", "^2")]),
+                fake_plugin("compression", "1.3.0", &[]),
+                fake_plugin("player", "1.0.0", &[("codec", "2")]),
+                fake_plugin("viewer", "1.0.0", &[("zip", "1.0")]),
+                fake_plugin("writer", "1.0.0", &[("compression", "one")]),
+            ]
+        );
+    }
+    assert_eq!(registry.load_order(), &["compression".to_string()]);
+    // The first pass rejects what can be told without waiting, and later passes reject what
+    // waited on a rejected provider.
+    let kinds: Vec<PluginDependencyKind> = registry.rejected().iter().map(dependency_kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            PluginDependencyKind::Missing { name: "zip".to_string(), requirement: "1.0".to_string() },
+            PluginDependencyKind::InvalidRequirement { name: "compression".to_string(), requirement: "one".to_string() },
+            PluginDependencyKind::Unsatisfied { name: "compression".to_string(), requirement: "^2".to_string(), version: "1.3.0".to_string() },
+            PluginDependencyKind::Missing { name: "codec".to_string(), requirement: "2".to_string() },
+        ]
+    );
+}
+
+#[test]
+fn reject_cycles() {
+    let mut registry = PluginRegistry::<GreeterApi>::new();
+    unsafe {
+        registry.register(
+            vec![
+                fake_plugin("a", "1.0.0", &[("b", "1")]),
+                fake_plugin("b", "1.0.0", &[("a", "1")]),
+                fake_plugin("c", "1.0.0", &[("a", "1")]),
+                fake_plugin("d", "1.0.0", &[("d", "1")]),
+            ]
+        );
+    }
+    assert!(registry.load_order().is_empty());
+    let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<String>>();
+    let kinds: Vec<PluginDependencyKind> = registry.rejected().iter().map(dependency_kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            PluginDependencyKind::Cycle(names(&["a", "b", "a"])),
+            PluginDependencyKind::Cycle(names(&["b", "a", "b"])),
+            PluginDependencyKind::Cycle(names(&["c", "a", "b", "a"])),
+            PluginDependencyKind::Cycle(names(&["d", "d"])),
+        ]
+    );
+}
+
+fn reload_error_kind(registry: &mut PluginRegistry<GreeterApi>, name: &str) -> PluginDependencyKind {
+    match unsafe { registry.reload(name) } {
+        Err(SharedlibError::PluginDependency(err)) => err.kind().clone(),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("reloaded a plugin which is still in use"),
+    }
+}
+
+#[test]
+fn reload_refuses_plugin_in_use() {
+    let mut registry = PluginRegistry::<GreeterApi>::new();
+    unsafe {
+        registry.register(
+            vec![
+                fake_plugin("codec", "2.0.0", &[("compression", "^1.2")]),
+                fake_plugin("compression", "1.3.0", &[]),
+            ]
+        );
+    }
+    // Reloading would initialize the image which the dependent keeps loaded a second time.
+    assert_eq!(reload_error_kind(&mut registry, "compression"), PluginDependencyKind::InUse(vec!["codec".to_string()]));
+    assert_eq!(registry.load_order(), &["compression".to_string(), "codec".to_string()]);
+
+    registry.unload("codec");
+    let clone = registry.get("compression").unwrap().clone();
+    assert_eq!(reload_error_kind(&mut registry, "compression"), PluginDependencyKind::InUse(Vec::new()));
+    drop(clone);
+    assert!(registry.get("compression").is_some());
+}
@@ -0,0 +1,25 @@
+use std::env;
+use std::fs;
+use std::fs::Permissions;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+
+// Returns the directory which holds every scratch directory, next to the test executable. It is
+// created with mode 0755, but the directories above it may still be writable by others, for
+// instance when the target directory is below /tmp.
+pub fn scratch_root() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let root = exe.parent().unwrap().join("sharedlib-test");
+    fs::create_dir_all(&root).unwrap();
+    fs::set_permissions(&root, Permissions::from_mode(0o755)).unwrap();
+    root
+}
+
+// Creates an empty directory with mode 0755 in the scratch root.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = scratch_root().join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::set_permissions(&dir, Permissions::from_mode(0o755)).unwrap();
+    dir
+}
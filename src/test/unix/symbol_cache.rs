@@ -0,0 +1,93 @@
+use LibArc;
+use LibUnsafe;
+use OpenOptions;
+use SharedlibError as E;
+use std::thread;
+use std::time::Duration;
+use Symbol;
+use test::unix::LIBM;
+
+type Ceil = extern "C" fn(f64) -> f64;
+
+#[test]
+fn cache_finds_same_address() {
+    unsafe {
+        let uncached = LibUnsafe::new(LIBM).unwrap();
+        let expected = uncached.find_func::<usize, _>("ceil").unwrap();
+
+        let lib: LibUnsafe = OpenOptions::new().cache_symbols(true).open(LIBM).unwrap();
+        assert_eq!(lib.find_func::<usize, _>("ceil").unwrap(), expected);
+        assert_eq!(lib.find_func::<usize, _>("ceil\0").unwrap(), expected);
+        assert_eq!(lib.find_data::<u8, _>("ceil").unwrap() as usize, expected);
+        let ceil: Ceil = lib.find_func("ceil").unwrap();
+        assert_eq!(ceil(0.45), 1.0);
+    }
+}
+
+#[test]
+fn cache_skips_missing_symbols() {
+    unsafe {
+        let lib: LibUnsafe = OpenOptions::new().cache_symbols(true).open(LIBM).unwrap();
+        for _ in 0..2 {
+            match lib.find_func::<Ceil, _>("not_a_libm_function") {
+                Err(E::LibraryFindSymbol(_)) => (),
+                Err(err) => panic!("unexpected error: {}", err),
+                Ok(_) => panic!("found a missing symbol"),
+            }
+        }
+    }
+}
+
+#[test]
+fn cache_emptied_on_close() {
+    unsafe {
+        let lib: LibUnsafe = OpenOptions::new().cache_symbols(true).open(LIBM).unwrap();
+        lib.find_func::<Ceil, _>("ceil").unwrap();
+        lib.unload_when_idle(Duration::from_secs(1)).unwrap();
+        match lib.find_func::<Ceil, _>("ceil") {
+            Err(E::LibraryUnloaded(_)) => (),
+            Err(err) => panic!("unexpected error: {}", err),
+            Ok(_) => panic!("found a symbol in a closed library"),
+        }
+    }
+}
+
+#[test]
+fn cache_shared_between_threads() {
+    let lib: LibArc = unsafe { OpenOptions::new().cache_symbols(true).open(LIBM) }.unwrap();
+    let threads: Vec<_> =
+        (0..8)
+            .map(
+                |i| {
+                    let lib = lib.clone();
+                    thread::spawn(
+                        move || {
+                            for _ in 0..100 {
+                                let ceil = unsafe { lib.find_func::<Ceil, _>("ceil\0") }.unwrap();
+                                assert_eq!(unsafe { ceil.get() }(i as f64 + 0.45), i as f64 + 1.0);
+                            }
+                        }
+                    )
+                }
+            ).collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
+
+#[cfg(target_arch="x86_64")]
+#[test]
+fn cache_versioned_symbols() {
+    unsafe {
+        let lib: LibUnsafe = OpenOptions::new().cache_symbols(true).open(LIBM).unwrap();
+        let expected = lib.find_func::<usize, _>("ceil").unwrap();
+        for _ in 0..2 {
+            assert_eq!(lib.find_func_versioned::<usize, _, _>("ceil", "GLIBC_2.2.5").unwrap(), expected);
+        }
+        match lib.find_func_versioned::<Ceil, _, _>("ceil", "NOT_A_VERSION\0") {
+            Err(E::LibraryFindSymbol(err)) => assert_eq!(err.symbol(), "ceil@NOT_A_VERSION"),
+            Err(err) => panic!("unexpected error: {}", err),
+            Ok(_) => panic!("found a symbol with a missing version"),
+        }
+    }
+}
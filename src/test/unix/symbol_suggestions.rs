@@ -0,0 +1,66 @@
+use LibUnsafe;
+use OpenOptions;
+use SharedlibError as E;
+use test::unix::LIBM;
+
+#[test]
+fn suggests_close_names() {
+    let lib: LibUnsafe = unsafe { OpenOptions::new().suggest_symbols(true).open(LIBM) }.unwrap();
+    match unsafe { lib.find_func::<extern "C" fn(f64) -> f64, _>("ceill_\0") } {
+        Err(E::LibraryFindSymbol(err)) => {
+            assert_eq!(err.symbol(), "ceill_");
+            assert_eq!(err.suggestions()[0], "ceill");
+            assert!(err.suggestions().iter().any(|suggestion| suggestion == "ceil"), "{:?}", err.suggestions());
+            let message = err.to_string();
+            assert!(message.contains("did you mean 'ceill'"), "{}", message);
+        },
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("found a symbol which does not exist"),
+    }
+}
+
+#[test]
+fn suggests_nothing_far_away() {
+    let lib: LibUnsafe = unsafe { OpenOptions::new().suggest_symbols(true).open(LIBM) }.unwrap();
+    match unsafe { lib.find_func::<extern "C" fn(), _>("not_a_libm_function") } {
+        Err(E::LibraryFindSymbol(err)) => {
+            assert!(err.suggestions().is_empty(), "{:?}", err.suggestions());
+            assert!(!err.to_string().contains("did you mean"));
+        },
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("found a symbol which does not exist"),
+    }
+}
+
+#[test]
+fn suggestions_off_by_default() {
+    let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+    match unsafe { lib.find_func::<extern "C" fn(f64) -> f64, _>("ceill_") } {
+        Err(E::LibraryFindSymbol(err)) => assert!(err.suggestions().is_empty()),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("found a symbol which does not exist"),
+    }
+}
+
+#[test]
+fn suggests_in_batches() {
+    let lib: LibUnsafe = unsafe { OpenOptions::new().suggest_symbols(true).open(LIBM) }.unwrap();
+    let batch = lib.find_many(&["floorr", "ceil"]).unwrap();
+    let missing = batch.missing().unwrap();
+    assert_eq!(missing.errors()[0].suggestions()[0], "floor");
+}
+
+#[test]
+fn suggests_mangled_names() {
+    // Not every system has a C++ runtime to look into.
+    let lib: LibUnsafe =
+        match unsafe { OpenOptions::new().suggest_symbols(true).open("libstdc++.so.6") } {
+            Ok(lib) => lib,
+            Err(_) => return,
+        };
+    match unsafe { lib.find_func::<extern "C" fn(), _>("terminate") } {
+        Err(E::LibraryFindSymbol(err)) => assert_eq!(err.suggestions()[0], "_ZSt9terminatev"),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("found an unmangled C++ function"),
+    }
+}
@@ -0,0 +1,30 @@
+use LibUnsafe;
+use SharedlibError as E;
+use SymbolTableLoader;
+use std::time::Duration;
+use test::unix::copy_libm;
+use test::unix::LIBM;
+
+type Ceil = extern "C" fn(f64) -> f64;
+
+#[test]
+fn optional_missing() {
+    let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+    let mut loader = SymbolTableLoader::new(&lib);
+    assert!(unsafe { loader.optional::<Ceil>("ceil\0") }.is_some());
+    assert!(unsafe { loader.optional::<Ceil>("not_a_libm_function\0") }.is_none());
+    loader.finish().unwrap();
+}
+
+#[test]
+fn optional_unloaded() {
+    let lib = unsafe { LibUnsafe::new(copy_libm("symbol-table-loader-unloaded")) }.unwrap();
+    unsafe { lib.unload_when_idle(Duration::from_secs(1)) }.unwrap();
+    let mut loader = SymbolTableLoader::new(&lib);
+    assert!(unsafe { loader.optional::<Ceil>("ceil\0") }.is_none());
+    match loader.finish() {
+        Err(E::LibraryUnloaded(_)) => (),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("loaded a table from an unloaded library"),
+    }
+}
@@ -0,0 +1,22 @@
+use Lib;
+use LibUnsafe;
+use ResidentReason;
+use test::unix::copy_libm;
+use UnloadOutcome;
+
+#[test]
+fn close_and_verify_unloaded() {
+    let path = copy_libm("close-and-verify-unloaded");
+    let lib = unsafe { LibUnsafe::new(&path) }.unwrap();
+    assert_eq!(lib.close_and_verify().unwrap(), UnloadOutcome::Unloaded);
+    assert!(unsafe { LibUnsafe::get_loaded(&path) }.unwrap().is_none());
+}
+
+#[test]
+fn close_and_verify_other_references() {
+    let path = copy_libm("close-and-verify-other-references");
+    let lib = unsafe { Lib::new(&path) }.unwrap();
+    let clone = lib.try_clone().unwrap();
+    assert_eq!(lib.close_and_verify().unwrap(), UnloadOutcome::StillResident(vec![ResidentReason::OtherReferences]));
+    assert_eq!(clone.close_and_verify().unwrap(), UnloadOutcome::Unloaded);
+}
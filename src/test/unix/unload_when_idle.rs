@@ -0,0 +1,86 @@
+use FuncArc;
+use LibArc;
+use SharedlibError;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use test::unix::copy_libm;
+
+type Ceil = extern fn(f64) -> f64;
+
+#[test]
+fn unload_when_idle_closes() {
+    unsafe {
+        let lib = LibArc::new(copy_libm("unload-when-idle")).unwrap();
+        let ceil: FuncArc<Ceil> = lib.find_func("ceil").unwrap();
+        assert_eq!(ceil.call(|ceil| ceil(0.45)).unwrap(), 1.0);
+        lib.unload_when_idle(Duration::from_secs(1)).unwrap();
+        match ceil.call(|ceil| ceil(0.45)) {
+            Err(SharedlibError::LibraryUnloaded(_)) => (),
+            _ => panic!("expected a LibraryUnloaded error"),
+        }
+        match lib.find_func::<Ceil, _>("ceil") {
+            Err(SharedlibError::LibraryUnloaded(_)) => (),
+            _ => panic!("expected a LibraryUnloaded error"),
+        }
+    }
+}
+
+#[test]
+fn unload_when_idle_times_out() {
+    unsafe {
+        let lib = LibArc::new(copy_libm("unload-when-idle-timeout")).unwrap();
+        let ceil: FuncArc<Ceil> = lib.find_func("ceil").unwrap();
+        let (entered_sender, entered_receiver) = mpsc::channel();
+        let (release_sender, release_receiver) = mpsc::channel::<()>();
+        let call =
+            thread::spawn(
+                move || {
+                    ceil.call(
+                        |ceil| {
+                            entered_sender.send(()).unwrap();
+                            release_receiver.recv().unwrap();
+                            ceil(0.45)
+                        }
+                    ).unwrap()
+                }
+            );
+        entered_receiver.recv().unwrap();
+        match lib.unload_when_idle(Duration::from_millis(50)) {
+            Err(SharedlibError::UnloadTimeout(err)) => assert_eq!(err.in_flight(), 1),
+            _ => panic!("expected an UnloadTimeout error"),
+        }
+
+        // The library is still loaded, so calls are accepted again.
+        let ceil: FuncArc<Ceil> = lib.find_func("ceil").unwrap();
+        assert_eq!(ceil.call(|ceil| ceil(1.45)).unwrap(), 2.0);
+
+        release_sender.send(()).unwrap();
+        assert_eq!(call.join().unwrap(), 1.0);
+        lib.unload_when_idle(Duration::from_secs(1)).unwrap();
+    }
+}
+
+#[test]
+fn unload_when_idle_waits_for_calls() {
+    unsafe {
+        let lib = LibArc::new(copy_libm("unload-when-idle-waits")).unwrap();
+        let ceil: FuncArc<Ceil> = lib.find_func("ceil").unwrap();
+        let (entered_sender, entered_receiver) = mpsc::channel();
+        let call =
+            thread::spawn(
+                move || {
+                    ceil.call(
+                        |ceil| {
+                            entered_sender.send(()).unwrap();
+                            thread::sleep(Duration::from_millis(100));
+                            ceil(0.45)
+                        }
+                    ).unwrap()
+                }
+            );
+        entered_receiver.recv().unwrap();
+        lib.unload_when_idle(Duration::from_secs(10)).unwrap();
+        assert_eq!(call.join().unwrap(), 1.0);
+    }
+}
@@ -0,0 +1,76 @@
+use util::Version;
+use util::VersionReq;
+
+fn matches(requirement: &str, version: &str) -> bool {
+    VersionReq::parse(requirement).unwrap().matches(&Version::parse(version).unwrap())
+}
+
+#[test]
+fn parse_version() {
+    let version = Version::parse("1.2.3-beta.2+build").unwrap();
+    assert_eq!((version.major, version.minor, version.patch), (1, 2, 3));
+    assert_eq!(version.pre, vec!["beta".to_string(), "2".to_string()]);
+    assert!(Version::parse("1.2").is_none());
+    assert!(Version::parse("1.2.x").is_none());
+    assert!(Version::parse("1.2.3-").is_none());
+}
+
+#[test]
+fn precedence() {
+    let versions = ["1.0.0-alpha", "1.0.0-alpha.1", "1.0.0-alpha.beta", "1.0.0-beta.2", "1.0.0-beta.11", "1.0.0", "1.0.1", "1.10.0"];
+    for pair in versions.windows(2) {
+        assert!(Version::parse(pair[0]).unwrap() < Version::parse(pair[1]).unwrap(), "{} < {}", pair[0], pair[1]);
+    }
+}
+
+#[test]
+fn caret() {
+    assert!(matches("1.2.3", "1.9.0"));
+    assert!(matches("^1.2.3", "1.2.3"));
+    assert!(!matches("^1.2.3", "1.2.2"));
+    assert!(!matches("^1.2.3", "2.0.0"));
+    assert!(matches("^0.2.3", "0.2.9"));
+    assert!(!matches("^0.2.3", "0.3.0"));
+    assert!(!matches("^0.0.3", "0.0.4"));
+    assert!(matches("^1", "1.9.9"));
+    assert!(!matches("^0.0", "0.1.0"));
+}
+
+#[test]
+fn tilde_and_wildcard() {
+    assert!(matches("~1.2.3", "1.2.9"));
+    assert!(!matches("~1.2.3", "1.3.0"));
+    assert!(matches("~1", "1.9.0"));
+    assert!(matches("1.2.*", "1.2.7"));
+    assert!(!matches("1.2.*", "1.3.0"));
+    assert!(matches("*", "7.0.0"));
+    assert!(matches("", "7.0.0"));
+}
+
+#[test]
+fn comparisons() {
+    assert!(matches(">=1.0, <2.0", "1.5.0"));
+    assert!(!matches(">=1.0, <2.0", "2.0.0"));
+    assert!(matches(">1.2", "1.3.0"));
+    assert!(!matches(">1.2", "1.2.9"));
+    assert!(matches("<=1.2", "1.2.9"));
+    assert!(matches("=1.2.3", "1.2.3"));
+    assert!(!matches("=1.2.3", "1.2.4"));
+}
+
+#[test]
+fn pre_release() {
+    assert!(!matches("^1.0", "1.1.0-beta"));
+    assert!(matches(">=1.1.0-alpha", "1.1.0-beta"));
+    assert!(!matches(">=1.1.0-alpha", "1.2.0-beta"));
+    assert!(matches(">=1.1.0-alpha", "1.2.0"));
+}
+
+#[test]
+fn invalid() {
+    assert!(VersionReq::parse("one").is_none());
+    assert!(VersionReq::parse("1.*.3").is_none());
+    assert!(VersionReq::parse("<*").is_none());
+    assert!(VersionReq::parse("1.2-beta").is_none());
+    assert!(VersionReq::parse("1.2.3.4").is_none());
+}
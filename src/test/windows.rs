@@ -1,6 +1,7 @@
 use Func;
 use kernel32;
 use Lib;
+use LibUnsafe;
 use winapi::DWORD;
 use Symbol;
 
@@ -28,3 +29,25 @@ fn works_getlasterror0() {
 fn fails_new_kernel23() {
     unsafe { Lib::new("kernel23").err().unwrap() };
 }
+
+#[test]
+fn get_loaded_kernel32() {
+    unsafe { LibUnsafe::get_loaded("kernel32.dll").unwrap().unwrap() };
+}
+
+#[test]
+fn get_loaded_kernel23() {
+    assert!(unsafe { LibUnsafe::get_loaded("kernel23.dll") }.unwrap().is_none());
+}
+
+#[test]
+fn try_clone_kernel32() {
+    unsafe {
+        let lib = Lib::new("kernel32.dll").unwrap();
+        let clone = lib.try_clone().unwrap();
+        drop(lib);
+        let gle: Func<extern "system" fn() -> DWORD> = clone.find_func("GetLastError").unwrap();
+        kernel32::SetLastError(42);
+        assert_eq!(kernel32::GetLastError(), gle.get()());
+    }
+}
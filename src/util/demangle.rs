@@ -0,0 +1,58 @@
+// Recovers the qualified name, like `plugin::init`, from a C++ or legacy Rust mangled symbol, like
+// `_ZN6plugin4initEv`. Only the name is recovered; parameter types, templates and the special
+// names of constructors and operators are not. Returns `None` if `symbol` is not mangled.
+pub fn demangle(symbol: &str) -> Option<String> {
+    symbol
+        .strip_prefix("__Z")
+        .or_else(|| symbol.strip_prefix("_Z"))
+        .and_then(demangle_name)
+}
+
+// Demangles what follows the `_Z` prefix.
+fn demangle_name(mangled: &str) -> Option<String> {
+    // A nested name may start with the qualifiers of a member function.
+    let (nested, mut rest) =
+        match mangled.strip_prefix('N') {
+            Some(nested) => (true, nested.trim_start_matches(&['K', 'V', 'r'][..])),
+            None => (false, mangled),
+        };
+    let mut parts = Vec::new();
+    // `St` abbreviates the `std` namespace.
+    if let Some(std) = rest.strip_prefix("St") {
+        parts.push("std");
+        rest = std;
+    }
+    loop {
+        let digits = rest.bytes().take_while(|byte| byte.is_ascii_digit()).count();
+        let len: usize =
+            match rest[..digits].parse() {
+                Ok(len) => len,
+                Err(_) => break,
+            };
+        match digits.checked_add(len).and_then(|end| rest.get(digits..end).map(|part| (part, end))) {
+            Some((part, end)) => {
+                parts.push(part);
+                rest = &rest[end..];
+            },
+            None => return None,
+        }
+        if !nested {
+            break;
+        }
+    }
+    // Legacy Rust mangling ends each path with a hash, like `h0123456789abcdef`.
+    if parts.len() > 1 && is_rust_hash(parts[parts.len() - 1]) {
+        parts.pop();
+    }
+    if parts.is_empty() || parts == ["std"] {
+        None
+    } else {
+        Some(parts.join("::"))
+    }
+}
+
+fn is_rust_hash(part: &str) -> bool {
+    part.len() == 17 &&
+        part.starts_with('h') &&
+        part[1..].bytes().all(|byte| byte.is_ascii_hexdigit())
+}
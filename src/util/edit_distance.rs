@@ -0,0 +1,22 @@
+use std::cmp;
+use std::mem;
+
+// Returns the Levenshtein distance between `a` and `b`: the fewest single character insertions,
+// deletions and substitutions which turn one into the other.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // The distances from the prefix of `a` seen so far to every prefix of `b`.
+    let mut previous: Vec<usize> = (0..b.len() + 1).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == b_char { 0 } else { 1 };
+            let insertion = current[j] + 1;
+            let deletion = previous[j + 1] + 1;
+            current[j + 1] = cmp::min(substitution, cmp::min(insertion, deletion));
+        }
+        mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
@@ -1,9 +1,17 @@
-use util::ERROR_MUTEX;
+use util::ErrorLock;
 
+lazy_static! {
+    static ref ERROR_LOCK: ErrorLock = ErrorLock::new();
+}
+
+// Runs `func`, which reads the error state the platform loader left behind, while no other
+// thread reads it through this crate. Only the read is guarded, never the call into the loader,
+// so constructors and destructors which run inside the loader can use this crate freely. Where
+// the platform keeps its error state per thread, the lock only keeps this crate's reads from
+// overlapping; elsewhere, an error may still be replaced by another thread's between the failing
+// call and the read.
 pub fn error_guard<TFn, T>(func: TFn) -> T
     where TFn: FnOnce() -> T {
-    let _lock = ERROR_MUTEX.lock().unwrap();
-    let result = func();
-    drop(_lock);
-    result
+    let _lock = ERROR_LOCK.lock();
+    func()
 }
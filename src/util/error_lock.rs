@@ -0,0 +1,66 @@
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::thread;
+use std::thread::ThreadId;
+use util::ErrorLockGuard;
+
+#[derive(Debug)]
+struct ErrorLockState {
+    owner: Option<ThreadId>,
+    depth: usize,
+}
+
+// A lock which the thread holding it may take again, and which keeps working after a thread
+// panicked while holding it. A guard releases it even while unwinding, so there is nothing a
+// panic could leave half done.
+#[derive(Debug)]
+pub struct ErrorLock {
+    state: Mutex<ErrorLockState>,
+    released: Condvar,
+}
+
+impl ErrorLock {
+    pub fn new() -> Self {
+        let state =
+            ErrorLockState {
+                owner: None,
+                depth: 0,
+            };
+        ErrorLock {
+            state: Mutex::new(state),
+            released: Condvar::new(),
+        }
+    }
+
+    pub fn lock<'a>(&'a self) -> ErrorLockGuard<'a> {
+        let current = thread::current().id();
+        let mut state = self.state();
+        loop {
+            match state.owner {
+                None => {
+                    state.owner = Some(current);
+                    break;
+                },
+                Some(owner) if owner == current => break,
+                Some(_) => state = self.released.wait(state).unwrap_or_else(PoisonError::into_inner),
+            }
+        }
+        state.depth += 1;
+        ErrorLockGuard::new(self)
+    }
+
+    pub fn unlock(&self) {
+        let mut state = self.state();
+        state.depth -= 1;
+        if state.depth == 0 {
+            state.owner = None;
+            self.released.notify_one();
+        }
+    }
+
+    fn state<'a>(&'a self) -> MutexGuard<'a, ErrorLockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,21 @@
+use util::ErrorLock;
+
+// Holds an `ErrorLock` until it is dropped.
+#[derive(Debug)]
+pub struct ErrorLockGuard<'a> {
+    lock: &'a ErrorLock,
+}
+
+impl <'a> ErrorLockGuard<'a> {
+    pub fn new(lock: &'a ErrorLock) -> Self {
+        ErrorLockGuard {
+            lock: lock,
+        }
+    }
+}
+
+impl <'a> Drop for ErrorLockGuard<'a> {
+    fn drop(&mut self) {
+        self.lock.unlock();
+    }
+}
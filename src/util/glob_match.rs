@@ -0,0 +1,27 @@
+// Matches `name` against a glob `pattern` in which `*` matches any run of characters, `?` matches
+// a single character, and every other character matches itself.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let mut p = 0;
+    let mut n = 0;
+    // The position after the last `*` and the position in `name` it was tried at, so that a
+    // failed match can let the `*` swallow one more character.
+    let mut backtrack = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            p += 1;
+            backtrack = Some((p, n));
+        } else if let Some((star_p, star_n)) = backtrack {
+            p = star_p;
+            n = star_n + 1;
+            backtrack = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
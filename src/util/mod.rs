@@ -1,11 +1,51 @@
+pub mod demangle;
+
+pub mod edit_distance;
+
+#[cfg(unix)]
 pub mod error_guard;
 
-pub mod error_mutex;
+#[cfg(unix)]
+pub mod error_lock;
+
+#[cfg(unix)]
+pub mod error_lock_guard;
+
+pub mod glob_match;
 
 pub mod null_terminate;
 
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod sha256;
+
+pub mod to_hex;
+
+pub mod version;
+
+pub mod version_req;
+
+pub use util::demangle::demangle;
+
+pub use util::edit_distance::edit_distance;
+
+#[cfg(unix)]
 pub use util::error_guard::error_guard;
 
-pub use util::error_mutex::ERROR_MUTEX;
+#[cfg(unix)]
+pub use util::error_lock::ErrorLock;
+
+#[cfg(unix)]
+pub use util::error_lock_guard::ErrorLockGuard;
+
+pub use util::glob_match::glob_match;
 
 pub use util::null_terminate::null_terminate;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub use util::sha256::Sha256;
+
+pub use util::to_hex::to_hex;
+
+pub use util::version::Version;
+
+pub use util::version_req::VersionReq;
@@ -0,0 +1,11 @@
+pub fn to_hex<TBytes>(bytes: TBytes) -> String
+    where TBytes: AsRef<[u8]> {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let bytes = bytes.as_ref();
+    let mut result = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        result.push(DIGITS[(byte >> 4) as usize] as char);
+        result.push(DIGITS[(byte & 0xf) as usize] as char);
+    }
+    result
+}
@@ -0,0 +1,87 @@
+use std::cmp::Ordering;
+
+// A semantic version, `major.minor.patch` with an optional pre-release. Build metadata is
+// ignored, as it takes no part in precedence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Vec<String>,
+}
+
+impl Version {
+    pub fn parse(text: &str) -> Option<Version> {
+        let text = text.trim();
+        let text = text.split('+').next().unwrap_or("");
+        let mut parts = text.splitn(2, '-');
+        let numbers: Vec<&str> = parts.next().unwrap_or("").split('.').collect();
+        let pre =
+            match parts.next() {
+                Some(pre) if !pre.is_empty() => pre.split('.').map(|identifier| identifier.to_string()).collect(),
+                Some(_) => return None,
+                None => Vec::new(),
+            };
+        if numbers.len() != 3 {
+            return None;
+        }
+        match (Version::number(numbers[0]), Version::number(numbers[1]), Version::number(numbers[2])) {
+            (Some(major), Some(minor), Some(patch)) => {
+                let result =
+                    Version {
+                        major: major,
+                        minor: minor,
+                        patch: patch,
+                        pre: pre,
+                    };
+                Some(result)
+            },
+            _ => None,
+        }
+    }
+
+    pub fn number(text: &str) -> Option<u64> {
+        if text.is_empty() || !text.bytes().all(|byte| byte.is_ascii_digit()) {
+            None
+        } else {
+            text.parse().ok()
+        }
+    }
+
+    // Pre-release identifiers compare numerically when both are numbers, and numbers sort before
+    // words. A version without a pre-release sorts after every pre-release of it.
+    fn compare_pre(left: &[String], right: &[String]) -> Ordering {
+        match (left.is_empty(), right.is_empty()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => (),
+        }
+        for (left, right) in left.iter().zip(right.iter()) {
+            let ordering =
+                match (Version::number(left), Version::number(right)) {
+                    (Some(left), Some(right)) => left.cmp(&right),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => left.cmp(right),
+                };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        left.len().cmp(&right.len())
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Version) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| Version::compare_pre(&self.pre, &other.pre))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
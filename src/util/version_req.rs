@@ -0,0 +1,153 @@
+use util::Version;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Op {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+// A version requirement in the syntax Cargo uses, a comma separated list of comparators such as
+// `^1.2`, `~1.2.3`, `>=1.0, <2.0`, `=1.2.3-beta.1`, `1.*`, or `*`. A bare version means `^`.
+#[derive(Clone, Debug)]
+pub struct VersionReq {
+    bounds: Vec<(Op, Version)>,
+    // The versions whose pre-releases may match; any other pre-release never does.
+    pre_allowed: Vec<(u64, u64, u64)>,
+}
+
+impl VersionReq {
+    pub fn parse(text: &str) -> Option<VersionReq> {
+        let mut result =
+            VersionReq {
+                bounds: Vec::new(),
+                pre_allowed: Vec::new(),
+            };
+        if text.trim().is_empty() {
+            return Some(result);
+        }
+        for comparator in text.split(',') {
+            if !result.push_comparator(comparator.trim()) {
+                return None;
+            }
+        }
+        Some(result)
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        if !version.pre.is_empty() && !self.pre_allowed.contains(&(version.major, version.minor, version.patch)) {
+            return false;
+        }
+        self.bounds.iter().all(|&(op, ref bound)| {
+            match op {
+                Op::Eq => version == bound,
+                Op::Gt => version > bound,
+                Op::Ge => version >= bound,
+                Op::Lt => version < bound,
+                Op::Le => version <= bound,
+            }
+        })
+    }
+
+    fn push_comparator(&mut self, comparator: &str) -> bool {
+        let (op, rest) =
+            [">=", "<=", ">", "<", "=", "~", "^"].iter()
+                .find(|op| comparator.starts_with(*op))
+                .map_or(("", comparator), |op| (*op, comparator[op.len()..].trim_start()));
+        let (numbers, pre, wildcard) =
+            match VersionReq::parse_partial(rest) {
+                Some(partial) => partial,
+                None => return false,
+            };
+        if numbers.is_empty() {
+            // A lone wildcard only makes sense without an operator, or with one which includes
+            // every version.
+            return op.is_empty() || op == ">=";
+        }
+        // A bare version means `^`, unless it ends in a wildcard, as in `1.2.*`.
+        let op =
+            match op {
+                "" if wildcard => "=",
+                "" => "^",
+                _ => op,
+            };
+        let major = numbers[0];
+        let minor = numbers.get(1).cloned();
+        let patch = numbers.get(2).cloned();
+        let version = |major, minor, patch| Version { major: major, minor: minor, patch: patch, pre: Vec::new() };
+        let lowest = Version { major: major, minor: minor.unwrap_or(0), patch: patch.unwrap_or(0), pre: pre };
+        if !lowest.pre.is_empty() {
+            self.pre_allowed.push((lowest.major, lowest.minor, lowest.patch));
+        }
+        // The first version past the partial version, as in `<2.0.0` for `1` and `<1.3.0` for `1.2`.
+        let next_minor = minor.map(|minor| version(major, minor + 1, 0));
+        let next_major = version(major + 1, 0, 0);
+        let after_partial = next_minor.clone().unwrap_or_else(|| next_major.clone());
+        match (op, patch) {
+            ("=", Some(_)) => self.bounds.push((Op::Eq, lowest)),
+            ("=", None) | ("~", None) => {
+                self.bounds.push((Op::Ge, lowest));
+                self.bounds.push((Op::Lt, after_partial));
+            },
+            (">", Some(_)) => self.bounds.push((Op::Gt, lowest)),
+            (">", None) => self.bounds.push((Op::Ge, after_partial)),
+            (">=", _) => self.bounds.push((Op::Ge, lowest)),
+            ("<", _) => self.bounds.push((Op::Lt, lowest)),
+            ("<=", Some(_)) => self.bounds.push((Op::Le, lowest)),
+            ("<=", None) => self.bounds.push((Op::Lt, after_partial)),
+            ("~", Some(_)) => {
+                self.bounds.push((Op::Ge, lowest));
+                self.bounds.push((Op::Lt, next_minor.unwrap_or(next_major)));
+            },
+            _ => {
+                // `^` allows changes which keep the leftmost non-zero number.
+                let upper =
+                    match (major, minor, patch) {
+                        (0, Some(0), Some(patch)) => version(0, 0, patch + 1),
+                        (0, Some(minor), _) => version(0, minor + 1, 0),
+                        _ => next_major,
+                    };
+                self.bounds.push((Op::Ge, lowest));
+                self.bounds.push((Op::Lt, upper));
+            },
+        }
+        true
+    }
+
+    // Parses up to three numbers, in which a trailing `*` or `x` stands for any number, and the
+    // pre-release of a full version. Also returns whether there was a wildcard.
+    fn parse_partial(text: &str) -> Option<(Vec<u64>, Vec<String>, bool)> {
+        let text = text.split('+').next().unwrap_or("");
+        let mut parts = text.splitn(2, '-');
+        let components: Vec<&str> = parts.next().unwrap_or("").split('.').collect();
+        let pre: Vec<String> =
+            match parts.next() {
+                Some(pre) if !pre.is_empty() => pre.split('.').map(|identifier| identifier.to_string()).collect(),
+                Some(_) => return None,
+                None => Vec::new(),
+            };
+        if components.len() > 3 {
+            return None;
+        }
+        let mut numbers = Vec::new();
+        let mut wildcard = false;
+        for component in components {
+            match component {
+                "*" | "x" | "X" => wildcard = true,
+                _ if wildcard => return None,
+                _ => {
+                    match Version::number(component) {
+                        Some(number) => numbers.push(number),
+                        None => return None,
+                    }
+                },
+            }
+        }
+        if !pre.is_empty() && numbers.len() != 3 {
+            return None;
+        }
+        Some((numbers, pre, wildcard))
+    }
+}
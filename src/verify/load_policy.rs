@@ -0,0 +1,192 @@
+use error::LibraryOpen;
+use error::PolicyViolation;
+use error::PolicyViolationKind;
+use inspect::ElfFile;
+use inspect::HardeningLevel;
+use os::unix::external;
+use os::unix::O_NOFOLLOW;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::fs::Metadata;
+use std::fs::OpenOptions;
+use std::io::Error as IoError;
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// A policy which a shared library, and every directory above it, must satisfy before the library is loaded by an [OpenOptions](../struct.OpenOptions.html).
+/// By default, a [LoadPolicy](struct.LoadPolicy.html) refuses files and parent directories which are writable by their group or by other users, or which are owned by someone other than root or the current user. Parent directories which are owned by root and have the sticky bit set, like `/tmp`, are accepted even though others can write to them, since only the owner of an entry in such a directory can remove or rename it. Relative paths, symbolic links, and insufficiently hardened libraries may additionally be refused.
+///
+/// # Examples
+/// ``` no_run
+/// # use sharedlib::Lib;
+/// # use sharedlib::LoadPolicy;
+/// # use sharedlib::OpenOptions;
+/// # use sharedlib::SharedlibResult as R;
+/// # fn test() -> R<()> {
+/// let mut policy = LoadPolicy::new();
+/// policy.refuse_relative_paths(true).refuse_symlinks(true);
+/// let lib: Lib = try!(unsafe { OpenOptions::new().policy(policy).open("/opt/plugins/libexample.so") });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LoadPolicy {
+    minimum_hardening: Option<HardeningLevel>,
+    refuse_relative_paths: bool,
+    refuse_symlinks: bool,
+}
+
+impl LoadPolicy {
+    /// Creates a new [LoadPolicy](struct.LoadPolicy.html) which only checks ownership and permissions.
+    pub fn new() -> Self {
+        LoadPolicy {
+            minimum_hardening: None,
+            refuse_relative_paths: false,
+            refuse_symlinks: false,
+        }
+    }
+
+    /// Sets whether relative paths are refused.
+    pub fn refuse_relative_paths(&mut self, refuse: bool) -> &mut Self {
+        self.refuse_relative_paths = refuse;
+        self
+    }
+
+    /// Sets whether paths containing a symbolic link in any component are refused.
+    /// The library is then also opened without following a symbolic link, so its last component cannot be swapped for one after the path was checked.
+    pub fn refuse_symlinks(&mut self, refuse: bool) -> &mut Self {
+        self.refuse_symlinks = refuse;
+        self
+    }
+
+    /// Requires the library to have at least the specified [HardeningLevel](inspect/enum.HardeningLevel.html), as reported by [ElfFile::hardening](inspect/struct.ElfFile.html#method.hardening).
+    /// The check reads the same open file which is later loaded, so it happens before any code in the library can run.
+    pub fn require_hardening(&mut self, level: HardeningLevel) -> &mut Self {
+        self.minimum_hardening = Some(level);
+        self
+    }
+
+    /// Checks the open `file`, which was opened from `path_to_lib`, and every directory above it against this policy.
+    /// The directories which are checked are the ones which contain the open file, which the kernel reports for its descriptor, so a directory or link which is swapped after the file was opened does not affect the result.
+    ///
+    /// # Errors
+    /// A `PolicyViolation` error will be returned as a [SharedlibError](../enum.SharedlibError.html) variant naming the first offending path component. A `LibraryOpen` error will be returned if the metadata of a path component cannot be read. An `InvalidElf` error will be returned if a minimum hardening level is required and the file is not a valid ELF object.
+    pub fn check(&self, file: &File, path_to_lib: &Path) -> R<()> {
+        try!(self.check_path(path_to_lib));
+        self.check_file(file, path_to_lib)
+    }
+
+    // Opens the library for checking and loading. With symbolic links refused, the last component is
+    // opened without following a link, so it cannot be swapped for one after `check_path`.
+    pub(crate) fn open(&self, path_to_lib: &Path) -> R<File> {
+        let mut options = OpenOptions::new();
+        options.read(true);
+        if self.refuse_symlinks {
+            options.custom_flags(O_NOFOLLOW);
+        }
+        options.open(path_to_lib).map_err(|err| LoadPolicy::open_error(err, path_to_lib))
+    }
+
+    // Checks the rules which only depend on the path, so that they can be enforced before the path
+    // is opened.
+    pub(crate) fn check_path(&self, path_to_lib: &Path) -> R<()> {
+        if path_to_lib.is_relative() && self.refuse_relative_paths {
+            return LoadPolicy::violation(PolicyViolationKind::RelativePath, path_to_lib);
+        }
+
+        if self.refuse_symlinks {
+            let absolute_path = try!(LoadPolicy::absolute_path(path_to_lib));
+            let mut prefix = PathBuf::new();
+            for component in absolute_path.components() {
+                prefix.push(component);
+                let metadata = try!(fs::symlink_metadata(&prefix).map_err(|err| LoadPolicy::open_error(err, path_to_lib)));
+                if metadata.file_type().is_symlink() {
+                    return LoadPolicy::violation(PolicyViolationKind::Symlink, &prefix);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_file(&self, file: &File, path_to_lib: &Path) -> R<()> {
+        let metadata = try!(file.metadata().map_err(|err| LoadPolicy::open_error(err, path_to_lib)));
+        try!(LoadPolicy::check_metadata(&metadata, path_to_lib));
+
+        // The directories are found from the open file rather than from the path, which may name a
+        // different file by now.
+        let fd_path = format!("/proc/self/fd/{}", file.as_raw_fd());
+        let real_path = try!(fs::read_link(fd_path).map_err(|err| LoadPolicy::open_error(err, path_to_lib)));
+        for directory in real_path.ancestors().skip(1) {
+            let metadata = try!(fs::metadata(directory).map_err(|err| LoadPolicy::open_error(err, path_to_lib)));
+            try!(LoadPolicy::check_directory(&metadata, directory));
+        }
+
+        if let Some(required) = self.minimum_hardening {
+            let mut file = file;
+            let elf_file = try!(ElfFile::read(&mut file, path_to_lib));
+            let actual = elf_file.hardening().level();
+            if actual < required {
+                let kind =
+                    PolicyViolationKind::InsufficientHardening {
+                        actual: actual,
+                        required: required,
+                    };
+                return LoadPolicy::violation(kind, path_to_lib);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn absolute_path(path_to_lib: &Path) -> R<PathBuf> {
+        if path_to_lib.is_relative() {
+            let current_dir = try!(env::current_dir().map_err(|err| LoadPolicy::open_error(err, path_to_lib)));
+            Ok(current_dir.join(path_to_lib))
+        } else {
+            Ok(path_to_lib.to_path_buf())
+        }
+    }
+
+    // Checks a directory above the library. Root-owned sticky directories are accepted even if others
+    // can write to them, since the entries which others create in them cannot replace ours.
+    pub(crate) fn check_directory(metadata: &Metadata, path: &Path) -> R<()> {
+        if metadata.uid() == 0 && metadata.mode() & 0o1000 != 0 {
+            return Ok(());
+        }
+        LoadPolicy::check_metadata(metadata, path)
+    }
+
+    fn check_metadata(metadata: &Metadata, path: &Path) -> R<()> {
+        let owner = metadata.uid();
+        if owner != 0 && owner != unsafe { external::geteuid() } {
+            return LoadPolicy::violation(PolicyViolationKind::UntrustedOwner(owner), path);
+        }
+        if metadata.mode() & 0o022 != 0 {
+            return LoadPolicy::violation(PolicyViolationKind::WritableByOthers, path);
+        }
+        Ok(())
+    }
+
+    fn open_error(err: IoError, path_to_lib: &Path) -> E {
+        let err = LibraryOpen::new(Box::new(err), path_to_lib.to_path_buf());
+        E::from(err)
+    }
+
+    fn violation(kind: PolicyViolationKind, path: &Path) -> R<()> {
+        let err = PolicyViolation::new(kind, path.to_path_buf());
+        Err(E::from(err))
+    }
+}
+
+impl Default for LoadPolicy {
+    fn default() -> Self {
+        LoadPolicy::new()
+    }
+}
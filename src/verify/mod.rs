@@ -0,0 +1,13 @@
+//! Defines verifiers and policies which check a shared library before it is loaded by an [OpenOptions](../struct.OpenOptions.html).
+
+mod load_policy;
+
+mod sha256_verifier;
+
+mod verifier;
+
+pub use verify::load_policy::LoadPolicy;
+
+pub use verify::sha256_verifier::Sha256Verifier;
+
+pub use verify::verifier::Verifier;
@@ -0,0 +1,62 @@
+use error::IntegrityMismatch;
+use error::LibraryOpen;
+use SharedlibError as E;
+use SharedlibResult as R;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use util::Sha256;
+use verify::Verifier;
+
+/// A [Verifier](trait.Verifier.html) which checks that a shared library has a known SHA-256 digest.
+#[derive(Clone, Debug)]
+pub struct Sha256Verifier {
+    digest: [u8; 32],
+}
+
+impl Sha256Verifier {
+    /// Creates a new [Sha256Verifier](struct.Sha256Verifier.html) which expects the specified digest.
+    pub fn new(digest: [u8; 32]) -> Self {
+        Sha256Verifier {
+            digest: digest,
+        }
+    }
+
+    /// Computes the SHA-256 digest of everything remaining in `file`.
+    ///
+    /// # Errors
+    /// A `LibraryOpen` error will be returned as a [SharedlibError](../enum.SharedlibError.html) variant if the file cannot be read.
+    pub fn digest_of(file: &mut File, path_to_lib: &Path) -> R<[u8; 32]> {
+        let mut hasher = Sha256::new();
+        let mut buffer = [0; 8192];
+        loop {
+            let read =
+                try!(
+                    file.read(&mut buffer)
+                        .map_err(
+                            |err| {
+                                let err = LibraryOpen::new(Box::new(err), path_to_lib.to_path_buf());
+                                E::from(err)
+                            }
+                        )
+                );
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+        Ok(hasher.finish())
+    }
+}
+
+impl Verifier for Sha256Verifier {
+    fn verify(&self, file: &mut File, path_to_lib: &Path) -> R<()> {
+        let actual = try!(Sha256Verifier::digest_of(file, path_to_lib));
+        if actual == self.digest {
+            Ok(())
+        } else {
+            let err = IntegrityMismatch::new(self.digest.to_vec(), actual.to_vec(), path_to_lib.to_path_buf());
+            Err(E::from(err))
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use SharedlibResult as R;
+use std::fs::File;
+use std::path::Path;
+
+/// Checks the contents of a shared library before it is loaded.
+/// A [Verifier](trait.Verifier.html) is given the same open file which will later be passed to the platform loader, so the file cannot be swapped between the check and the load.
+pub trait Verifier {
+    /// Verifies the shared library read from `file`.
+    /// The file is positioned at its start when this is called. `path_to_lib` is the path which was used to open the file and should only be used for error reporting.
+    ///
+    /// # Errors
+    /// Any [SharedlibError](../enum.SharedlibError.html) returned by this function aborts the load and is returned to the caller.
+    fn verify(&self, file: &mut File, path_to_lib: &Path) -> R<()>;
+}
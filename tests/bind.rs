@@ -0,0 +1,70 @@
+#![cfg(all(target_os="linux", target_env="gnu"))]
+
+#[macro_use]
+extern crate sharedlib;
+
+use sharedlib::SharedlibError as E;
+use std::thread;
+
+mod libm {
+    bind! {
+        library "libm.so.6";
+        fn ceil(x: f64) -> f64;
+        fn pow(x: f64, y: f64,) -> f64;
+        unsafe fn frexp(x: f64, exp: *mut i32) -> f64;
+        fn not_a_libm_function();
+    }
+}
+
+mod missing {
+    bind! {
+        library "libsharedlib_not_a_library.so";
+        fn ceil(x: f64) -> f64;
+    }
+}
+
+#[test]
+fn call_bound_functions() {
+    assert!(libm::is_available());
+    assert!(libm::load_error().is_none());
+    assert_eq!(libm::ceil(0.45).unwrap(), 1.0);
+    assert_eq!(libm::ceil(1.45).unwrap(), 2.0);
+    assert_eq!(libm::pow(2.0, 10.0).unwrap(), 1024.0);
+
+    let mut exp = 0;
+    assert_eq!(unsafe { libm::frexp(8.0, &mut exp) }.unwrap(), 0.5);
+    assert_eq!(exp, 4);
+}
+
+#[test]
+fn call_from_many_threads() {
+    let threads: Vec<_> =
+        (0..8)
+            .map(|i| thread::spawn(move || libm::pow(2.0, i as f64).unwrap()))
+            .collect();
+    for (i, thread) in threads.into_iter().enumerate() {
+        assert_eq!(thread.join().unwrap(), (1 << i) as f64);
+    }
+}
+
+#[test]
+fn missing_symbol() {
+    match libm::not_a_libm_function() {
+        Err(E::LibraryFindSymbol(_)) => (),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(()) => panic!("called a missing symbol"),
+    }
+    assert!(libm::is_available());
+}
+
+#[test]
+fn missing_library() {
+    assert!(!missing::is_available());
+    let first = format!("{}", missing::load_error().unwrap());
+    assert!(first.contains("libsharedlib_not_a_library.so"));
+    match missing::ceil(0.45) {
+        Err(E::LibraryOpen(err)) => assert_eq!(format!("{}", err), first),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("called a function from a missing library"),
+    }
+}
@@ -0,0 +1,23 @@
+use sharedlib::SymbolTable;
+
+pub const LIBM: &str = "libm.so.6";
+
+#[derive(SymbolTable)]
+pub struct Libm {
+    pub ceil: extern "C" fn(f64) -> f64,
+    #[symbol(name = "floor")]
+    pub round_down: extern "C" fn(f64) -> f64,
+    pub exp2: Option<extern "C" fn(f64) -> f64>,
+    pub not_a_libm_function: Option<extern "C" fn(f64) -> f64>,
+}
+
+#[derive(SymbolTable)]
+pub struct Broken {
+    #[allow(dead_code)]
+    ceil: extern "C" fn(f64) -> f64,
+    #[allow(dead_code)]
+    not_a_libm_function: extern "C" fn(),
+    #[allow(dead_code)]
+    #[symbol(name = "also_not_a_libm_function")]
+    renamed: extern "C" fn(),
+}
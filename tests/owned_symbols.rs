@@ -0,0 +1,58 @@
+#![cfg(all(target_os="linux", target_env="gnu"))]
+
+extern crate sharedlib;
+
+mod common;
+
+use common::Broken;
+use common::Libm;
+use common::LIBM;
+use sharedlib::LibUnsafe;
+use sharedlib::OwnedSymbols;
+use sharedlib::SharedlibError as E;
+use std::thread;
+
+struct Math {
+    libm: OwnedSymbols<Libm>,
+}
+
+impl Math {
+    fn round_trip(&self, value: f64) -> f64 {
+        (self.libm.round_down)((self.libm.ceil)(value))
+    }
+}
+
+#[test]
+fn store_and_move() {
+    let math =
+        Math {
+            libm: unsafe { OwnedSymbols::new(LIBM) }.unwrap(),
+        };
+    assert_eq!(math.round_trip(0.45), 1.0);
+
+    let moved = Box::new(math);
+    assert_eq!(moved.round_trip(1.45), 2.0);
+
+    let result = thread::spawn(move || (moved.libm.table().ceil)(2.45)).join().unwrap();
+    assert_eq!(result, 3.0);
+}
+
+#[test]
+fn from_lib() {
+    let lib = unsafe { LibUnsafe::new(LIBM) }.unwrap();
+    let libm: OwnedSymbols<Libm> = unsafe { OwnedSymbols::from_lib(lib) }.unwrap();
+    assert_eq!((libm.ceil)(0.45), 1.0);
+    assert_eq!((libm.exp2.unwrap())(3.0), 8.0);
+    assert!(libm.not_a_libm_function.is_none());
+    let lib = libm.into_lib();
+    assert!(unsafe { lib.find_func::<extern "C" fn(f64) -> f64, _>("ceil") }.is_ok());
+}
+
+#[test]
+fn missing_symbols() {
+    match unsafe { OwnedSymbols::<Broken>::new(LIBM) } {
+        Err(E::MissingSymbols(err)) => assert_eq!(err.symbols(), vec!["not_a_libm_function", "also_not_a_libm_function"]),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("loaded a table with missing symbols"),
+    }
+}
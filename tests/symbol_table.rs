@@ -0,0 +1,45 @@
+#![cfg(all(target_os="linux", target_env="gnu"))]
+
+extern crate sharedlib;
+
+mod common;
+
+use common::Broken;
+use common::Libm;
+use common::LIBM;
+use sharedlib::Lib;
+use sharedlib::LibArc;
+use sharedlib::SharedlibError as E;
+
+#[test]
+fn load_table() {
+    let lib = unsafe { Lib::new(LIBM) }.unwrap();
+    let libm = unsafe { lib.load_table::<Libm>() }.unwrap();
+    assert_eq!((libm.ceil)(0.45), 1.0);
+    assert_eq!((libm.round_down)(0.45), 0.0);
+    assert_eq!((libm.exp2.unwrap())(3.0), 8.0);
+    assert!(libm.not_a_libm_function.is_none());
+}
+
+#[test]
+fn load_table_tracked() {
+    let lib = unsafe { LibArc::new(LIBM) }.unwrap();
+    let libm = unsafe { lib.load_table::<Libm>() }.unwrap();
+    drop(lib);
+    assert_eq!((libm.ceil)(1.5), 2.0);
+}
+
+#[test]
+fn report_every_missing_symbol() {
+    let lib = unsafe { Lib::new(LIBM) }.unwrap();
+    match unsafe { lib.load_table::<Broken>() } {
+        Err(E::MissingSymbols(err)) => {
+            assert_eq!(err.symbols(), vec!["not_a_libm_function", "also_not_a_libm_function"]);
+            let message = format!("{}", err);
+            assert!(message.contains("not_a_libm_function"));
+            assert!(message.contains("also_not_a_libm_function"));
+        },
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("loaded a table with missing symbols"),
+    }
+}